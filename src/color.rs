@@ -0,0 +1,35 @@
+/// Helpers for packed 0xRRGGBBAA colors as used by turbo draw calls
+pub struct Color;
+
+impl Color {
+    /// Pack 8-bit channels into 0xRRGGBBAA
+    pub fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> u32 {
+        ((r as u32) << 24) | ((g as u32) << 16) | ((b as u32) << 8) | a as u32
+    }
+
+    /// Split a packed color into (r, g, b, a)
+    pub fn to_rgba(color: u32) -> (u8, u8, u8, u8) {
+        ((color >> 24) as u8, (color >> 16) as u8, (color >> 8) as u8, color as u8)
+    }
+
+    /// Same color with its alpha channel replaced
+    pub fn with_alpha(color: u32, alpha: u8) -> u32 {
+        (color & 0xFFFFFF00) | alpha as u32
+    }
+
+    /// Multiply RGB by a factor (clamped), keeping alpha
+    pub fn scale_brightness(color: u32, factor: f32) -> u32 {
+        let (r, g, b, a) = Self::to_rgba(color);
+        let scale = |c: u8| (c as f32 * factor).clamp(0.0, 255.0) as u8;
+        Self::from_rgba(scale(r), scale(g), scale(b), a)
+    }
+
+    /// Per-channel linear blend from `from` (t = 0) to `to` (t = 1)
+    pub fn lerp(from: u32, to: u32, t: f32) -> u32 {
+        let t = t.clamp(0.0, 1.0);
+        let (r1, g1, b1, a1) = Self::to_rgba(from);
+        let (r2, g2, b2, a2) = Self::to_rgba(to);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self::from_rgba(mix(r1, r2), mix(g1, g2), mix(b1, b2), mix(a1, a2))
+    }
+}
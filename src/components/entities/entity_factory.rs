@@ -61,6 +61,17 @@ impl EntityFactory {
         Entity::Hook(HookEntity::new(self.next_entity_id(), owner_id))
     }
     
+    /// Create an anchored buoy marker
+    pub fn create_buoy(&mut self, position: V3) -> Entity {
+        Entity::Buoy(BuoyEntity::new(self.next_entity_id(), position))
+    }
+    
+    /// Create a thrown spear flying from `position` along `direction`
+    pub fn create_projectile(&mut self, position: V3, direction: V3) -> Entity {
+        let bullet = crate::models::bullet::Bullet::new(position, direction, SPEAR_THROW_SPEED, SPEAR_DAMAGE, SPEAR_THROW_RANGE);
+        Entity::Projectile(ProjectileEntity::new(self.next_entity_id(), bullet))
+    }
+    
     /// Get next entity ID
     fn next_entity_id(&mut self) -> u32 {
         let id = self.next_entity_id;
@@ -70,6 +81,7 @@ impl EntityFactory {
 }
 
 /// Fish types
+#[derive(Copy, PartialEq)]
 #[turbo::serialize]
 pub enum FishType {
     SmallFish,
@@ -78,6 +90,39 @@ pub enum FishType {
     Shark,
 }
 
+impl FishType {
+    /// Every fish type, in collection log order (shallow to deep)
+    pub const ALL: [FishType; 4] = [FishType::SmallFish, FishType::TropicalFish, FishType::DeepSeaFish, FishType::Shark];
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            FishType::SmallFish => "Small Fish",
+            FishType::TropicalFish => "Tropical Fish",
+            FishType::DeepSeaFish => "Deep Sea Fish",
+            FishType::Shark => "Shark",
+        }
+    }
+    
+    pub fn size(&self) -> f32 {
+        match self {
+            FishType::SmallFish => 4.0,
+            FishType::TropicalFish => 6.0,
+            FishType::DeepSeaFish => 8.0,
+            FishType::Shark => 16.0,
+        }
+    }
+    
+    /// Distance at which a hook tip can catch this fish
+    pub fn hook_radius(&self) -> f32 {
+        crate::constants::HOOK_FISH_BASE_RADIUS + self.size() * crate::constants::HOOK_RADIUS_SIZE_SCALE
+    }
+    
+    /// Large fish put up a fight on the hook instead of coming in straight away
+    pub fn fights_on_hook(&self) -> bool {
+        matches!(self, FishType::DeepSeaFish | FishType::Shark)
+    }
+}
+
 /// Monster types
 #[turbo::serialize]
 pub enum MonsterType {
@@ -176,15 +221,18 @@ pub struct FishEntity {
     pub stats: StatsComponent,
     pub render_data: RenderData,
     pub lifetime: f32,
+    pub attack_windup: f32,   // Seconds until a telegraphed bite lands (0 = not charging)
+    pub attack_cooldown: f32, // Seconds before this fish may charge again
 }
 
 impl FishEntity {
     pub fn new(id: u32, position: V3, fish_type: FishType) -> Self {
-        let (size, color) = match fish_type {
-            FishType::SmallFish => (4.0, 0xFFB6C1FF),
-            FishType::TropicalFish => (6.0, 0xFFFF00FF),
-            FishType::DeepSeaFish => (8.0, 0x4169E1FF),
-            FishType::Shark => (16.0, 0x696969FF),
+        let size = fish_type.size();
+        let color = match fish_type {
+            FishType::SmallFish => 0xFFB6C1FF,
+            FishType::TropicalFish => 0xFFFF00FF,
+            FishType::DeepSeaFish => 0x4169E1FF,
+            FishType::Shark => 0x696969FF,
         };
         
         let speed = match fish_type {
@@ -207,6 +255,8 @@ impl FishEntity {
             stats: StatsComponent::new(speed, 10.0, 5.0, 100.0),
             render_data,
             lifetime: 0.0,
+            attack_windup: 0.0,
+            attack_cooldown: 0.0,
         }
     }
 }
@@ -218,7 +268,10 @@ impl FishEntity {
 impl crate::components::systems::ai_system::AIEntity for FishEntity {
     fn get_id(&self) -> u32 { self.id }
     fn get_entity_type(&self) -> crate::components::systems::ai_system::EntityType { 
-        crate::components::systems::ai_system::EntityType::Fish
+        match self.fish_type {
+            FishType::Shark => crate::components::systems::ai_system::EntityType::Shark,
+            _ => crate::components::systems::ai_system::EntityType::Fish,
+        }
     }
     fn get_position(&self) -> V3 { self.position.clone() }
     fn set_position(&mut self, pos: V3) { self.position = pos; }
@@ -242,7 +295,8 @@ impl FloatingItemEntity {
     pub fn new(id: u32, position: V3, item_type: FloatingItemType) -> Self {
         let size = item_type.size();
         let render_data = RenderData::new(position.clone(), size, item_type.color())
-            .with_layer(RenderLayer::Entity);
+            .with_layer(RenderLayer::Entity)
+            .with_item_type(item_type);
         
         Self {
             id,
@@ -280,6 +334,7 @@ pub struct ParticleEntity {
     pub render_data: RenderData,
     pub lifetime: f32,
     pub max_lifetime: f32,
+    pub bubble: bool, // Rises and pops at the surface
 }
 
 impl ParticleEntity {
@@ -294,8 +349,17 @@ impl ParticleEntity {
             render_data,
             lifetime: 0.0,
             max_lifetime: 2.0, // 2 seconds
+            bubble: false,
         }
     }
+
+    /// Turn this particle into a bubble that lives until it reaches the surface
+    pub fn make_bubble(&mut self) {
+        self.bubble = true;
+        self.max_lifetime = BUBBLE_MAX_LIFETIME;
+        self.render_data.size = BUBBLE_SIZE;
+        self.render_data.color = BUBBLE_COLOR;
+    }
 }
 
 // GameEntity trait removed; behavior handled via Entity enum
@@ -387,3 +451,45 @@ impl crate::components::systems::ai_system::AIEntity for MonsterEntity {
     fn get_velocity(&self) -> V3 { self.velocity.clone() }
     fn set_velocity(&mut self, vel: V3) { self.velocity = vel; }
 }
+
+/// Buoy entity: a stationary navigation landmark placed by the player
+#[turbo::serialize]
+pub struct BuoyEntity {
+    pub id: u32,
+    pub position: V3,
+    pub render_data: RenderData,
+}
+
+impl BuoyEntity {
+    pub fn new(id: u32, position: V3) -> Self {
+        let render_data = RenderData::new(position, 10.0, BUOY_COLOR)
+            .with_layer(RenderLayer::Entity);
+        
+        Self {
+            id,
+            position,
+            render_data,
+        }
+    }
+}
+
+/// Projectile entity: a thrown spear in flight
+#[turbo::serialize]
+pub struct ProjectileEntity {
+    pub id: u32,
+    pub bullet: crate::models::bullet::Bullet,
+    pub render_data: RenderData,
+}
+
+impl ProjectileEntity {
+    pub fn new(id: u32, bullet: crate::models::bullet::Bullet) -> Self {
+        let render_data = RenderData::new(bullet.position, 4.0, FloatingItemType::Spear.color())
+            .with_layer(RenderLayer::Entity);
+        
+        Self {
+            id,
+            bullet,
+            render_data,
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use crate::components::entities::game_entity::{Entity, EntityType};
 use crate::math::Vec3 as V3;
+use crate::constants::MAX_TOTAL_ENTITIES;
 
 
 /// Manages all game entities and their lifecycle
@@ -9,6 +10,11 @@ pub struct EntityManager {
     entity_types: HashMap<EntityType, Vec<u32>>,
     next_entity_id: u32,
     spatial_hash: SpatialHash,
+    creation_order: VecDeque<(u32, u64)>, // (entity ID, creation stamp) oldest-first for cap eviction; removed entities are skipped lazily
+    creation_stamps: HashMap<u32, u64>,   // Creation stamp of each live entity, so stale or recycled IDs in creation_order are recognized
+    next_creation_stamp: u64,
+    max_total_entities: usize,
+    free_pooled_ids: Vec<u32>, // IDs of despawned pooled entities, handed out again before new ones
 }
 
 /// Runtime entity storage
@@ -23,6 +29,16 @@ impl EntityStorage {
             entities: HashMap::new(),
         }
     }
+
+    /// Copy of the storage with only persistent entities, for writing saves
+    pub fn persistent_snapshot(&self) -> Self {
+        Self {
+            entities: self.entities.iter()
+                .filter(|(_, e)| e.is_persistent())
+                .map(|(id, e)| (*id, e.clone()))
+                .collect(),
+        }
+    }
 }
 
 impl EntityManager {
@@ -31,15 +47,30 @@ impl EntityManager {
             entity_types: HashMap::new(),
             next_entity_id: 1,
             spatial_hash: SpatialHash::new(100.0), // 100 unit grid size
+            creation_order: VecDeque::new(),
+            creation_stamps: HashMap::new(),
+            next_creation_stamp: 0,
+            max_total_entities: MAX_TOTAL_ENTITIES,
+            free_pooled_ids: Vec::new(),
         }
     }
     
-    /// Create a new entity
+    /// Create a new entity, evicting the oldest non-essential entity if over the global cap
     pub fn create_entity(&mut self, storage: &mut EntityStorage, entity: Entity) -> u32 {
-        let entity_id = self.next_entity_id;
-        self.next_entity_id += 1;
-        
+        while storage.entities.len() >= self.max_total_entities {
+            if !self.evict_oldest(storage) {
+                break;
+            }
+        }
+
         let entity_type = entity.get_entity_type();
+        // Pooled types reuse a freed slot so churn doesn't keep growing the ID space and maps
+        let recycled = if entity_type.is_pooled() { self.free_pooled_ids.pop() } else { None };
+        let entity_id = recycled.unwrap_or_else(|| {
+            let id = self.next_entity_id;
+            self.next_entity_id += 1;
+            id
+        });
         
         // Add to entities map
         storage.entities.insert(entity_id, entity);
@@ -52,22 +83,90 @@ impl EntityManager {
             self.spatial_hash.insert(entity_id, entity_ref.get_world_position());
         }
         
+        self.record_creation(entity_id);
+        
         entity_id
     }
     
+    /// Rebuild the type index, spatial hash and creation order from a loaded storage.
+    /// Transient entities are not saved and respawn through normal gameplay.
+    pub fn rebuild_from_storage(&mut self, storage: &EntityStorage) {
+        self.entity_types.clear();
+        self.spatial_hash.clear();
+        self.creation_order.clear();
+        self.creation_stamps.clear();
+        self.free_pooled_ids.clear();
+        let mut ids: Vec<u32> = storage.entities.keys().copied().collect();
+        ids.sort_unstable();
+        for id in ids {
+            let entity = &storage.entities[&id];
+            self.entity_types.entry(entity.get_entity_type()).or_default().push(id);
+            self.spatial_hash.insert(id, entity.get_world_position());
+            self.record_creation(id);
+        }
+        self.next_entity_id = self.creation_order.back().map_or(1, |(id, _)| id + 1).max(self.next_entity_id);
+    }
+    
+    /// Append a new entity to the eviction order under a fresh creation stamp
+    fn record_creation(&mut self, entity_id: u32) {
+        let stamp = self.next_creation_stamp;
+        self.next_creation_stamp += 1;
+        self.creation_stamps.insert(entity_id, stamp);
+        self.creation_order.push_back((entity_id, stamp));
+    }
+    
+    /// Whether a creation_order entry still refers to the live entity it was recorded for
+    fn is_current(&self, (entity_id, stamp): (u32, u64)) -> bool {
+        self.creation_stamps.get(&entity_id) == Some(&stamp)
+    }
+    
+    /// Remove the oldest non-essential entity; returns false if none can be evicted
+    fn evict_oldest(&mut self, storage: &mut EntityStorage) -> bool {
+        while self.creation_order.front().is_some_and(|&entry| !self.is_current(entry)) {
+            self.creation_order.pop_front();
+        }
+        // Essential entities (player, raft, buoys) sit near the front and are stepped over
+        let oldest = self.creation_order.iter().copied()
+            .filter(|&entry| self.is_current(entry))
+            .map(|(id, _)| id)
+            .find(|id| storage.entities.get(id).is_some_and(|e| !e.get_entity_type().is_essential()));
+        match oldest {
+            Some(id) => self.remove_entity(storage, id),
+            None => false,
+        }
+    }
+    
+    /// Set the global entity cap
+    pub fn set_max_total_entities(&mut self, max: usize) {
+        self.max_total_entities = max;
+    }
+    
     /// Remove an entity
     pub fn remove_entity(&mut self, storage: &mut EntityStorage, entity_id: u32) -> bool {
         if let Some(entity) = storage.entities.remove(&entity_id) {
             let entity_type = entity.get_entity_type();
             
             // Remove from type index
-            if let Some(type_list) = self.entity_types.get_mut(&entity_type) {
-                type_list.retain(|&id| id != entity_id);
+            if let Some(type_list) = self.entity_types.get_mut(&entity_type)
+                && let Some(index) = type_list.iter().position(|&id| id == entity_id)
+            {
+                type_list.swap_remove(index);
             }
             
             // Remove from spatial hash
             self.spatial_hash.remove(entity_id);
             
+            // Leave the creation_order entry to be skipped, compacting once stale entries dominate
+            self.creation_stamps.remove(&entity_id);
+            if self.creation_order.len() > 2 * self.creation_stamps.len() {
+                let stamps = &self.creation_stamps;
+                self.creation_order.retain(|(id, stamp)| stamps.get(id) == Some(stamp));
+            }
+            
+            if entity_type.is_pooled() {
+                self.free_pooled_ids.push(entity_id);
+            }
+            
             true
         } else {
             false
@@ -132,6 +231,8 @@ impl EntityManager {
         
         for (entity_id, entity) in &mut storage.entities {
             entity.update(delta_time);
+            // Keep proximity queries in step with movement
+            self.spatial_hash.move_to(*entity_id, entity.get_world_position());
             
             if entity.should_remove() {
                 entities_to_remove.push(*entity_id);
@@ -173,6 +274,9 @@ impl EntityManager {
         storage.entities.clear();
         self.entity_types.clear();
         self.spatial_hash.clear();
+        self.creation_order.clear();
+        self.creation_stamps.clear();
+        self.free_pooled_ids.clear();
     }
     
     /// Update spatial hash for an entity
@@ -210,22 +314,29 @@ impl SpatialHash {
     pub fn remove(&mut self, entity_id: u32) {
         if let Some(position) = self.entity_positions.get(&entity_id) {
             let grid_pos = self.world_to_grid(position);
-            if let Some(cell) = self.grid.get_mut(&grid_pos) {
-                cell.retain(|&id| id != entity_id);
-            }
+            self.remove_from_cell(grid_pos, entity_id);
         }
         self.entity_positions.remove(&entity_id);
     }
     
+    /// Drop an entity from one grid cell, freeing the cell once it's empty so the grid
+    /// only holds occupied cells
+    fn remove_from_cell(&mut self, grid_pos: (i32, i32), entity_id: u32) {
+        if let Some(cell) = self.grid.get_mut(&grid_pos) {
+            cell.retain(|&id| id != entity_id);
+            if cell.is_empty() {
+                self.grid.remove(&grid_pos);
+            }
+        }
+    }
+    
     /// Update entity position in spatial hash
     pub fn update(&mut self, entity_id: u32, old_position: V3, new_position: V3) {
         let old_grid_pos = self.world_to_grid(&old_position);
         let new_grid_pos = self.world_to_grid(&new_position);
         
         // Remove from old cell
-        if let Some(cell) = self.grid.get_mut(&old_grid_pos) {
-            cell.retain(|&id| id != entity_id);
-        }
+        self.remove_from_cell(old_grid_pos, entity_id);
         
         // Add to new cell
         self.grid.entry(new_grid_pos).or_insert_with(Vec::new).push(entity_id);
@@ -234,6 +345,17 @@ impl SpatialHash {
         self.entity_positions.insert(entity_id, new_position);
     }
     
+    /// Move a tracked entity to a new position, using its last known position as the old cell
+    pub fn move_to(&mut self, entity_id: u32, new_position: V3) {
+        if let Some(old_position) = self.entity_positions.get(&entity_id).copied() {
+            if self.world_to_grid(&old_position) == self.world_to_grid(&new_position) {
+                self.entity_positions.insert(entity_id, new_position);
+            } else {
+                self.update(entity_id, old_position, new_position);
+            }
+        }
+    }
+    
     /// Query entities in an area
     pub fn query_area(&self, center: &V3, radius: f32) -> Vec<u32> {
         let mut result = Vec::new();
@@ -273,3 +395,74 @@ impl SpatialHash {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::entities::entity_factory::ParticleEntity;
+
+    #[test]
+    fn particle_churn_reuses_slots_instead_of_growing() {
+        let mut manager = EntityManager::new();
+        let mut storage = EntityStorage::new();
+        // 1000 particles in waves of 50, scattered across many grid cells
+        for wave in 0..20 {
+            let ids: Vec<u32> = (0..50)
+                .map(|i| {
+                    let pos = V3::new((wave * 50 + i) as f32 * 37.0, (i as f32) * -53.0, 0.0);
+                    manager.create_entity(&mut storage, Entity::Particle(ParticleEntity::new(0, pos, V3::zero())))
+                })
+                .collect();
+            assert_eq!(manager.get_entity_count(EntityType::Particle), 50);
+            for id in ids {
+                assert!(manager.remove_entity(&mut storage, id));
+            }
+        }
+
+        assert!(manager.next_entity_id <= 52, "IDs kept growing: next is {}", manager.next_entity_id);
+        assert!(storage.entities.is_empty());
+        assert!(manager.spatial_hash.grid.is_empty(), "{} empty grid cells left behind", manager.spatial_hash.grid.len());
+        assert!(manager.spatial_hash.entity_positions.is_empty());
+        assert!(manager.creation_order.is_empty());
+        assert!(manager.free_pooled_ids.len() <= 50);
+    }
+
+    #[test]
+    fn persistent_snapshot_keeps_fish_and_drops_particles() {
+        use crate::components::entities::entity_factory::{EntityFactory, FishType};
+        let mut manager = EntityManager::new();
+        let mut storage = EntityStorage::new();
+        let mut factory = EntityFactory::new();
+        let fish = manager.create_entity(&mut storage, factory.create_fish(V3::new(10.0, 0.0, -20.0), FishType::SmallFish));
+        let particle = manager.create_entity(&mut storage, factory.create_particle(V3::zero(), V3::new(1.0, 0.0, 0.0)));
+
+        let snapshot = storage.persistent_snapshot();
+        assert!(snapshot.entities.contains_key(&fish));
+        assert!(!snapshot.entities.contains_key(&particle));
+        assert_eq!(snapshot.entities.len(), 1);
+    }
+
+    #[test]
+    fn creating_past_the_cap_evicts_the_oldest_but_never_the_player() {
+        use crate::components::entities::entity_factory::{EntityFactory, FishType};
+        let mut manager = EntityManager::new();
+        let mut storage = EntityStorage::new();
+        let mut factory = EntityFactory::new();
+        manager.set_max_total_entities(5);
+        let player = manager.create_entity(&mut storage, factory.create_player(V3::zero()));
+        let fish: Vec<u32> = (0..4)
+            .map(|i| manager.create_entity(&mut storage, factory.create_fish(V3::new(i as f32 * 10.0, 0.0, -5.0), FishType::SmallFish)))
+            .collect();
+        // A removed entity leaves a stale entry behind that eviction has to skip
+        assert!(manager.remove_entity(&mut storage, fish[0]));
+        manager.create_entity(&mut storage, factory.create_fish(V3::zero(), FishType::SmallFish));
+
+        for i in 0..20 {
+            manager.create_entity(&mut storage, factory.create_fish(V3::new(0.0, i as f32, -5.0), FishType::SmallFish));
+            assert!(storage.entities.len() <= 5);
+            assert!(storage.entities.contains_key(&player), "player evicted after {} extra fish", i + 1);
+        }
+        assert!(!storage.entities.contains_key(&fish[1]), "oldest fish should go first");
+        assert!(manager.creation_order.len() <= 2 * storage.entities.len() + 1);
+    }
+}
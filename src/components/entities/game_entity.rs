@@ -23,6 +23,10 @@ pub struct RenderData {
     pub player_is_moving: bool,
     pub player_last_movement: Vec3,
     pub player_on_raft: bool,
+    pub warning_flash: bool, // Attack telegraph (shark windup)
+    pub hurt_flash: bool,    // Player i-frames after taking a hit
+    pub item_type: Option<crate::models::ocean::FloatingItemType>, // Floating items: picks the sprite
+    pub raft_tiles: Vec<(Vec3, crate::models::raft::RaftTileType)>, // Raft: world min corner of each tile
 }
 
 impl RenderData {
@@ -37,12 +41,20 @@ impl RenderData {
             player_is_moving: false,
             player_last_movement: Vec3::zero(),
             player_on_raft: false,
+            warning_flash: false,
+            hurt_flash: false,
+            item_type: None,
+            raft_tiles: Vec::new(),
         }
     }
     pub fn with_layer(mut self, layer: RenderLayer) -> Self {
         self.layer = layer;
         self
     }
+    pub fn with_item_type(mut self, item_type: crate::models::ocean::FloatingItemType) -> Self {
+        self.item_type = Some(item_type);
+        self
+    }
 }
 
 #[derive(Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -56,6 +68,21 @@ pub enum EntityType {
     FloatingItem,
     Particle,
     Hook,
+    Buoy,
+    Projectile,
+}
+
+impl EntityType {
+    /// Essential entities are never evicted by the global entity cap
+    pub fn is_essential(&self) -> bool {
+        matches!(self, EntityType::Player | EntityType::Raft | EntityType::Buoy)
+    }
+
+    /// High-churn transient entities (particles, bubbles) whose IDs are recycled on despawn.
+    /// Nothing holds on to these IDs, so reuse can't make a stale reference point elsewhere.
+    pub fn is_pooled(&self) -> bool {
+        matches!(self, EntityType::Particle)
+    }
 }
 
 #[turbo::serialize]
@@ -67,6 +94,8 @@ pub enum Entity {
     FloatingItem(super::entity_factory::FloatingItemEntity),
     Particle(super::entity_factory::ParticleEntity),
     Hook(super::entity_factory::HookEntity),
+    Buoy(super::entity_factory::BuoyEntity),
+    Projectile(super::entity_factory::ProjectileEntity),
 }
 
 impl Entity {
@@ -79,6 +108,8 @@ impl Entity {
             Entity::FloatingItem(e) => e.id,
             Entity::Particle(e) => e.id,
             Entity::Hook(e) => e.id,
+            Entity::Buoy(e) => e.id,
+            Entity::Projectile(e) => e.id,
         }
     }
     pub fn get_entity_type(&self) -> EntityType {
@@ -90,8 +121,14 @@ impl Entity {
             Entity::FloatingItem(_) => EntityType::FloatingItem,
             Entity::Particle(_) => EntityType::Particle,
             Entity::Hook(_) => EntityType::Hook,
+            Entity::Buoy(_) => EntityType::Buoy,
+            Entity::Projectile(_) => EntityType::Projectile,
         }
     }
+    /// Transient entities (particles, in-flight hooks and spears) are left out of saves
+    pub fn is_persistent(&self) -> bool {
+        !matches!(self, Entity::Particle(_) | Entity::Hook(_) | Entity::Projectile(_))
+    }
     pub fn get_world_position(&self) -> Vec3 {
         match self {
             Entity::Player(e) => e.player.pos.clone(),
@@ -101,6 +138,8 @@ impl Entity {
             Entity::FloatingItem(e) => e.position.clone(),
             Entity::Particle(e) => e.position.clone(),
             Entity::Hook(e) => e.hook.position.clone(),
+            Entity::Buoy(e) => e.position,
+            Entity::Projectile(e) => e.bullet.position,
         }
     }
     pub fn set_world_position(&mut self, pos: Vec3) {
@@ -133,18 +172,43 @@ impl Entity {
                 e.hook.position = pos;
                 e.render_data.world_position = pos;
             }
+            Entity::Buoy(e) => { 
+                e.position = pos;
+                e.render_data.world_position = pos;
+            }
+            Entity::Projectile(e) => { 
+                e.bullet.position = pos;
+                e.render_data.world_position = pos;
+            }
         }
     }
 
     pub fn get_render_data(&self) -> RenderData {
         match self {
             Entity::Player(e) => e.render_data.clone(),
-            Entity::Raft(e) => e.render_data.clone(),
+            Entity::Raft(e) => RenderData { raft_tiles: e.raft.tile_corners().collect(), ..e.render_data.clone() },
             Entity::Fish(e) => e.render_data.clone(),
             Entity::Monster(e) => e.render_data.clone(),
             Entity::FloatingItem(e) => e.render_data.clone(),
             Entity::Particle(e) => e.render_data.clone(),
             Entity::Hook(e) => e.render_data.clone(),
+            Entity::Buoy(e) => e.render_data.clone(),
+            Entity::Projectile(e) => e.render_data.clone(),
+        }
+    }
+    
+    /// Borrow the stored render data without cloning (raft tiles are only filled in by get_render_data)
+    pub fn render_data(&self) -> &RenderData {
+        match self {
+            Entity::Player(e) => &e.render_data,
+            Entity::Raft(e) => &e.render_data,
+            Entity::Fish(e) => &e.render_data,
+            Entity::Monster(e) => &e.render_data,
+            Entity::FloatingItem(e) => &e.render_data,
+            Entity::Particle(e) => &e.render_data,
+            Entity::Hook(e) => &e.render_data,
+            Entity::Buoy(e) => &e.render_data,
+            Entity::Projectile(e) => &e.render_data,
         }
     }
     
@@ -157,6 +221,8 @@ impl Entity {
             Entity::FloatingItem(e) => { e.render_data = render_data; }
             Entity::Particle(e) => { e.render_data = render_data; }
             Entity::Hook(e) => { e.render_data = render_data; }
+            Entity::Buoy(e) => { e.render_data = render_data; }
+            Entity::Projectile(e) => { e.render_data = render_data; }
         }
     }
 
@@ -169,6 +235,8 @@ impl Entity {
             Entity::FloatingItem(e) => e.velocity.clone(),
             Entity::Particle(e) => e.velocity.clone(),
             Entity::Hook(e) => e.hook.velocity.clone(),
+            Entity::Buoy(_e) => Vec3::zero(),
+            Entity::Projectile(e) => e.bullet.velocity,
         }
     }
     pub fn set_velocity(&mut self, vel: Vec3) {
@@ -180,6 +248,8 @@ impl Entity {
             Entity::FloatingItem(e) => { e.velocity = vel; }
             Entity::Particle(e) => { e.velocity = vel; }
             Entity::Hook(e) => { e.hook.velocity = vel; }
+            Entity::Buoy(_e) => {}
+            Entity::Projectile(e) => { e.bullet.velocity = vel; }
         }
     }
     pub fn update(&mut self, delta_time: f32) {
@@ -193,7 +263,7 @@ impl Entity {
                 e.position = e.position.add(e.velocity.scale(delta_time));
                 e.lifetime += delta_time;
                 e.health.update(delta_time);
-                e.stats.regenerate_stamina(delta_time);
+                e.stats.regenerate_stamina(delta_time, e.velocity.length() < crate::constants::STAMINA_IDLE_SPEED);
                 // Despawn after flowing a certain distance from origin
                 if e.position.distance_to(&e.spawn_origin) > 1200.0 {
                     e.health.hp = 0.0; // mark for removal via is_alive/lifetime checks
@@ -202,10 +272,16 @@ impl Entity {
             Entity::Monster(e) => {
                 e.position = e.position.add(e.velocity.scale(delta_time));
                 e.health.update(delta_time);
-                e.stats.regenerate_stamina(delta_time);
+                e.stats.regenerate_stamina(delta_time, e.velocity.length() < crate::constants::STAMINA_IDLE_SPEED);
             },
             Entity::FloatingItem(e) => {
-                e.position = e.position.add(e.velocity.scale(delta_time));
+                // Dropped or sunk items bob back up to the surface; sunken treasure only drifts with the current
+                if e.item_type.sinks() {
+                    e.velocity.z = 0.0;
+                    e.position = e.position.add(e.velocity.scale(delta_time));
+                } else {
+                    crate::models::PhysicsBody::floating_item().step(&mut e.position, &mut e.velocity, delta_time);
+                }
                 e.lifetime += delta_time;
                 if e.position.distance_to(&e.spawn_origin) > 1600.0 {
                     e.lifetime = 10000.0; // exceed removal threshold
@@ -215,12 +291,20 @@ impl Entity {
                 e.position = e.position.add(e.velocity.scale(delta_time));
                 e.lifetime += delta_time;
                 // gravity handled where needed; keep parity with previous
+                if e.bubble && e.position.z >= 0.0 {
+                    e.lifetime = e.max_lifetime + 1.0; // Popped at the surface
+                }
             },
             Entity::Hook(e) => {
                 // Hook update is handled in the hook system, not here
                 // Just update render position
                 e.render_data.world_position = e.hook.position.clone();
             },
+            Entity::Buoy(_e) => {}, // Anchored; never drifts
+            Entity::Projectile(e) => {
+                // Flight and hits are handled by the game manager, like hooks
+                e.render_data.world_position = e.bullet.position;
+            },
         }
     }
     pub fn should_remove(&self) -> bool {
@@ -229,6 +313,7 @@ impl Entity {
             Entity::FloatingItem(e) => e.lifetime > 600.0,
             Entity::Particle(e) => e.lifetime > e.max_lifetime,
             Entity::Hook(e) => !e.hook.is_active(), // Remove when hook is retracted
+            Entity::Projectile(e) => e.bullet.spent,
             _ => false,
         }
     }
@@ -255,13 +340,19 @@ pub struct StatsComponent {
     pub strength: f32,
     pub defense: f32,
     pub stamina: f32,
+    pub max_stamina: f32,
 }
 
 impl StatsComponent {
     pub fn new(speed: f32, strength: f32, defense: f32, stamina: f32) -> Self {
-        Self { speed, strength, defense, stamina }
+        Self { speed, strength, defense, stamina, max_stamina: stamina }
+    }
+    /// Recover stamina while idle, capped at the max
+    pub fn regenerate_stamina(&mut self, dt: f32, idle: bool) {
+        if idle {
+            self.stamina = (self.stamina + crate::constants::STAMINA_REGEN_RATE * dt).min(self.max_stamina);
+        }
     }
-    pub fn regenerate_stamina(&mut self, _dt: f32) {}
 }
 
 
@@ -1,38 +1,72 @@
 use std::collections::HashMap;
 use turbo::serialize;
+use crate::components::input::input_system::InputKey;
 
-/// Handles input mapping and key bindings
+/// Handles input mapping and key bindings: each logical action maps to a key identifier
 #[turbo::serialize]
 pub struct InputMapping {
-    key_bindings: HashMap<String, String>,
-    default_bindings: HashMap<String, String>,
+    key_bindings: HashMap<InputKey, String>,
+    default_bindings: HashMap<InputKey, String>,
 }
 
 impl InputMapping {
     pub fn new() -> Self {
         let mut default_bindings = HashMap::new();
-        default_bindings.insert("move_left".to_string(), "A".to_string());
-        default_bindings.insert("move_right".to_string(), "D".to_string());
-        default_bindings.insert("move_up".to_string(), "W".to_string());
-        default_bindings.insert("move_down".to_string(), "S".to_string());
-        default_bindings.insert("sail_left".to_string(), "J".to_string());
-        default_bindings.insert("sail_right".to_string(), "L".to_string());
-        default_bindings.insert("sail_forward".to_string(), "I".to_string());
-        default_bindings.insert("sail_backward".to_string(), "K".to_string());
-        default_bindings.insert("sail_north".to_string(), "Q".to_string());
-        default_bindings.insert("sail_south".to_string(), "E".to_string());
-        default_bindings.insert("use_tool".to_string(), "MOUSE_LEFT".to_string());
-        default_bindings.insert("switch_tool".to_string(), "E".to_string());
-        default_bindings.insert("eat_food".to_string(), "F".to_string());
-        default_bindings.insert("collect_item".to_string(), "G".to_string());
-        default_bindings.insert("open_inventory".to_string(), "I".to_string());
-        default_bindings.insert("open_crafting".to_string(), "C".to_string());
-        default_bindings.insert("craft_item".to_string(), "Space".to_string());
-        default_bindings.insert("quick_item_1".to_string(), "Digit1".to_string());
-        default_bindings.insert("quick_item_2".to_string(), "Digit2".to_string());
-        default_bindings.insert("quick_item_3".to_string(), "Digit3".to_string());
-        default_bindings.insert("quick_item_4".to_string(), "Digit4".to_string());
-        default_bindings.insert("quick_item_5".to_string(), "Digit5".to_string());
+        default_bindings.insert(InputKey::MoveLeft, "A".to_string());
+        default_bindings.insert(InputKey::MoveRight, "D".to_string());
+        default_bindings.insert(InputKey::MoveUp, "W".to_string());
+        default_bindings.insert(InputKey::MoveDown, "S".to_string());
+        default_bindings.insert(InputKey::Sprint, "Shift".to_string());
+        default_bindings.insert(InputKey::SailLeft, "J".to_string());
+        default_bindings.insert(InputKey::SailRight, "L".to_string());
+        default_bindings.insert(InputKey::SailForward, "I".to_string());
+        default_bindings.insert(InputKey::SailBackward, "K".to_string());
+        default_bindings.insert(InputKey::SailNorth, "Q".to_string());
+        default_bindings.insert(InputKey::SailSouth, "E".to_string());
+        default_bindings.insert(InputKey::UseTool, "MOUSE_LEFT".to_string());
+        default_bindings.insert(InputKey::ReelIn, "MOUSE_RIGHT".to_string());
+        default_bindings.insert(InputKey::SwitchTool, "E".to_string());
+        default_bindings.insert(InputKey::EatFood, "F".to_string());
+        default_bindings.insert(InputKey::ToggleAutoEat, "U".to_string());
+        default_bindings.insert(InputKey::DeployBuoy, "N".to_string());
+        default_bindings.insert(InputKey::CollectItem, "G".to_string());
+        default_bindings.insert(InputKey::Sleep, "Z".to_string());
+        default_bindings.insert(InputKey::Dive, "Space".to_string());
+        default_bindings.insert(InputKey::OpenInventory, "I".to_string());
+        default_bindings.insert(InputKey::OpenCrafting, "C".to_string());
+        default_bindings.insert(InputKey::OpenCollectionLog, "L".to_string());
+        default_bindings.insert(InputKey::OpenOptions, "O".to_string());
+        default_bindings.insert(InputKey::CycleInventoryFilter, "Tab".to_string());
+        default_bindings.insert(InputKey::ToggleInventoryDock, "B".to_string());
+        default_bindings.insert(InputKey::ToggleControlHints, "H".to_string());
+        default_bindings.insert(InputKey::TogglePause, "Escape".to_string());
+        default_bindings.insert(InputKey::CameraZoomIn, "Equal".to_string());
+        default_bindings.insert(InputKey::CameraZoomOut, "Minus".to_string());
+        default_bindings.insert(InputKey::ToggleCameraFollow, "V".to_string());
+        default_bindings.insert(InputKey::ToggleItemLabels, "X".to_string());
+        default_bindings.insert(InputKey::RenderDistanceDown, "BracketLeft".to_string());
+        default_bindings.insert(InputKey::RenderDistanceUp, "BracketRight".to_string());
+        default_bindings.insert(InputKey::RandomizeSeed, "R".to_string());
+        default_bindings.insert(InputKey::EraseSeedDigit, "Backspace".to_string());
+        default_bindings.insert(InputKey::CycleLoadout, "T".to_string());
+        default_bindings.insert(InputKey::MenuUp, "ArrowUp".to_string());
+        default_bindings.insert(InputKey::MenuDown, "ArrowDown".to_string());
+        default_bindings.insert(InputKey::MenuConfirm, "Enter".to_string());
+        default_bindings.insert(InputKey::CraftItem, "Space".to_string());
+        default_bindings.insert(InputKey::QuickItem1, "Digit1".to_string());
+        default_bindings.insert(InputKey::QuickItem2, "Digit2".to_string());
+        default_bindings.insert(InputKey::QuickItem3, "Digit3".to_string());
+        default_bindings.insert(InputKey::QuickItem4, "Digit4".to_string());
+        default_bindings.insert(InputKey::QuickItem5, "Digit5".to_string());
+        default_bindings.insert(InputKey::QuickItem6, "Digit6".to_string());
+        default_bindings.insert(InputKey::QuickItem7, "Digit7".to_string());
+        default_bindings.insert(InputKey::QuickItem8, "Digit8".to_string());
+        default_bindings.insert(InputKey::QuickItem9, "Digit9".to_string());
+        default_bindings.insert(InputKey::QuickItem0, "Digit0".to_string());
+        default_bindings.insert(InputKey::QuickCraft1, "F1".to_string());
+        default_bindings.insert(InputKey::QuickCraft2, "F2".to_string());
+        default_bindings.insert(InputKey::QuickCraft3, "F3".to_string());
+        default_bindings.insert(InputKey::QuickCraft4, "F4".to_string());
         
         Self {
             key_bindings: default_bindings.clone(),
@@ -41,19 +75,19 @@ impl InputMapping {
     }
     
     /// Get key binding for an action
-    pub fn get_key_binding(&self, action: &str) -> Option<&String> {
-        self.key_bindings.get(action)
+    pub fn get_key_binding(&self, action: InputKey) -> Option<&String> {
+        self.key_bindings.get(&action)
     }
     
-    /// Set key binding for an action
-    pub fn set_key_binding(&mut self, action: &str, key: &str) {
-        self.key_bindings.insert(action.to_string(), key.to_string());
+    /// Bind an action to a key identifier (see `key_state` for the supported names)
+    pub fn rebind(&mut self, action: InputKey, key: &str) {
+        self.key_bindings.insert(action, key.to_string());
     }
     
     /// Reset key binding to default
-    pub fn reset_key_binding(&mut self, action: &str) {
-        if let Some(default_key) = self.default_bindings.get(action) {
-            self.key_bindings.insert(action.to_string(), default_key.clone());
+    pub fn reset_key_binding(&mut self, action: InputKey) {
+        if let Some(default_key) = self.default_bindings.get(&action) {
+            self.key_bindings.insert(action, default_key.clone());
         }
     }
     
@@ -63,7 +97,7 @@ impl InputMapping {
     }
     
     /// Get all current key bindings
-    pub fn get_all_key_bindings(&self) -> &HashMap<String, String> {
+    pub fn get_all_key_bindings(&self) -> &HashMap<InputKey, String> {
         &self.key_bindings
     }
     
@@ -79,3 +113,95 @@ impl InputMapping {
         Ok(())
     }
 }
+
+/// Key identifiers `key_state` understands that an action can be rebound to.
+/// Escape is left out so it can always cancel a rebind.
+pub const BINDABLE_KEYS: [&str; 63] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M",
+    "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+    "Digit0", "Digit1", "Digit2", "Digit3", "Digit4", "Digit5", "Digit6", "Digit7", "Digit8", "Digit9",
+    "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+    "Space", "Shift", "Tab", "Enter", "Backspace", "Equal", "Minus", "BracketLeft", "BracketRight",
+    "ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight",
+    "MOUSE_LEFT", "MOUSE_RIGHT",
+];
+
+/// Pressed state of one key identifier for the current frame
+#[derive(Clone, Copy, Default)]
+pub struct KeyState {
+    pub pressed: bool,
+    pub just_pressed: bool,
+}
+
+/// Look up a key identifier ("W", "Digit1", "F2", "Space", "MOUSE_LEFT", ...) on the
+/// current keyboard/mouse state. Unknown identifiers read as never pressed.
+pub fn key_state(keyboard: &turbo::keyboard::Keyboard, mouse: &turbo::mouse::ScreenMouse, key: &str) -> KeyState {
+    let button = match key {
+        "A" => keyboard.key_a(),
+        "B" => keyboard.key_b(),
+        "C" => keyboard.key_c(),
+        "D" => keyboard.key_d(),
+        "E" => keyboard.key_e(),
+        "F" => keyboard.key_f(),
+        "G" => keyboard.key_g(),
+        "H" => keyboard.key_h(),
+        "I" => keyboard.key_i(),
+        "J" => keyboard.key_j(),
+        "K" => keyboard.key_k(),
+        "L" => keyboard.key_l(),
+        "M" => keyboard.key_m(),
+        "N" => keyboard.key_n(),
+        "O" => keyboard.key_o(),
+        "P" => keyboard.key_p(),
+        "Q" => keyboard.key_q(),
+        "R" => keyboard.key_r(),
+        "S" => keyboard.key_s(),
+        "T" => keyboard.key_t(),
+        "U" => keyboard.key_u(),
+        "V" => keyboard.key_v(),
+        "W" => keyboard.key_w(),
+        "X" => keyboard.key_x(),
+        "Y" => keyboard.key_y(),
+        "Z" => keyboard.key_z(),
+        "Digit0" => keyboard.digit_0(),
+        "Digit1" => keyboard.digit_1(),
+        "Digit2" => keyboard.digit_2(),
+        "Digit3" => keyboard.digit_3(),
+        "Digit4" => keyboard.digit_4(),
+        "Digit5" => keyboard.digit_5(),
+        "Digit6" => keyboard.digit_6(),
+        "Digit7" => keyboard.digit_7(),
+        "Digit8" => keyboard.digit_8(),
+        "Digit9" => keyboard.digit_9(),
+        "F1" => keyboard.f1(),
+        "F2" => keyboard.f2(),
+        "F3" => keyboard.f3(),
+        "F4" => keyboard.f4(),
+        "F5" => keyboard.f5(),
+        "F6" => keyboard.f6(),
+        "F7" => keyboard.f7(),
+        "F8" => keyboard.f8(),
+        "F9" => keyboard.f9(),
+        "F10" => keyboard.f10(),
+        "F11" => keyboard.f11(),
+        "F12" => keyboard.f12(),
+        "Space" => keyboard.space(),
+        "Shift" => keyboard.shift_any(),
+        "Tab" => keyboard.tab(),
+        "Enter" => keyboard.enter(),
+        "Escape" => keyboard.escape(),
+        "Backspace" => keyboard.backspace(),
+        "Equal" => keyboard.equal(),
+        "Minus" => keyboard.minus(),
+        "BracketLeft" => keyboard.bracket_left(),
+        "BracketRight" => keyboard.bracket_right(),
+        "ArrowUp" => keyboard.arrow_up(),
+        "ArrowDown" => keyboard.arrow_down(),
+        "ArrowLeft" => keyboard.arrow_left(),
+        "ArrowRight" => keyboard.arrow_right(),
+        "MOUSE_LEFT" => mouse.left,
+        "MOUSE_RIGHT" => mouse.right,
+        _ => return KeyState::default(),
+    };
+    KeyState { pressed: button.pressed(), just_pressed: button.just_pressed() }
+}
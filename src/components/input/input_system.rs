@@ -1,6 +1,6 @@
 use crate::math::Vec2 as V2;
 use crate::math::Vec3 as V3;
-use crate::components::input::input_mapping::InputMapping;
+use crate::components::input::input_mapping::{key_state, InputMapping, KeyState, BINDABLE_KEYS};
 use turbo::{keyboard, mouse};
 
 /// Handles all input processing
@@ -32,57 +32,127 @@ impl InputSystem {
         let mouse = mouse::screen();
         let (mx, my) = mouse.xy();
         
+        InputState {
+            // Mouse
+            mouse_pos: V2::new(mx as f32, my as f32),
+            mouse_left_pressed: mouse.left.just_pressed(),
+            mouse_left_held: mouse.left.pressed(),
+            mouse_right_pressed: mouse.right.just_pressed(),
+            mouse_right_held: mouse.right.pressed(),
+            shift_held: keyboard.shift_any().pressed(),
+            
+            ..self.bound_input_state(|key| key_state(&keyboard, &mouse, key))
+        }
+    }
+    
+    /// Resolve every bound action through the input mapping; `key_state` reports
+    /// the state of a single key identifier. Mouse position/buttons are left default.
+    fn bound_input_state(&self, key_state: impl Fn(&str) -> KeyState) -> InputState {
+        let state = |action: InputKey| self.input_mapping.get_key_binding(action).map(|key| key_state(key)).unwrap_or_default();
+        let held = |action: InputKey| state(action).pressed;
+        let tapped = |action: InputKey| state(action).just_pressed;
+        
         InputState {
             // Movement
-            move_left: keyboard.key_a().pressed(),
-            move_right: keyboard.key_d().pressed(),
-            move_up: keyboard.key_w().pressed(),
-            move_down: keyboard.key_s().pressed(),
+            move_left: held(InputKey::MoveLeft),
+            move_right: held(InputKey::MoveRight),
+            move_up: held(InputKey::MoveUp),
+            move_down: held(InputKey::MoveDown),
+            sprint: held(InputKey::Sprint),
             
             // Raft sailing
-            sail_left: keyboard.key_j().pressed(),
-            sail_right: keyboard.key_l().pressed(),
-            sail_forward: keyboard.key_i().pressed(),
-            sail_backward: keyboard.key_k().pressed(),
-            sail_north: keyboard.key_q().pressed(),
-            sail_south: keyboard.key_e().pressed(),
+            sail_left: held(InputKey::SailLeft),
+            sail_right: held(InputKey::SailRight),
+            sail_forward: held(InputKey::SailForward),
+            sail_backward: held(InputKey::SailBackward),
+            sail_north: held(InputKey::SailNorth),
+            sail_south: held(InputKey::SailSouth),
             
             // Actions
-            use_tool: mouse.left.just_pressed(),
-            switch_tool: keyboard.key_e().just_pressed(),
-            eat_food: keyboard.key_f().just_pressed(),
-            collect_item: keyboard.key_g().just_pressed(),
-            dive: keyboard.space().just_pressed(),
+            use_tool: tapped(InputKey::UseTool),
+            use_tool_held: held(InputKey::UseTool),
+            cast_held: held(InputKey::UseTool) || held(InputKey::CollectItem),
+            reel_in: held(InputKey::ReelIn),
+            reel_tap: tapped(InputKey::ReelIn),
+            switch_tool: tapped(InputKey::SwitchTool),
+            eat_food: tapped(InputKey::EatFood),
+            toggle_auto_eat: tapped(InputKey::ToggleAutoEat),
+            deploy_buoy: tapped(InputKey::DeployBuoy),
+            collect_item: tapped(InputKey::CollectItem),
+            sleep: tapped(InputKey::Sleep),
+            dive: tapped(InputKey::Dive),
             
             // UI
-            open_inventory: keyboard.key_i().just_pressed(),
-            open_crafting: keyboard.key_c().just_pressed(),
-            
-            // Mouse
-            mouse_pos: V2::new(mx as f32, my as f32),
-            mouse_left_pressed: mouse.left.just_pressed(),
-            mouse_left_held: mouse.left.pressed(),
-            mouse_right_pressed: mouse.right.just_pressed(),
+            open_inventory: tapped(InputKey::OpenInventory),
+            open_crafting: tapped(InputKey::OpenCrafting),
+            open_collection_log: tapped(InputKey::OpenCollectionLog),
+            open_options: tapped(InputKey::OpenOptions),
+            cycle_inventory_filter: tapped(InputKey::CycleInventoryFilter),
+            toggle_inventory_dock: tapped(InputKey::ToggleInventoryDock),
+            toggle_control_hints: tapped(InputKey::ToggleControlHints),
+            toggle_pause: tapped(InputKey::TogglePause),
             
             // Camera
-            camera_zoom_in: keyboard.key_e().just_pressed(),
-            camera_zoom_out: keyboard.key_q().just_pressed(),
+            camera_zoom_in: tapped(InputKey::CameraZoomIn),
+            camera_zoom_out: tapped(InputKey::CameraZoomOut),
+            toggle_camera_follow: tapped(InputKey::ToggleCameraFollow),
+            toggle_item_labels: tapped(InputKey::ToggleItemLabels),
+            render_distance_down: tapped(InputKey::RenderDistanceDown),
+            render_distance_up: tapped(InputKey::RenderDistanceUp),
+            randomize_seed: tapped(InputKey::RandomizeSeed),
+            erase_seed_digit: tapped(InputKey::EraseSeedDigit),
+            cycle_loadout: tapped(InputKey::CycleLoadout),
+            menu_up: tapped(InputKey::MenuUp),
+            menu_down: tapped(InputKey::MenuDown),
+            menu_confirm: tapped(InputKey::MenuConfirm),
 
             // Crafting
-            craft_item: keyboard.space().just_pressed(),
-            quick_item_1: keyboard.digit_1().just_pressed(),
-            quick_item_2: keyboard.digit_2().just_pressed(),
-            quick_item_3: keyboard.digit_3().just_pressed(),
-            quick_item_4: keyboard.digit_4().just_pressed(),
-            quick_item_5: keyboard.digit_5().just_pressed(),
-            quick_item_6: keyboard.digit_6().just_pressed(),
-            quick_item_7: keyboard.digit_7().just_pressed(),
-            quick_item_8: keyboard.digit_8().just_pressed(),
-            quick_item_9: keyboard.digit_9().just_pressed(),
-            quick_item_0: keyboard.digit_0().just_pressed(),
+            craft_item: tapped(InputKey::CraftItem),
+            quick_item_1: tapped(InputKey::QuickItem1),
+            quick_item_2: tapped(InputKey::QuickItem2),
+            quick_item_3: tapped(InputKey::QuickItem3),
+            quick_item_4: tapped(InputKey::QuickItem4),
+            quick_item_5: tapped(InputKey::QuickItem5),
+            quick_item_6: tapped(InputKey::QuickItem6),
+            quick_item_7: tapped(InputKey::QuickItem7),
+            quick_item_8: tapped(InputKey::QuickItem8),
+            quick_item_9: tapped(InputKey::QuickItem9),
+            quick_item_0: tapped(InputKey::QuickItem0),
+            quick_craft_1: tapped(InputKey::QuickCraft1),
+            quick_craft_2: tapped(InputKey::QuickCraft2),
+            quick_craft_3: tapped(InputKey::QuickCraft3),
+            quick_craft_4: tapped(InputKey::QuickCraft4),
+
+            ..InputState::default()
         }
     }
     
+    /// Display label for the key bound to an action (e.g. "I" for OpenInventory)
+    pub fn key_label(&self, action: InputKey) -> String {
+        self.input_mapping.get_key_binding(action).cloned().unwrap_or_else(|| "?".to_string())
+    }
+    
+    /// Bind an action to a key identifier
+    pub fn rebind(&mut self, action: InputKey, key: &str) {
+        self.input_mapping.rebind(action, key);
+    }
+    
+    /// Every action's current binding, in action order
+    pub fn key_bindings(&self) -> Vec<(InputKey, String)> {
+        let mut bindings: Vec<(InputKey, String)> = self.input_mapping.get_all_key_bindings().iter()
+            .map(|(action, key)| (*action, key.clone()))
+            .collect();
+        bindings.sort();
+        bindings
+    }
+    
+    /// First bindable key (or mouse button) pressed this frame, for capturing a rebind
+    pub fn just_pressed_key(&self) -> Option<&'static str> {
+        let keyboard = keyboard::get();
+        let mouse = mouse::screen();
+        BINDABLE_KEYS.into_iter().find(|key| key_state(&keyboard, &mouse, key).just_pressed)
+    }
+    
     /// Get current input state
     pub fn get_input_state(&self) -> &InputState {
         &self.current_input_state
@@ -95,6 +165,7 @@ impl InputSystem {
             InputKey::MoveRight => !self.previous_input_state.move_right && self.current_input_state.move_right,
             InputKey::MoveUp => !self.previous_input_state.move_up && self.current_input_state.move_up,
             InputKey::MoveDown => !self.previous_input_state.move_down && self.current_input_state.move_down,
+            InputKey::Sprint => !self.previous_input_state.sprint && self.current_input_state.sprint,
             InputKey::SailLeft => !self.previous_input_state.sail_left && self.current_input_state.sail_left,
             InputKey::SailRight => !self.previous_input_state.sail_right && self.current_input_state.sail_right,
             InputKey::SailForward => !self.previous_input_state.sail_forward && self.current_input_state.sail_forward,
@@ -102,11 +173,22 @@ impl InputSystem {
             InputKey::SailNorth => !self.previous_input_state.sail_north && self.current_input_state.sail_north,
             InputKey::SailSouth => !self.previous_input_state.sail_south && self.current_input_state.sail_south,
             InputKey::UseTool => self.current_input_state.use_tool,
+            InputKey::ReelIn => self.current_input_state.reel_in,
             InputKey::SwitchTool => self.current_input_state.switch_tool,
             InputKey::EatFood => self.current_input_state.eat_food,
+            InputKey::ToggleAutoEat => self.current_input_state.toggle_auto_eat,
+            InputKey::DeployBuoy => self.current_input_state.deploy_buoy,
             InputKey::CollectItem => self.current_input_state.collect_item,
+            InputKey::Sleep => self.current_input_state.sleep,
+            InputKey::Dive => self.current_input_state.dive,
             InputKey::OpenInventory => self.current_input_state.open_inventory,
             InputKey::OpenCrafting => self.current_input_state.open_crafting,
+            InputKey::OpenCollectionLog => self.current_input_state.open_collection_log,
+            InputKey::OpenOptions => self.current_input_state.open_options,
+            InputKey::CycleInventoryFilter => self.current_input_state.cycle_inventory_filter,
+            InputKey::ToggleInventoryDock => self.current_input_state.toggle_inventory_dock,
+            InputKey::ToggleControlHints => self.current_input_state.toggle_control_hints,
+            InputKey::TogglePause => self.current_input_state.toggle_pause,
             InputKey::CraftItem => self.current_input_state.craft_item,
             InputKey::QuickItem1 => self.current_input_state.quick_item_1,
             InputKey::QuickItem2 => self.current_input_state.quick_item_2,
@@ -118,8 +200,22 @@ impl InputSystem {
             InputKey::QuickItem8 => self.current_input_state.quick_item_8,
             InputKey::QuickItem9 => self.current_input_state.quick_item_9,
             InputKey::QuickItem0 => self.current_input_state.quick_item_0,
+            InputKey::QuickCraft1 => self.current_input_state.quick_craft_1,
+            InputKey::QuickCraft2 => self.current_input_state.quick_craft_2,
+            InputKey::QuickCraft3 => self.current_input_state.quick_craft_3,
+            InputKey::QuickCraft4 => self.current_input_state.quick_craft_4,
             InputKey::CameraZoomIn => self.current_input_state.camera_zoom_in,
             InputKey::CameraZoomOut => self.current_input_state.camera_zoom_out,
+            InputKey::ToggleCameraFollow => self.current_input_state.toggle_camera_follow,
+            InputKey::ToggleItemLabels => self.current_input_state.toggle_item_labels,
+            InputKey::RenderDistanceDown => self.current_input_state.render_distance_down,
+            InputKey::RenderDistanceUp => self.current_input_state.render_distance_up,
+            InputKey::RandomizeSeed => self.current_input_state.randomize_seed,
+            InputKey::EraseSeedDigit => self.current_input_state.erase_seed_digit,
+            InputKey::CycleLoadout => self.current_input_state.cycle_loadout,
+            InputKey::MenuUp => self.current_input_state.menu_up,
+            InputKey::MenuDown => self.current_input_state.menu_down,
+            InputKey::MenuConfirm => self.current_input_state.menu_confirm,
         }
     }
     
@@ -130,6 +226,7 @@ impl InputSystem {
             InputKey::MoveRight => self.current_input_state.move_right,
             InputKey::MoveUp => self.current_input_state.move_up,
             InputKey::MoveDown => self.current_input_state.move_down,
+            InputKey::Sprint => self.current_input_state.sprint,
             InputKey::SailLeft => self.current_input_state.sail_left,
             InputKey::SailRight => self.current_input_state.sail_right,
             InputKey::SailForward => self.current_input_state.sail_forward,
@@ -137,11 +234,22 @@ impl InputSystem {
             InputKey::SailNorth => self.current_input_state.sail_north,
             InputKey::SailSouth => self.current_input_state.sail_south,
             InputKey::UseTool => self.current_input_state.use_tool,
+            InputKey::ReelIn => self.current_input_state.reel_in,
             InputKey::SwitchTool => self.current_input_state.switch_tool,
             InputKey::EatFood => self.current_input_state.eat_food,
+            InputKey::ToggleAutoEat => self.current_input_state.toggle_auto_eat,
+            InputKey::DeployBuoy => self.current_input_state.deploy_buoy,
             InputKey::CollectItem => self.current_input_state.collect_item,
+            InputKey::Sleep => self.current_input_state.sleep,
+            InputKey::Dive => self.current_input_state.dive,
             InputKey::OpenInventory => self.current_input_state.open_inventory,
             InputKey::OpenCrafting => self.current_input_state.open_crafting,
+            InputKey::OpenCollectionLog => self.current_input_state.open_collection_log,
+            InputKey::OpenOptions => self.current_input_state.open_options,
+            InputKey::CycleInventoryFilter => self.current_input_state.cycle_inventory_filter,
+            InputKey::ToggleInventoryDock => self.current_input_state.toggle_inventory_dock,
+            InputKey::ToggleControlHints => self.current_input_state.toggle_control_hints,
+            InputKey::TogglePause => self.current_input_state.toggle_pause,
             InputKey::CraftItem => self.current_input_state.craft_item,
             InputKey::QuickItem1 => self.current_input_state.quick_item_1,
             InputKey::QuickItem2 => self.current_input_state.quick_item_2,
@@ -153,8 +261,22 @@ impl InputSystem {
             InputKey::QuickItem8 => self.current_input_state.quick_item_8,
             InputKey::QuickItem9 => self.current_input_state.quick_item_9,
             InputKey::QuickItem0 => self.current_input_state.quick_item_0,
+            InputKey::QuickCraft1 => self.current_input_state.quick_craft_1,
+            InputKey::QuickCraft2 => self.current_input_state.quick_craft_2,
+            InputKey::QuickCraft3 => self.current_input_state.quick_craft_3,
+            InputKey::QuickCraft4 => self.current_input_state.quick_craft_4,
             InputKey::CameraZoomIn => self.current_input_state.camera_zoom_in,
             InputKey::CameraZoomOut => self.current_input_state.camera_zoom_out,
+            InputKey::ToggleCameraFollow => self.current_input_state.toggle_camera_follow,
+            InputKey::ToggleItemLabels => self.current_input_state.toggle_item_labels,
+            InputKey::RenderDistanceDown => self.current_input_state.render_distance_down,
+            InputKey::RenderDistanceUp => self.current_input_state.render_distance_up,
+            InputKey::RandomizeSeed => self.current_input_state.randomize_seed,
+            InputKey::EraseSeedDigit => self.current_input_state.erase_seed_digit,
+            InputKey::CycleLoadout => self.current_input_state.cycle_loadout,
+            InputKey::MenuUp => self.current_input_state.menu_up,
+            InputKey::MenuDown => self.current_input_state.menu_down,
+            InputKey::MenuConfirm => self.current_input_state.menu_confirm,
         }
     }
     
@@ -222,15 +344,27 @@ impl InputSystem {
     pub fn is_mouse_right_just_pressed(&self) -> bool {
         self.current_input_state.mouse_right_pressed
     }
+    
+    /// Check if mouse right button is held
+    pub fn is_mouse_right_held(&self) -> bool {
+        self.current_input_state.mouse_right_held
+    }
+    
+    /// Check if either shift key is held
+    pub fn is_shift_held(&self) -> bool {
+        self.current_input_state.shift_held
+    }
 }
 
 /// Input keys that can be checked
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[turbo::serialize]
+#[derive(Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum InputKey {
     MoveLeft,
     MoveRight,
     MoveUp,
     MoveDown,
+    Sprint,
     SailLeft,
     SailRight,
     SailForward,
@@ -240,9 +374,20 @@ pub enum InputKey {
     UseTool,
     SwitchTool,
     EatFood,
+    ToggleAutoEat,
+    DeployBuoy,
     CollectItem,
+    Sleep,
+    Dive,
+    ReelIn,
     OpenInventory,
     OpenCrafting,
+    OpenCollectionLog,
+    OpenOptions,
+    CycleInventoryFilter,
+    ToggleInventoryDock,
+    ToggleControlHints,
+    TogglePause,
     CraftItem,
     QuickItem1,
     QuickItem2,
@@ -254,8 +399,22 @@ pub enum InputKey {
     QuickItem8,
     QuickItem9,
     QuickItem0,
+    QuickCraft1,
+    QuickCraft2,
+    QuickCraft3,
+    QuickCraft4,
     CameraZoomIn,
     CameraZoomOut,
+    ToggleCameraFollow,
+    RenderDistanceDown,
+    RenderDistanceUp,
+    RandomizeSeed,
+    EraseSeedDigit,
+    CycleLoadout,
+    MenuUp,
+    MenuDown,
+    MenuConfirm,
+    ToggleItemLabels,
 }
 
 /// Current input state
@@ -266,6 +425,7 @@ pub struct InputState {
     pub move_right: bool,
     pub move_up: bool,
     pub move_down: bool,
+    pub sprint: bool, // Held to swim faster while stamina lasts
     
     // Raft sailing
     pub sail_left: bool,
@@ -277,24 +437,49 @@ pub struct InputState {
     
     // Actions
     pub use_tool: bool,
+    pub use_tool_held: bool, // Builder paints wall pixels while held
+    pub cast_held: bool, // Cast button (UseTool or CollectItem) still down; releasing retracts early
+    pub reel_in: bool,   // Held to reel a returning hook in faster
+    pub reel_tap: bool,  // Reel key pressed this frame; keeps a hooked big fish's line in the zone
     pub switch_tool: bool,
     pub eat_food: bool,
+    pub toggle_auto_eat: bool,
+    pub deploy_buoy: bool,
     pub collect_item: bool,
+    pub sleep: bool,
     pub dive: bool,
     
     // UI
     pub open_inventory: bool,
     pub open_crafting: bool,
+    pub open_collection_log: bool,
+    pub open_options: bool,
+    pub cycle_inventory_filter: bool,
+    pub toggle_inventory_dock: bool,
+    pub toggle_control_hints: bool,
+    pub toggle_pause: bool,
     
     // Mouse
     pub mouse_pos: V2,
     pub mouse_left_pressed: bool,
     pub mouse_left_held: bool,
     pub mouse_right_pressed: bool,
+    pub mouse_right_held: bool,
+    pub shift_held: bool, // Modifier for mouse actions (e.g. stack splitting)
     
     // Camera
     pub camera_zoom_in: bool,
     pub camera_zoom_out: bool,
+    pub toggle_camera_follow: bool,
+    pub toggle_item_labels: bool,
+    pub render_distance_down: bool,
+    pub render_distance_up: bool,
+    pub randomize_seed: bool,
+    pub erase_seed_digit: bool,
+    pub cycle_loadout: bool,
+    pub menu_up: bool,      // Main menu keyboard navigation
+    pub menu_down: bool,
+    pub menu_confirm: bool,
 
     // Crafting
     pub craft_item: bool,
@@ -308,6 +493,10 @@ pub struct InputState {
     pub quick_item_8: bool,
     pub quick_item_9: bool,
     pub quick_item_0: bool,
+    pub quick_craft_1: bool,
+    pub quick_craft_2: bool,
+    pub quick_craft_3: bool,
+    pub quick_craft_4: bool,
 }
 
 impl Default for InputState {
@@ -317,6 +506,7 @@ impl Default for InputState {
             move_right: false,
             move_up: false,
             move_down: false,
+            sprint: false,
             sail_left: false,
             sail_right: false,
             sail_forward: false,
@@ -324,12 +514,25 @@ impl Default for InputState {
             sail_north: false,
             sail_south: false,
             use_tool: false,
+            use_tool_held: false,
+            cast_held: false,
+            reel_in: false,
+            reel_tap: false,
             switch_tool: false,
             eat_food: false,
+            toggle_auto_eat: false,
+            deploy_buoy: false,
             collect_item: false,
+            sleep: false,
             dive: false,
             open_inventory: false,
             open_crafting: false,
+            open_collection_log: false,
+            open_options: false,
+            cycle_inventory_filter: false,
+            toggle_inventory_dock: false,
+            toggle_control_hints: false,
+            toggle_pause: false,
             craft_item: false,
             quick_item_1: false,
             quick_item_2: false,
@@ -341,12 +544,28 @@ impl Default for InputState {
             quick_item_8: false,
             quick_item_9: false,
             quick_item_0: false,
+            quick_craft_1: false,
+            quick_craft_2: false,
+            quick_craft_3: false,
+            quick_craft_4: false,
             mouse_pos: V2::zero(),
             mouse_left_pressed: false,
             mouse_left_held: false,
             mouse_right_pressed: false,
+            mouse_right_held: false,
+            shift_held: false,
             camera_zoom_in: false,
             camera_zoom_out: false,
+            toggle_camera_follow: false,
+            toggle_item_labels: false,
+            render_distance_down: false,
+            render_distance_up: false,
+            randomize_seed: false,
+            erase_seed_digit: false,
+            cycle_loadout: false,
+            menu_up: false,
+            menu_down: false,
+            menu_confirm: false,
         }
     }
 }
@@ -361,3 +580,21 @@ pub struct SailingInput {
     pub north: bool,
     pub south: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebinding_move_up_changes_movement_vector() {
+        let mut input = InputSystem::new();
+        input.input_mapping.rebind(InputKey::MoveUp, "ArrowUp");
+
+        input.current_input_state = input.bound_input_state(|key| KeyState { pressed: key == "ArrowUp", just_pressed: false });
+        assert_eq!(input.get_movement_vector().y, -1.0);
+
+        // The old key no longer moves
+        input.current_input_state = input.bound_input_state(|key| KeyState { pressed: key == "W", just_pressed: false });
+        assert_eq!(input.get_movement_vector().y, 0.0);
+    }
+}
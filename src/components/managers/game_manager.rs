@@ -11,7 +11,7 @@ use crate::components::systems::spawn_system::SpawnType;
 use crate::components::entities::{EntityManager, EntityStorage, EntityFactory};
 use crate::models::player::Player;
 use crate::models::raft::Raft;
-use crate::models::ocean::Ocean;
+use crate::models::ocean::{Ocean, ItemCategory};
 use crate::models::particle::Particle;
 use crate::models::crafting::CraftingSystem;
 
@@ -30,6 +30,22 @@ pub struct GameState {
     pub wind: V3,
     pub inventory_context_menu: Option<InventoryContextMenu>,
     pub dragging_slot: Option<usize>,
+    pub split_stack: Option<(usize, u32)>, // (source slot, amount) carried by a shift+right-drag split
+    pub hovered_slot: Option<usize>, // Inventory slot under the cursor, for the item tooltip
+    pub inventory_filter: Option<ItemCategory>,
+    pub pinned_recipes: Vec<String>, // Recipe IDs on the quick-craft bar (F1-F4)
+    pub selected_recipe: Option<String>, // Recipe highlighted in the crafting panel
+    pub time_of_day: f32, // 0..1 through the current day (0.25 = morning)
+    pub day: u32,
+    pub play_time: f32, // Seconds spent in the playing scene
+    pub world_seed: u32, // Chosen on the main menu, shareable
+    pub loadout: crate::models::player::Loadout, // Starting inventory preset chosen on the main menu
+    pub fish_log: Vec<(crate::components::entities::entity_factory::FishType, u32)>, // Catch counts per fish type ever caught
+    pub collector_water: f32, // Rainwater held by the player's water collectors, up to COLLECTOR_CAPACITY
+    pub walls: Vec<crate::models::wall::WallGrid>, // Pixel walls built on the raft (raft-local positions)
+    pub kraken: Option<crate::models::monster::MonsterGrid>, // Boss closing in on the raft, if one has risen
+    pub next_kraken_time: f32, // Play time at which the next kraken rises
+    pub settings: GameSettings,
 }
 
 impl Default for GameState {
@@ -47,6 +63,60 @@ impl Default for GameState {
             wind: V3::zero(),
             inventory_context_menu: None,
             dragging_slot: None,
+            split_stack: None,
+            hovered_slot: None,
+            inventory_filter: None,
+            pinned_recipes: Vec::new(),
+            selected_recipe: None,
+            time_of_day: crate::constants::SLEEP_WAKE_TIME,
+            day: 1,
+            play_time: 0.0,
+            world_seed: crate::constants::DEFAULT_WORLD_SEED,
+            loadout: crate::models::player::Loadout::default(),
+            fish_log: Vec::new(),
+            collector_water: 0.0,
+            walls: Vec::new(),
+            kraken: None,
+            next_kraken_time: crate::constants::KRAKEN_FIRST_APPEARANCE,
+            settings: GameSettings::default(),
+        }
+    }
+}
+
+impl GameState {
+    /// Advance the day/night clock, rolling over into the next day
+    pub fn advance_time(&mut self, delta_time: f32) {
+        self.time_of_day += delta_time / crate::constants::DAY_LENGTH_SECONDS;
+        while self.time_of_day >= 1.0 {
+            self.time_of_day -= 1.0;
+            self.day += 1;
+        }
+    }
+
+    /// Night runs from NIGHT_START_TIME until the morning wake time
+    pub fn is_night(&self) -> bool {
+        self.time_of_day >= crate::constants::NIGHT_START_TIME || self.time_of_day < crate::constants::SLEEP_WAKE_TIME
+    }
+
+    /// Pin a recipe to the quick-craft bar, or unpin it if already pinned.
+    /// Returns false if the bar is full.
+    pub fn toggle_pinned_recipe(&mut self, recipe_id: &str) -> bool {
+        if let Some(idx) = self.pinned_recipes.iter().position(|id| id == recipe_id) {
+            self.pinned_recipes.remove(idx);
+            return true;
+        }
+        if self.pinned_recipes.len() >= crate::constants::MAX_PINNED_RECIPES {
+            return false;
+        }
+        self.pinned_recipes.push(recipe_id.to_string());
+        true
+    }
+
+    /// Count a caught fish in the collection log
+    pub fn record_fish_catch(&mut self, fish_type: crate::components::entities::entity_factory::FishType) {
+        match self.fish_log.iter_mut().find(|(t, _)| *t == fish_type) {
+            Some((_, count)) => *count += 1,
+            None => self.fish_log.push((fish_type, 1)),
         }
     }
 }
@@ -62,6 +132,141 @@ pub enum UiMode {
     Paused,
 }
 
+/// Player-facing options
+#[turbo::serialize]
+pub struct GameSettings {
+    pub dock_inventory: bool, // Inventory opens as a side panel and the game keeps running
+    pub auto_eat: bool,       // Eat automatically when hunger/thirst run low (off by default)
+    pub render_distance: i32, // Terrain chunks generated around the player
+    pub show_control_hints: bool,
+    pub control_hints_user_set: bool, // Once toggled by hand, hints no longer auto-hide
+    pub fast_empty_retract: bool, // Hooks that catch nothing reel back at high speed
+    pub pin_raft_while_diving: bool, // Raft stops drifting while the player is underwater
+    pub camera_lead: f32, // Camera look-ahead factor while moving (0 disables)
+    pub camera_smoothing: f32, // Camera follow rate per second (0 snaps to the target)
+    pub master_volume: f32, // 0..1 for every sound effect
+    pub render_scale: f32,  // Camera zoom preset from RENDER_SCALES
+    pub sort_includes_hotbar: bool, // Inventory Sort reorders the hotbar too, not just the bag
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            dock_inventory: false,
+            auto_eat: false,
+            render_distance: crate::constants::RENDER_DISTANCE,
+            show_control_hints: true,
+            control_hints_user_set: false,
+            fast_empty_retract: true,
+            pin_raft_while_diving: true,
+            camera_lead: crate::constants::CAMERA_LEAD_FACTOR,
+            camera_smoothing: crate::constants::CAMERA_SMOOTHING,
+            master_volume: crate::constants::MASTER_VOLUME,
+            render_scale: crate::constants::RENDER_SCALES[0],
+            sort_includes_hotbar: false,
+        }
+    }
+}
+
+/// Authoritative world state shared between instances running the same seed.
+/// Transient entities (particles, hooks) are left out; each instance recreates them.
+#[turbo::serialize]
+pub struct WorldSnapshot {
+    pub player: Option<Player>,
+    pub raft: Option<Raft>,
+    pub player_entity_id: Option<u32>,
+    pub raft_entity_id: Option<u32>,
+    pub entities: crate::components::entities::entity_manager::EntityStorage,
+}
+
+/// Progress written to local storage between sessions. Entities are rebuilt from the
+/// player, raft and buoys on load; everything else in the world respawns.
+#[turbo::serialize]
+pub struct SaveGame {
+    pub player: Player,
+    pub raft: Raft,
+    pub discovered_recipes: Vec<String>,
+    pub game_mode: GameMode,
+    pub world_seed: u32,
+    pub day: u32,
+    pub time_of_day: f32,
+    pub walls: Vec<crate::models::wall::WallGrid>,
+    pub pinned_recipes: Vec<String>,
+    pub fish_log: Vec<(crate::components::entities::entity_factory::FishType, u32)>,
+    pub buoys: Vec<V3>, // Anchored buoy positions
+    pub collector_water: f32,
+    pub craft_queue: Vec<crate::models::crafting::QueuedCraft>, // Ingredients are already spent
+    pub play_time: f32, // Drives difficulty, so it picks up where the session left off
+}
+
+/// SaveGame as written before pins, the fish log, buoys, collector water, the craft queue and play time were saved
+#[turbo::serialize]
+pub(crate) struct SaveGameV1 {
+    pub player: Player,
+    pub raft: Raft,
+    pub discovered_recipes: Vec<String>,
+    pub game_mode: GameMode,
+    pub world_seed: u32,
+    pub day: u32,
+    pub time_of_day: f32,
+    pub walls: Vec<crate::models::wall::WallGrid>,
+}
+
+impl From<SaveGameV1> for SaveGame {
+    fn from(old: SaveGameV1) -> Self {
+        Self {
+            player: old.player,
+            raft: old.raft,
+            discovered_recipes: old.discovered_recipes,
+            game_mode: old.game_mode,
+            world_seed: old.world_seed,
+            day: old.day,
+            time_of_day: old.time_of_day,
+            walls: old.walls,
+            pinned_recipes: Vec::new(),
+            fish_log: Vec::new(),
+            buoys: Vec::new(),
+            collector_water: 0.0,
+            craft_queue: Vec::new(),
+            play_time: 0.0,
+        }
+    }
+}
+
+/// LocalData as written alongside a SaveGameV1
+#[turbo::serialize]
+struct LocalDataV1 {
+    save: Option<SaveGameV1>,
+    options: Option<SavedOptions>,
+}
+
+/// Options kept across sessions, whether or not a game is saved
+#[turbo::serialize]
+pub struct SavedOptions {
+    pub settings: GameSettings,
+    pub key_bindings: Vec<(crate::components::input::input_system::InputKey, String)>,
+}
+
+/// Everything kept in local storage: the saved session, if any, and the options
+#[turbo::serialize]
+#[derive(Default)]
+pub struct LocalData {
+    pub save: Option<SaveGame>,
+    pub options: Option<SavedOptions>,
+}
+
+/// Decode local storage. Saves from before the current SaveGame layout load with the newer
+/// fields empty, and the oldest builds stored a bare save, which loads with default options.
+pub(crate) fn decode_local_data(bytes: &[u8]) -> LocalData {
+    if let Ok(data) = turbo::borsh::from_slice::<LocalData>(bytes) {
+        return data;
+    }
+    if let Ok(old) = turbo::borsh::from_slice::<LocalDataV1>(bytes) {
+        return LocalData { save: old.save.map(SaveGame::from), options: old.options };
+    }
+    LocalData { save: turbo::borsh::from_slice::<SaveGameV1>(bytes).ok().map(SaveGame::from), options: None }
+}
+
 /// High-level gameplay mode switch
 #[derive(Copy, PartialEq, Default)]
 #[turbo::serialize]
@@ -82,6 +287,8 @@ pub enum SceneType {
     Inventory,
     Crafting,
     Paused,
+    CollectionLog,
+    Options,
 }
 
 
@@ -95,6 +302,7 @@ pub struct GameManager {
     
     // Renderer
     pub(crate) render_system: RenderSystem,
+    pub(crate) ui_renderer: UIRenderer,
     
     // Input
     pub(crate) input_system: InputSystem,
@@ -106,6 +314,10 @@ pub struct GameManager {
     // Game state
     pub(crate) game_state: GameState,
     pub(crate) current_scene: SceneType,
+    pub(crate) options_return_scene: SceneType, // Menu the options scene goes back to
+    pub(crate) rebinding_action: Option<crate::components::input::input_system::InputKey>, // Options row waiting for a new key
+    pub(crate) menu_selection: usize, // Title screen button highlighted for keyboard use
+    pub(crate) save_available: bool, // Whether Continue on the title screen has a save to load
     // Entities
     pub(crate) entity_manager: EntityManager,
     pub(crate) entity_storage: EntityStorage,
@@ -114,6 +326,10 @@ pub struct GameManager {
     // Timing
     pub(crate) delta_time: f32,
     pub(crate) frame_count: u64,
+
+    // Stat feedback: last seen (health, hunger, thirst) and accumulated slow health drain
+    pub(crate) prev_player_vitals: Option<(f32, f32, f32)>,
+    pub(crate) health_drain_accum: f32,
 }
 
 #[turbo::serialize]
@@ -127,24 +343,35 @@ impl GameManager {
     pub fn new() -> Self {
         let mut game_manager = Self {
             spawn_system: SpawnSystem::new(),
-            world_system: WorldSystem::new(12345), // Fixed seed for now
+            world_system: WorldSystem::new(crate::constants::DEFAULT_WORLD_SEED), // Rebuilt from the menu seed on New Game
             ai_system: AISystem::new(),
             render_system: RenderSystem::new(),
+            ui_renderer: UIRenderer::new(),
             input_system: InputSystem::new(),
             scene_manager: SceneManager::new(),
             resource_manager: ResourceManager::new(),
             game_state: GameState { player_entity_id: None, raft_entity_id: None, ..GameState::default() },
             current_scene: SceneType::MainMenu,
+            options_return_scene: SceneType::MainMenu,
+            rebinding_action: None,
+            menu_selection: 0,
+            save_available: false,
             entity_manager: EntityManager::new(),
             entity_storage: EntityStorage::new(),
             entity_factory: EntityFactory::new(),
             delta_time: 1.0 / 60.0, // Assume 60 FPS
             frame_count: 0,
+            prev_player_vitals: None,
+            health_drain_accum: 0.0,
         };
         
         // Initialize systems
         game_manager.initialize_systems();
         
+        // Options apply everywhere; a saved session waits behind Continue on the title screen
+        game_manager.load_options();
+        game_manager.show_main_menu();
+        
         game_manager
     }
     
@@ -160,8 +387,14 @@ impl GameManager {
         self.spawn_system.set_spawn_rate(SpawnType::FloatingItem, 300);
         self.spawn_system.set_spawn_rate(SpawnType::Fish, 180);
         self.spawn_system.set_spawn_rate(SpawnType::Bubble, 60);
+        self.spawn_system.set_max_entities(SpawnType::Shark, crate::constants::MAX_SHARKS);
         self.game_state.wind = V3::new(1.0, 0.0, 0.0);
         self.spawn_system.set_wind(V3::new(1.0, 0.0, 0.0));
+        
+        // Register sound effects
+        for (name, path, duration) in crate::constants::GAME_SOUNDS {
+            self.resource_manager.register_sound(name, path, duration);
+        }
     }
     
     /// Main update loop
@@ -176,9 +409,21 @@ impl GameManager {
         match self.current_scene {
             SceneType::MainMenu => scenes::main_menu::update(self),
             SceneType::Playing => scenes::playing::update(self),
-            SceneType::Inventory => scenes::inventory::update(self),
+            SceneType::Inventory => {
+                // Docked inventory keeps the world simulating beside the panel
+                if self.game_state.settings.dock_inventory {
+                    scenes::playing::update(self);
+                }
+                scenes::inventory::update(self)
+            }
             SceneType::Crafting => scenes::crafting::update(self),
             SceneType::Paused => scenes::paused::update(self),
+            SceneType::CollectionLog => scenes::collection_log::update(self),
+            SceneType::Options => scenes::options::update(self),
+        }
+        // Crafts keep working behind every in-game panel, but not while paused
+        if !matches!(self.current_scene, SceneType::MainMenu | SceneType::Paused | SceneType::Options) {
+            self.update_crafting_queue();
         }
         // Sync structs to entities
         if let Some(id) = self.game_state.player_entity_id {
@@ -199,15 +444,20 @@ impl GameManager {
             if let Some(raft) = self.game_state.raft.as_ref() {
                 if let Some(entity) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, id) {
                     entity.set_world_position(raft.center.clone());
+                    // Placed or removed tiles show up on the rendered raft
+                    if let crate::components::entities::game_entity::Entity::Raft(raft_entity) = entity {
+                        raft_entity.raft.tiles = raft.tiles.clone();
+                    }
                 }
             }
         }
         // Move raft world position with sea and optionally autopilot, and carry player if on raft
         let (player_on_raft, player_diving) = if let Some(p) = &self.game_state.player { (p.on_raft, p.is_diving) } else { (false, false) };
-        if let Some(raft) = &mut self.game_state.raft {
+        let raft_pinned = player_diving && self.game_state.settings.pin_raft_while_diving;
+        if let Some(raft) = self.game_state.raft.as_mut().filter(|_| !raft_pinned) {
             let wind = self.game_state.wind;
-            // Slow tide-driven drift
-            let drift = wind.scale(0.2);
+            // Wind-driven drift: stronger wind pushes the raft faster
+            let drift = wind.scale(crate::constants::RAFT_WIND_DRIFT);
             let delta = drift.scale(self.delta_time);
             raft.center = raft.center.add(delta);
             if player_on_raft {
@@ -222,19 +472,13 @@ impl GameManager {
             for id in self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::FloatingItem) {
                 if let Some(e) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, id) {
                     let pos = e.get_world_position();
-                    // Make floating items flow much faster from left to right
-                    let base_flow = V3::new(6.0, 0.0, 0.0); // Much stronger left-to-right flow
-                    let v = base_flow.add(self.game_state.wind.scale(0.3));
+                    // Left-to-right current, pushed off course by the wind
+                    let base_flow = V3::new(crate::constants::ITEM_BASE_FLOW, 0.0, 0.0);
+                    let mut v = base_flow.add(self.game_state.wind.scale(crate::constants::ITEM_WIND_DRIFT));
+                    v.z = e.get_velocity().z; // Vertical motion belongs to buoyancy
                     e.set_velocity(v);
                 }
             }
-            // Fish drift with currents/wind
-            for id in self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Fish) {
-                if let Some(e) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, id) {
-                    let wind = self.game_state.wind;
-                    e.set_velocity(wind.scale(0.2));
-                }
-            }
             // Raft drifts slowly with surface current in Raft mode
             if self.game_state.game_mode == GameMode::Raft {
                 if let Some(raft_id) = self.game_state.raft_entity_id {
@@ -264,7 +508,8 @@ impl GameManager {
         // Update hook system
         let player_pos = self.game_state.player.as_ref().map(|p| p.pos.clone());
         if let Some(pos) = player_pos {
-            self.update_hooks(&pos, self.delta_time);
+            let input = self.input_system.get_input_state().clone();
+            self.update_hooks(&pos, self.delta_time, &input);
         }
         
         // Update-render entities
@@ -276,7 +521,7 @@ impl GameManager {
             if let crate::components::entities::game_entity::EntityType::Player = entity_type {
                 // Special handling for player with movement data
                 if let Some(player) = &self.game_state.player {
-                    self.render_system.add_player_entity(entity, player.is_moving, &player.last_movement);
+                    self.render_system.add_player_entity(entity, player.is_moving, &player.last_movement, player.is_invulnerable());
                 } else {
                     self.render_system.add_entity(entity);
                 }
@@ -284,7 +529,18 @@ impl GameManager {
                 self.render_system.add_entity(entity);
             }
         }
+        self.render_system.queue_terrain(&self.world_system);
+        if let Some(raft) = &self.game_state.raft {
+            self.render_system.queue_walls(&self.game_state.walls, &raft.center);
+        }
+        self.render_system.queue_monster(self.game_state.kraken.as_ref());
+        // Spawn damage/heal popups from this frame's stat changes
+        self.update_stat_feedback();
+        self.ui_renderer.update_notifications(self.delta_time);
+
         // Render world then UI once per frame after scene update
+        self.render_system.set_frame_count(self.frame_count);
+        self.render_system.set_sprites_available(self.resource_manager.sprites_available(&crate::constants::REQUIRED_SPRITES));
         self.render_system.render();
         self.render_ui();
         
@@ -298,16 +554,28 @@ impl GameManager {
         
         match self.current_scene {
             SceneType::MainMenu => {
-                if input_state.use_tool {
-                    self.current_scene = SceneType::Playing;
-                    self.initialize_playing_scene();
+                // Start and Continue are menu buttons (see scenes::main_menu)
+                if input_state.open_options {
+                    self.open_options();
                 }
             },
             SceneType::Playing => {
-                if input_state.open_inventory {
+                if input_state.toggle_pause {
+                    self.set_paused(true);
+                    if let Err(code) = self.save_game() {
+                        self.ui_renderer.push_colored_notification(format!("Save failed ({})", code), crate::constants::UI_TEXT_ORANGE, crate::constants::NOTIFICATION_DURATION);
+                    }
+                } else if input_state.open_inventory {
                     self.current_scene = SceneType::Inventory;
                 } else if input_state.open_crafting {
                     self.current_scene = SceneType::Crafting;
+                } else if input_state.open_collection_log {
+                    self.current_scene = SceneType::CollectionLog;
+                }
+            },
+            SceneType::CollectionLog => {
+                if input_state.open_collection_log {
+                    self.current_scene = SceneType::Playing;
                 }
             },
             SceneType::Inventory => {
@@ -321,8 +589,156 @@ impl GameManager {
                 }
             },
             SceneType::Paused => {
-                // Handle pause menu
+                if input_state.toggle_pause {
+                    self.set_paused(false);
+                }
             },
+            SceneType::Options => {
+                // Escape during a rebind cancels it instead (see scenes::options)
+                if input_state.toggle_pause && self.rebinding_action.is_none() {
+                    self.close_options();
+                }
+            },
+        }
+    }
+    
+    /// Open the options scene over the current menu (main menu or pause), which it returns to
+    pub(crate) fn open_options(&mut self) {
+        self.ui_renderer.hide_pause_menu();
+        self.ui_renderer.hide_main_menu();
+        self.options_return_scene = self.current_scene;
+        self.rebinding_action = None;
+        self.current_scene = SceneType::Options;
+        self.refresh_options_menu();
+    }
+    
+    /// Persist the options and go back to the menu that opened them
+    pub(crate) fn close_options(&mut self) {
+        self.ui_renderer.hide_options_menu();
+        self.rebinding_action = None;
+        if let Err(code) = self.save_options() {
+            self.ui_renderer.push_colored_notification(format!("Options not saved ({})", code), crate::constants::UI_TEXT_ORANGE, crate::constants::NOTIFICATION_DURATION);
+        }
+        if self.options_return_scene == SceneType::Paused {
+            self.set_paused(true);
+        } else if self.options_return_scene == SceneType::MainMenu {
+            self.show_main_menu();
+        } else {
+            self.current_scene = self.options_return_scene;
+        }
+    }
+    
+    /// Rebuild the options rows so their labels show the current values
+    pub(crate) fn refresh_options_menu(&mut self) {
+        use crate::components::renderer::ui_renderer::OptionsRow;
+        let button = |id: &str, label: String| (id.to_string(), label);
+        let settings = &self.game_state.settings;
+        let mut rows = vec![OptionsRow {
+            label: format!("Master volume: {}%", (settings.master_volume * 100.0).round() as i32),
+            buttons: vec![button("options_volume_down", "-".to_string()), button("options_volume_up", "+".to_string())],
+        }];
+        for (i, (action, label)) in scenes::options::REBINDABLE_ACTIONS.into_iter().enumerate() {
+            let key = if self.rebinding_action == Some(action) { "Press a key...".to_string() } else { self.input_system.key_label(action) };
+            rows.push(OptionsRow { label: label.to_string(), buttons: vec![button(&format!("options_bind_{}", i), key)] });
+        }
+        rows.push(OptionsRow {
+            label: "Render scale".to_string(),
+            buttons: vec![button("options_render_scale", format!("{}x", settings.render_scale))],
+        });
+        rows.push(OptionsRow {
+            label: "Sort includes hotbar".to_string(),
+            buttons: vec![button("options_sort_hotbar", if settings.sort_includes_hotbar { "On" } else { "Off" }.to_string())],
+        });
+        self.ui_renderer.show_options_menu(&rows);
+    }
+    
+    /// Step the master volume, keeping it within 0..1
+    pub(crate) fn adjust_master_volume(&mut self, delta: f32) {
+        let volume = &mut self.game_state.settings.master_volume;
+        *volume = ((*volume + delta) * 10.0).round().clamp(0.0, 10.0) / 10.0;
+        self.apply_settings();
+    }
+    
+    /// Switch to the next render scale preset, wrapping around
+    pub(crate) fn cycle_render_scale(&mut self) {
+        let scales = crate::constants::RENDER_SCALES;
+        let current = scales.iter().position(|&s| s == self.game_state.settings.render_scale).unwrap_or(0);
+        self.game_state.settings.render_scale = scales[(current + 1) % scales.len()];
+        self.apply_settings();
+    }
+    
+    /// Push volume and render scale from the settings into the systems that use them
+    fn apply_settings(&mut self) {
+        self.resource_manager.set_master_volume(self.game_state.settings.master_volume);
+        self.render_system.set_zoom(self.game_state.settings.render_scale);
+    }
+    
+    /// Enter or leave the Paused scene, showing the pause panel buttons only while paused
+    pub(crate) fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.current_scene = SceneType::Paused;
+            self.ui_renderer.show_pause_menu();
+        } else {
+            self.current_scene = SceneType::Playing;
+            self.ui_renderer.hide_pause_menu();
+        }
+    }
+    
+    /// Save and drop back to the title screen with a fresh session; the seed, loadout and
+    /// settings carry over so the menu shows what was last played
+    pub(crate) fn quit_to_main_menu(&mut self) {
+        if let Err(code) = self.save_game() {
+            self.ui_renderer.push_colored_notification(format!("Save failed ({})", code), crate::constants::UI_TEXT_ORANGE, crate::constants::NOTIFICATION_DURATION);
+        }
+        self.ui_renderer.hide_pause_menu();
+        self.reset_session();
+        self.show_main_menu();
+    }
+    
+    /// Drop the current session's world and entities, keeping the seed, loadout and settings
+    fn reset_session(&mut self) {
+        self.game_state = GameState {
+            world_seed: self.game_state.world_seed,
+            loadout: self.game_state.loadout,
+            settings: self.game_state.settings.clone(),
+            ..GameState::default()
+        };
+        self.entity_manager = EntityManager::new();
+        self.entity_storage = EntityStorage::new();
+        self.prev_player_vitals = None;
+    }
+    
+    /// Enter the title screen, checking whether there's a save for Continue
+    pub(crate) fn show_main_menu(&mut self) {
+        self.save_available = Self::read_local_data().save.is_some();
+        if !self.save_available && self.menu_selection == 1 {
+            self.menu_selection = 0;
+        }
+        self.ui_renderer.show_main_menu(self.save_available);
+        self.current_scene = SceneType::MainMenu;
+    }
+    
+    /// Title screen Start: a fresh session on the menu's seed and loadout
+    pub(crate) fn start_new_game(&mut self) {
+        self.ui_renderer.hide_main_menu();
+        self.reset_session();
+        self.world_system = WorldSystem::new(self.game_state.world_seed);
+        self.world_system.set_render_distance(self.game_state.settings.render_distance);
+        self.initialize_playing_scene();
+        self.current_scene = SceneType::Playing;
+    }
+    
+    /// Title screen Continue: resume the saved session, staying on the menu if it can't be read
+    pub(crate) fn continue_game(&mut self) {
+        if !self.save_available {
+            return;
+        }
+        self.reset_session();
+        if self.load_game() {
+            self.ui_renderer.hide_main_menu();
+        } else {
+            self.ui_renderer.push_colored_notification("No save to continue".to_string(), crate::constants::UI_TEXT_ORANGE, crate::constants::NOTIFICATION_DURATION);
+            self.show_main_menu();
         }
     }
     
@@ -330,7 +746,7 @@ impl GameManager {
     fn initialize_playing_scene(&mut self) {
         // Create player if not exists
         if self.game_state.player.is_none() {
-            let player = Player::new(V3::new(0.0, 0.0, 0.0));
+            let player = Player::with_loadout(V3::new(0.0, 0.0, 0.0), self.game_state.loadout);
             self.game_state.player = Some(player);
         }
         
@@ -354,7 +770,7 @@ impl GameManager {
                 self.game_state.player_entity_id = Some(id);
             }
             self.render_system.set_camera_target(player.pos);
-            self.render_system.update_camera(0.0); // Immediate update
+            self.render_system.snap_camera(); // Immediate update
         }
         if let Some(raft) = &self.game_state.raft {
             if self.game_state.raft_entity_id.is_none() {
@@ -370,8 +786,37 @@ impl GameManager {
     // Scene-specific update functions are now in managers::scenes::* modules
     
     /// Update AI for all entities
+    /// Run fish behavior trees against the player (fleeing inside FISH_FLEE_RADIUS, wandering
+    /// otherwise), measured in the plane of the current mode, then add the current's drift
     pub(crate) fn update_ai(&mut self) {
-        // TODO: Get all AI entities and update them
+        use crate::components::entities::game_entity::{Entity, EntityType};
+        let Some(player_pos) = self.game_state.player.as_ref().map(|p| p.pos) else { return; };
+        self.ai_system.set_view_mode(match self.game_state.game_mode {
+            GameMode::Raft => crate::components::systems::spawn_system::ViewMode::TopDown,
+            GameMode::Dive => crate::components::systems::spawn_system::ViewMode::SideScroll,
+        });
+        let drift = self.game_state.wind.scale(0.2);
+        let mut fish: Vec<&mut crate::components::entities::entity_factory::FishEntity> = self.entity_manager
+            .get_entities_by_type_mut(&mut self.entity_storage, EntityType::Fish)
+            .into_iter()
+            .filter_map(|e| if let Entity::Fish(f) = e { Some(f) } else { None })
+            .collect();
+        let alive: Vec<u32> = fish.iter().map(|f| f.id).collect();
+        let mut ai_entities: Vec<&mut dyn crate::components::systems::ai_system::AIEntity> = fish
+            .iter_mut()
+            .map(|f| &mut **f as &mut dyn crate::components::systems::ai_system::AIEntity)
+            .collect();
+        let world = &self.world_system;
+        self.ai_system.update(&mut ai_entities, &player_pos, self.delta_time, |x, y| world.is_solid(x, y));
+        for f in fish {
+            f.velocity = f.velocity.add(drift);
+        }
+        self.ai_system.retain_entities(&alive);
+    }
+    
+    /// How hard the run has become: 0 at the start, easing smoothly toward 1
+    pub fn difficulty(&self) -> f32 {
+        difficulty_after(self.game_state.play_time)
     }
     
     /// Update spawning (internal version that takes extracted values)
@@ -380,26 +825,54 @@ impl GameManager {
         let mut current_counts = std::collections::HashMap::new();
         let floats = self.entity_manager.get_entity_count(crate::components::entities::game_entity::EntityType::FloatingItem);
         let fish = self.entity_manager.get_entity_count(crate::components::entities::game_entity::EntityType::Fish);
+        let sharks = self.entity_manager.get_entities_by_type(&self.entity_storage, crate::components::entities::game_entity::EntityType::Fish)
+            .into_iter()
+            .filter(|e| matches!(e, crate::components::entities::game_entity::Entity::Fish(f) if f.fish_type == crate::components::entities::entity_factory::FishType::Shark))
+            .count();
         current_counts.insert(SpawnType::FloatingItem, floats);
-        current_counts.insert(SpawnType::Fish, fish);
-        current_counts.insert(SpawnType::Bubble, 0);
+        current_counts.insert(SpawnType::Fish, fish - sharks);
+        current_counts.insert(SpawnType::Shark, sharks);
+        let bubbles = self.entity_manager.get_entities_by_type(&self.entity_storage, crate::components::entities::game_entity::EntityType::Particle)
+            .into_iter()
+            .filter(|e| matches!(e, crate::components::entities::game_entity::Entity::Particle(p) if p.bubble))
+            .count();
+        current_counts.insert(SpawnType::Bubble, bubbles);
+        let treasure = self.entity_manager.get_entities_by_type(&self.entity_storage, crate::components::entities::game_entity::EntityType::FloatingItem)
+            .into_iter()
+            .filter(|e| matches!(e, crate::components::entities::game_entity::Entity::FloatingItem(i) if i.item_type == crate::models::ocean::FloatingItemType::Treasure))
+            .count();
+        current_counts.insert(SpawnType::Treasure, treasure);
         
         // Update spawn system
-        // Keep wind in sync
+        // Keep wind and difficulty in sync
         self.spawn_system.set_wind(self.game_state.wind);
+        self.spawn_system.set_difficulty(self.difficulty());
         self.spawn_system.update(player_pos, &current_counts);
         // Consume pending spawns and create entities
-        for (stype, pos) in self.spawn_system.drain_pending() {
+        for (stype, pos, fish_type) in self.spawn_system.drain_pending() {
             match stype {
                 SpawnType::FloatingItem => {
                     let item_type = self.get_random_floating_item_type();
                     let item = self.entity_factory.create_floating_item(pos.clone(), item_type);
                     let _ = self.entity_manager.create_entity(&mut self.entity_storage, item);
                 }
-                SpawnType::Fish => {
-                    let fish = self.entity_factory.create_fish(pos.clone(), crate::components::entities::entity_factory::FishType::SmallFish);
+                SpawnType::Fish | SpawnType::Shark => {
+                    let fish_type = fish_type.unwrap_or(crate::components::entities::entity_factory::FishType::SmallFish);
+                    let fish = self.entity_factory.create_fish(pos.clone(), fish_type);
                     let _ = self.entity_manager.create_entity(&mut self.entity_storage, fish);
                 }
+                SpawnType::Bubble => {
+                    let wobble = (turbo::random::f32() * 2.0 - 1.0) * crate::constants::BUBBLE_WOBBLE;
+                    let mut bubble = self.entity_factory.create_particle(pos, V3::new(wobble, 0.0, crate::constants::BUBBLE_RISE_SPEED));
+                    if let crate::components::entities::game_entity::Entity::Particle(particle) = &mut bubble {
+                        particle.make_bubble();
+                    }
+                    let _ = self.entity_manager.create_entity(&mut self.entity_storage, bubble);
+                }
+                SpawnType::Treasure => {
+                    let item = self.entity_factory.create_floating_item(pos, crate::models::ocean::FloatingItemType::Treasure);
+                    let _ = self.entity_manager.create_entity(&mut self.entity_storage, item);
+                }
                 _ => {}
             }
         }
@@ -426,8 +899,8 @@ impl GameManager {
             FloatingItemType::Coconut,
             FloatingItemType::Fish,
             FloatingItemType::Seaweed,
-            FloatingItemType::Treasure,
             FloatingItemType::Bottle,
+            // Treasure only turns up in the deep zones (SpawnType::Treasure)
         ];
         
         for item_type in item_types.iter() {
@@ -441,46 +914,308 @@ impl GameManager {
         FloatingItemType::Wood
     }
     
+    /// Serialize player, raft and persistent entities for syncing over an external transport
+    pub fn export_world_snapshot(&self) -> Result<Vec<u8>, turbo::borsh::io::Error> {
+        let snapshot = WorldSnapshot {
+            player: self.game_state.player.clone(),
+            raft: self.game_state.raft.clone(),
+            player_entity_id: self.game_state.player_entity_id,
+            raft_entity_id: self.game_state.raft_entity_id,
+            entities: self.entity_storage.persistent_snapshot(),
+        };
+        turbo::borsh::to_vec(&snapshot)
+    }
+    
+    /// Replace the authoritative world state with one from `export_world_snapshot`.
+    /// Leaves the current state untouched if the bytes don't decode.
+    pub fn apply_world_snapshot(&mut self, bytes: &[u8]) -> Result<(), turbo::borsh::io::Error> {
+        let snapshot: WorldSnapshot = turbo::borsh::from_slice(bytes)?;
+        self.game_state.player = snapshot.player;
+        self.game_state.raft = snapshot.raft;
+        self.game_state.player_entity_id = snapshot.player_entity_id;
+        self.game_state.raft_entity_id = snapshot.raft_entity_id;
+        self.entity_storage = snapshot.entities;
+        self.entity_manager.rebuild_from_storage(&self.entity_storage);
+        Ok(())
+    }
+    
+    /// Write the player, raft, discovered recipes and world clock to local storage, along
+    /// with the options. Returns the runtime's error code if nothing could be saved.
+    pub fn save_game(&self) -> Result<(), i32> {
+        let (Some(player), Some(raft)) = (&self.game_state.player, &self.game_state.raft) else { return Ok(()); };
+        let save = SaveGame {
+            player: player.clone(),
+            raft: raft.clone(),
+            discovered_recipes: self.game_state.crafting_system.discovered_recipes.clone(),
+            game_mode: self.game_state.game_mode,
+            world_seed: self.game_state.world_seed,
+            day: self.game_state.day,
+            time_of_day: self.game_state.time_of_day,
+            walls: self.game_state.walls.clone(),
+            pinned_recipes: self.game_state.pinned_recipes.clone(),
+            fish_log: self.game_state.fish_log.clone(),
+            buoys: self.entity_manager.get_entities_by_type(&self.entity_storage, crate::components::entities::game_entity::EntityType::Buoy)
+                .into_iter()
+                .map(|buoy| buoy.get_world_position())
+                .collect(),
+            collector_water: self.game_state.collector_water,
+            craft_queue: self.game_state.crafting_system.queue.clone(),
+            play_time: self.game_state.play_time,
+        };
+        let mut data = Self::read_local_data();
+        data.save = Some(save);
+        data.options = Some(self.saved_options());
+        Self::write_local_data(&data)
+    }
+    
+    /// Write the options to local storage, keeping any saved session
+    pub fn save_options(&self) -> Result<(), i32> {
+        let mut data = Self::read_local_data();
+        data.options = Some(self.saved_options());
+        Self::write_local_data(&data)
+    }
+    
+    /// Restore settings and key bindings written by `save_options`, if any
+    pub fn load_options(&mut self) {
+        let Some(options) = Self::read_local_data().options else { return; };
+        self.game_state.settings = options.settings;
+        for (action, key) in options.key_bindings {
+            self.input_system.rebind(action, &key);
+        }
+        self.apply_settings();
+    }
+    
+    fn saved_options(&self) -> SavedOptions {
+        SavedOptions { settings: self.game_state.settings.clone(), key_bindings: self.input_system.key_bindings() }
+    }
+    
+    fn read_local_data() -> LocalData {
+        turbo::local::load().map(|bytes| decode_local_data(&bytes)).unwrap_or_default()
+    }
+    
+    fn write_local_data(data: &LocalData) -> Result<(), i32> {
+        let bytes = turbo::borsh::to_vec(data).map_err(|_| 0)?;
+        turbo::local::save(&bytes).map(|_| ())
+    }
+    
+    /// Restore a session written by `save_game` and drop into the Playing scene.
+    /// Returns false (leaving the current state alone) when there is no readable save.
+    pub fn load_game(&mut self) -> bool {
+        let Some(save) = Self::read_local_data().save else { return false; };
+        
+        // Fresh world for the saved seed; only the player, raft and buoys carry over as entities
+        self.world_system = WorldSystem::new(save.world_seed);
+        self.world_system.set_render_distance(self.game_state.settings.render_distance);
+        self.entity_manager = EntityManager::new();
+        self.entity_storage = EntityStorage::new();
+        let player_entity = self.entity_factory.create_player_from_existing(save.player.clone());
+        let raft_entity = self.entity_factory.create_raft(save.raft.center);
+        self.game_state.player_entity_id = Some(self.entity_manager.create_entity(&mut self.entity_storage, player_entity));
+        self.game_state.raft_entity_id = Some(self.entity_manager.create_entity(&mut self.entity_storage, raft_entity));
+        for position in save.buoys {
+            let buoy = self.entity_factory.create_buoy(position);
+            self.entity_manager.create_entity(&mut self.entity_storage, buoy);
+        }
+        
+        self.game_state.crafting_system.discovered_recipes = save.discovered_recipes;
+        self.game_state.crafting_system.queue = save.craft_queue;
+        self.game_state.pinned_recipes = save.pinned_recipes;
+        self.game_state.fish_log = save.fish_log;
+        self.game_state.collector_water = save.collector_water;
+        self.game_state.play_time = save.play_time;
+        if save.play_time >= self.game_state.next_kraken_time {
+            // Don't raise the kraken the moment a long session resumes
+            self.game_state.next_kraken_time = save.play_time + crate::constants::KRAKEN_RETURN_INTERVAL;
+        }
+        self.game_state.game_mode = save.game_mode;
+        self.game_state.world_seed = save.world_seed;
+        self.game_state.day = save.day;
+        self.game_state.time_of_day = save.time_of_day;
+        self.game_state.walls = save.walls;
+        self.game_state.ocean.get_or_insert_with(Ocean::new);
+        
+        // Snap the camera to the loaded position so the first frame doesn't pan in from the origin,
+        // and forget old vitals so the restored stats don't register as damage or healing
+        self.render_system.set_camera_target(save.player.pos);
+        self.render_system.snap_camera();
+        self.prev_player_vitals = None;
+        self.game_state.player = Some(save.player);
+        self.game_state.raft = Some(save.raft);
+        self.current_scene = SceneType::Playing;
+        true
+    }
+    
+    /// Rest on the raft until the next morning. Only allowed on the raft with no
+    /// sharks or monsters nearby and hunger/thirst above SLEEP_MIN_STAT.
+    pub fn try_sleep(&mut self) -> bool {
+        use crate::components::entities::game_entity::Entity;
+        use crate::components::entities::entity_factory::FishType;
+        let Some(player) = &self.game_state.player else { return false; };
+        if !player.on_raft || player.is_diving {
+            return false;
+        }
+        if player.hunger <= crate::constants::SLEEP_MIN_STAT || player.thirst <= crate::constants::SLEEP_MIN_STAT {
+            return false;
+        }
+        let threatened = self.entity_manager
+            .get_entities_near(&self.entity_storage, &player.pos, crate::constants::SLEEP_THREAT_RADIUS)
+            .into_iter()
+            .any(|e| match e {
+                Entity::Monster(_) => true,
+                Entity::Fish(f) => matches!(f.fish_type, FishType::Shark),
+                _ => false,
+            });
+        if threatened {
+            return false;
+        }
+
+        self.game_state.day += 1;
+        self.game_state.time_of_day = crate::constants::SLEEP_WAKE_TIME;
+        if let Some(player) = &mut self.game_state.player {
+            player.hunger = (player.hunger - crate::constants::SLEEP_HUNGER_COST).max(0.0);
+            player.thirst = (player.thirst - crate::constants::SLEEP_THIRST_COST).max(0.0);
+        }
+        self.render_system.trigger_transition_fade();
+        true
+    }
+    
+    /// Build a wall pixel at a raft-local point with the Builder, paying WALL_PIXEL_COST wood
+    pub fn build_wall(&mut self, x: f32, y: f32) {
+        let Some(player) = &mut self.game_state.player else { return; };
+        if player.inventory.get_count(crate::models::ocean::FloatingItemType::Wood) < crate::constants::WALL_PIXEL_COST {
+            return;
+        }
+        if place_wall_pixel(&mut self.game_state.walls, x, y) {
+            player.inventory.remove_material(crate::models::ocean::FloatingItemType::Wood, crate::constants::WALL_PIXEL_COST);
+        }
+    }
+    
+    /// Hammer blow against the walls at a raft-local point; broken and fallen pixels puff into debris
+    pub fn hammer_walls(&mut self, x: f32, y: f32) {
+        let Some(center) = self.game_state.raft.as_ref().map(|raft| raft.center) else { return; };
+        for debris in smash_walls(&mut self.game_state.walls, x, y, crate::constants::WALL_SMASH_RADIUS) {
+            let point = V3::new(center.x + debris.x, center.y + debris.y, 0.0);
+            for particle in self.spawn_system.spawn_impact_particles(&point, 1) {
+                let entity = self.entity_factory.create_particle(particle.pos, particle.vel);
+                let _ = self.entity_manager.create_entity(&mut self.entity_storage, entity);
+            }
+        }
+    }
+    
     /// Handle hook launching
-    pub fn launch_hook(&mut self, player_pos: &V3, direction: crate::math::Vec2) {
-        // Check if player already has an active hook
-        let has_active_hook = self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Hook)
-            .iter()
-            .any(|&hook_id| {
-                if let Some(entity) = self.entity_manager.get_entity(&self.entity_storage, hook_id) {
-                    if let crate::components::entities::game_entity::Entity::Hook(hook_entity) = entity {
-                        return hook_entity.hook.is_active();
+    pub fn launch_hook(&mut self, player_pos: &V3, direction: V3) {
+        let max_hooks = self.game_state.player.as_ref().map_or(1, |p| p.max_hooks);
+        if cast_hook(&mut self.entity_manager, &mut self.entity_storage, &mut self.entity_factory, player_pos, direction, max_hooks).is_some() {
+            self.resource_manager.play_sound(crate::constants::SOUND_HOOK_CAST);
+        }
+    }
+    
+    /// Throw the equipped spear along `direction`, using one up from the inventory
+    pub fn throw_spear(&mut self, player_pos: &V3, direction: V3) {
+        let Some(player) = self.game_state.player.as_mut() else { return; };
+        if player.throw_spear() {
+            launch_spear(&mut self.entity_manager, &mut self.entity_storage, &mut self.entity_factory, player_pos, direction);
+            self.resource_manager.play_sound(crate::constants::SOUND_HOOK_CAST);
+        }
+    }
+    
+    /// Fly thrown spears and resolve what they hit. Spent spears drop back into the water as
+    /// collectible spears, and anything they kill leaves a fish behind.
+    pub(crate) fn update_projectiles(&mut self) {
+        let world = &self.world_system;
+        let dive_mode = self.game_state.game_mode == GameMode::Dive;
+        let hits_terrain = |p: &V3| {
+            dive_mode && world.is_solid((p.x / crate::constants::PIXEL_SIZE).floor() as i32, (-p.z / crate::constants::PIXEL_SIZE).floor() as i32)
+        };
+        // The kraken lives on the surface, so only top-down throws can reach it
+        let kraken = if dive_mode { None } else { self.game_state.kraken.as_mut() };
+        let outcome = advance_projectiles(&mut self.entity_manager, &mut self.entity_storage, self.delta_time, hits_terrain, kraken);
+        for point in &outcome.impacts {
+            for particle in self.spawn_system.spawn_impact_particles(point, crate::constants::HOOK_IMPACT_PARTICLES) {
+                let entity = self.entity_factory.create_particle(particle.pos, particle.vel);
+                let _ = self.entity_manager.create_entity(&mut self.entity_storage, entity);
+            }
+        }
+        let drops = outcome.resting.iter().map(|&p| (p, crate::models::ocean::FloatingItemType::Spear))
+            .chain(outcome.kills.iter().map(|&p| (p, crate::models::ocean::FloatingItemType::Fish)));
+        for (point, item_type) in drops {
+            let item = self.entity_factory.create_floating_item(point, item_type);
+            let _ = self.entity_manager.create_entity(&mut self.entity_storage, item);
+        }
+    }
+    
+    /// Telegraphed shark bites: a shark near the diving player winds up (flashing, with a
+    /// warning sound) for SHARK_ATTACK_WINDUP, then bites if it has closed to SHARK_BITE_RANGE.
+    /// Surfacing or swimming clear during the windup dodges the bite.
+    pub(crate) fn update_shark_attacks(&mut self) {
+        let Some(player) = &self.game_state.player else { return; };
+        let (player_pos, diving) = (player.pos, player.is_diving);
+        let dt = self.delta_time;
+        let mut bites: Vec<V3> = Vec::new(); // Positions of sharks whose bite landed
+        for id in self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Fish) {
+            let Some(crate::components::entities::game_entity::Entity::Fish(fish)) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, id) else { continue; };
+            if fish.fish_type != crate::components::entities::entity_factory::FishType::Shark {
+                continue;
+            }
+            fish.attack_cooldown = (fish.attack_cooldown - dt).max(0.0);
+            let distance = fish.position.distance_to(&player_pos);
+            let underwater = diving && player_pos.z < 0.0;
+            let in_reach = underwater && distance <= crate::constants::SHARK_ATTACK_RANGE;
+            if fish.attack_windup > 0.0 {
+                fish.attack_windup -= dt;
+                if fish.attack_windup <= 0.0 {
+                    fish.attack_windup = 0.0;
+                    fish.attack_cooldown = crate::constants::SHARK_ATTACK_COOLDOWN;
+                    if underwater && distance <= crate::constants::SHARK_BITE_RANGE {
+                        bites.push(fish.position);
                     }
                 }
-                false
-            });
-        
-        if !has_active_hook {
-            // Create new hook entity
-            let hook = self.entity_factory.create_hook(0); // TODO: Use actual player ID
-            let hook_id = self.entity_manager.create_entity(&mut self.entity_storage, hook);
-            
-            // Launch the hook
-            if let Some(entity) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, hook_id) {
-                if let crate::components::entities::game_entity::Entity::Hook(hook_entity) = entity {
-                    hook_entity.hook.launch(*player_pos, direction);
-                    hook_entity.player_pos = *player_pos; // Store player position for line rendering
-                }
+            } else if in_reach && fish.attack_cooldown <= 0.0 {
+                fish.attack_windup = crate::constants::SHARK_ATTACK_WINDUP;
+                self.resource_manager.play_sound(crate::constants::SOUND_SHARK_WARNING);
+            }
+            fish.render_data.warning_flash = fish.attack_windup > 0.0;
+        }
+        // I-frames from the first bite shrug off any others in the same window
+        let Some(player) = self.game_state.player.as_mut() else { return; };
+        let mut bitten = false;
+        for shark_pos in bites {
+            bitten |= player.take_hit(crate::constants::SHARK_BITE_DAMAGE, shark_pos);
+        }
+        if bitten {
+            // Burst of impact particles around the player in the dive plane
+            for i in 0..crate::constants::SHARK_BITE_PARTICLES {
+                let angle = i as f32 / crate::constants::SHARK_BITE_PARTICLES as f32 * std::f32::consts::TAU;
+                let speed = 20.0 + turbo::random::f32() * 30.0;
+                let velocity = V3::new(angle.cos() * speed, 0.0, angle.sin() * speed);
+                let particle = self.entity_factory.create_particle(player_pos, velocity);
+                let _ = self.entity_manager.create_entity(&mut self.entity_storage, particle);
             }
         }
     }
     
     /// Update hook system
-    pub fn update_hooks(&mut self, player_pos: &V3, delta_time: f32) {
+    /// Releasing the cast button turns an outgoing hook around; holding the reel key
+    /// speeds up the return trip
+    pub fn update_hooks(&mut self, player_pos: &V3, delta_time: f32, input: &crate::components::input::input_system::InputState) {
         let mut hooks_to_remove = Vec::new();
         let mut collected_items = Vec::new();
+        let mut splashes: Vec<(V3, f32)> = Vec::new(); // (surface crossing point, hook speed)
+        let mut fight_outcomes = Vec::new();
+        let mut impacts: Vec<V3> = Vec::new(); // Where outgoing hooks struck the seafloor
+        // Only dive mode collides with terrain; surface casts skim over the deep seabed
+        let world = &self.world_system;
+        let dive_mode = self.game_state.game_mode == GameMode::Dive;
+        let hits_terrain = |p: &V3| {
+            dive_mode && world.is_solid((p.x / crate::constants::PIXEL_SIZE).floor() as i32, (-p.z / crate::constants::PIXEL_SIZE).floor() as i32)
+        };
         
-        // First, collect all item positions to avoid borrowing conflicts
-        let item_positions: Vec<(u32, V3)> = self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::FloatingItem)
+        // First, collect all item positions and hook radii to avoid borrowing conflicts
+        let item_positions: Vec<(u32, V3, f32)> = self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::FloatingItem)
             .into_iter()
             .filter_map(|item_id| {
-                if let Some(item_entity) = self.entity_manager.get_entity(&self.entity_storage, item_id) {
-                    Some((item_id, item_entity.get_world_position()))
+                if let Some(crate::components::entities::game_entity::Entity::FloatingItem(item)) = self.entity_manager.get_entity(&self.entity_storage, item_id) {
+                    Some((item_id, item.position, item.item_type.hook_radius()))
                 } else {
                     None
                 }
@@ -488,22 +1223,34 @@ impl GameManager {
             .collect();
         
         // Also collect all fish positions to avoid borrowing conflicts later
-        let fish_positions: Vec<(u32, V3)> = self
+        let fish_positions: Vec<(u32, V3, f32, bool)> = self
             .entity_manager
             .get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Fish)
             .into_iter()
             .filter_map(|fish_id| {
-                if let Some(fish_entity) = self.entity_manager.get_entity(&self.entity_storage, fish_id) {
-                    Some((fish_id, fish_entity.get_world_position()))
+                if let Some(crate::components::entities::game_entity::Entity::Fish(fish)) = self.entity_manager.get_entity(&self.entity_storage, fish_id) {
+                    Some((fish_id, fish.position, fish.fish_type.hook_radius(), fish.fish_type.fights_on_hook()))
                 } else {
                     None
                 }
             })
             .collect();
 
+        let fast_empty_retract = self.game_state.settings.fast_empty_retract;
+        let tool_stats = self.game_state.player.as_ref().map(|p| p.current_tool.stats()).unwrap_or(crate::models::player::Tool::Hook.stats());
+
         // Get all hook IDs first to avoid borrowing conflicts
         let hook_ids: Vec<u32> = self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Hook);
         
+        // Anything already on a hook stays there, so two hooks never carry (or collect) the same catch
+        let mut claimed: std::collections::HashSet<u32> = hook_ids.iter()
+            .filter_map(|&hook_id| match self.entity_manager.get_entity(&self.entity_storage, hook_id) {
+                Some(crate::components::entities::game_entity::Entity::Hook(hook_entity)) => Some(hook_entity.hook.attached_items.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        
         for hook_id in hook_ids {
             // We'll compute any pinning we need to do outside the hook's mutable borrow
             let mut pin_request: Option<(Vec<u32>, V3)> = None;
@@ -511,7 +1258,35 @@ impl GameManager {
             if let Some(entity) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, hook_id) {
                 if let crate::components::entities::game_entity::Entity::Hook(hook_entity) = entity {
                     // Update hook physics
-                    let hook_completed = hook_entity.hook.update(delta_time, *player_pos);
+                    hook_entity.hook.fast_empty_retract = fast_empty_retract;
+                    hook_entity.hook.max_length = tool_stats.hook_length.max(crate::constants::HOOK_MAX_LENGTH);
+                    if !input.cast_held {
+                        hook_entity.hook.start_retract();
+                    }
+                    hook_entity.hook.reel_multiplier = if input.reel_in { crate::constants::HOOK_REEL_MULTIPLIER } else { 1.0 };
+                    if let Some(outcome) = hook_entity.hook.update_fight(delta_time, input.reel_tap) {
+                        fight_outcomes.push(outcome);
+                    }
+                    let tip_before = hook_entity.hook.get_hook_tip_position();
+                    let hook_completed = hook_entity.hook.update(delta_time, *player_pos, hits_terrain);
+                    if let Some(point) = hook_entity.hook.terrain_impact.take() {
+                        impacts.push(point);
+                    }
+                    // Outgoing hooks tear pixels out of the kraken and bounce back off it
+                    if let (false, crate::models::hook::HookState::Extending, Some(kraken)) = (dive_mode, hook_entity.hook.state, self.game_state.kraken.as_mut()) {
+                        let tip = hook_entity.hook.get_hook_tip_position();
+                        if kraken.hit_circle(tip.x, tip.y, crate::constants::KRAKEN_HOOK_HIT_RADIUS) > 0 {
+                            hook_entity.hook.start_retract();
+                            impacts.push(tip);
+                        }
+                    }
+                    let tip_after = hook_entity.hook.get_hook_tip_position();
+                    // Splash wherever the tip breaks the surface, going in or coming out
+                    if (tip_before.z < 0.0) != (tip_after.z < 0.0) {
+                        let t = tip_before.z / (tip_before.z - tip_after.z);
+                        let crossing = tip_before.add(tip_after.sub(tip_before).scale(t));
+                        splashes.push((V3::new(crossing.x, crossing.y, 0.0), hook_entity.hook.velocity.length()));
+                    }
                     
                     if hook_completed {
                         // Hook has returned, collect attached items
@@ -523,25 +1298,25 @@ impl GameManager {
                         let hook_tip_pos = hook_entity.hook.get_hook_tip_position();
                         
                         // Check collisions with floating items
-                        for (item_id, item_pos) in &item_positions {
-                            let distance = hook_tip_pos.distance_to(item_pos);
-                            
-                            if distance <= 15.0 { // Hook collision range
-                                hook_entity.hook.attach_item(*item_id);
-                            }
-                        }
+                        attach_items_in_reach(&mut hook_entity.hook, &hook_tip_pos, &item_positions, &mut claimed);
                         
                         // Check collisions with fish (fishing mechanics) using pre-collected positions
-                        for (fish_id, fish_pos) in &fish_positions {
+                        for (fish_id, fish_pos, hook_radius, fights) in &fish_positions {
                             let distance = hook_tip_pos.distance_to(fish_pos);
 
                             // Fishing requires being underwater (negative z) and closer range
-                            if distance <= 12.0 && hook_tip_pos.z < -5.0 {
+                            if distance <= *hook_radius && hook_tip_pos.z < -5.0 {
                                 // Depth-based catch chance (avoid immutable borrow during mutable hook borrow)
-                                let depth = -hook_tip_pos.z;
-                                let catch_chance = if depth > 50.0 { 0.6 } else if depth > 20.0 { 0.5 } else { 0.3 };
-                                if turbo::random::f32() < catch_chance {
-                                    hook_entity.hook.attach_item(*fish_id);
+                                let catch_chance = hook_catch_chance(-hook_tip_pos.z, tool_stats.catch_bonus);
+                                // One fight per hook; big fish wait until the line is free
+                                let can_fight = hook_entity.hook.fight.is_none();
+                                if !claimed.contains(fish_id) && (!fights || can_fight) && turbo::random::f32() < catch_chance {
+                                    claimed.insert(*fish_id);
+                                    if *fights {
+                                        hook_entity.hook.start_fight(*fish_id);
+                                    } else {
+                                        hook_entity.hook.attach_item(*fish_id);
+                                    }
                                 }
                             }
                         }
@@ -566,12 +1341,40 @@ impl GameManager {
             }
         }
         
+        for outcome in fight_outcomes {
+            match outcome {
+                crate::models::hook::FightOutcome::Landed => self.ui_renderer.push_toast("Landed it! Reeling in".to_string(), crate::constants::NOTIFICATION_DURATION),
+                crate::models::hook::FightOutcome::Escaped => self.ui_renderer.push_toast("The fish got away".to_string(), crate::constants::NOTIFICATION_DURATION),
+                crate::models::hook::FightOutcome::Fighting => {}
+            }
+        }
+        
+        // A small puff of sediment where a hook struck terrain
+        for point in impacts {
+            for particle in self.spawn_system.spawn_impact_particles(&point, crate::constants::HOOK_IMPACT_PARTICLES) {
+                let entity = self.entity_factory.create_particle(particle.pos, particle.vel);
+                let _ = self.entity_manager.create_entity(&mut self.entity_storage, entity);
+            }
+        }
+        
+        // Faster hooks throw bigger splashes
+        for (point, speed) in splashes {
+            let count = ((speed * crate::constants::SPLASH_PARTICLES_PER_SPEED) as usize)
+                .clamp(crate::constants::SPLASH_MIN_PARTICLES, crate::constants::SPLASH_MAX_PARTICLES);
+            for particle in self.spawn_system.spawn_impact_particles(&point, count) {
+                let velocity = particle.vel.scale(speed * crate::constants::SPLASH_SPEED_SCALE);
+                let entity = self.entity_factory.create_particle(particle.pos, velocity);
+                let _ = self.entity_manager.create_entity(&mut self.entity_storage, entity);
+            }
+        }
+        
         // Remove completed hooks
         for hook_id in hooks_to_remove {
             let _ = self.entity_manager.remove_entity(&mut self.entity_storage, hook_id);
         }
         
         // Collect items that were attached to hooks
+        let mut treasures_opened = 0;
         for item_id in collected_items {
             if let Some(entity) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, item_id) {
                 match entity {
@@ -584,15 +1387,26 @@ impl GameManager {
                             if player.inventory.add_material(item_type, 1) {
                                 // Successfully added to inventory, remove the entity
                                 let _ = self.entity_manager.remove_entity(&mut self.entity_storage, item_id);
+                                self.resource_manager.play_sound(crate::constants::SOUND_ITEM_COLLECTED);
+                                if item_type == crate::models::ocean::FloatingItemType::Treasure {
+                                    treasures_opened += 1;
+                                }
+                            } else {
+                                self.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
                             }
                         }
                     },
-                    crate::components::entities::game_entity::Entity::Fish(_fish_entity) => {
+                    crate::components::entities::game_entity::Entity::Fish(fish_entity) => {
+                        let fish_type = fish_entity.fish_type;
                         // Convert caught fish to fish item
                         if let Some(player) = &mut self.game_state.player {
                             if player.inventory.add_material(crate::models::ocean::FloatingItemType::Fish, 1) {
                                 // Successfully added fish to inventory, remove the entity
                                 let _ = self.entity_manager.remove_entity(&mut self.entity_storage, item_id);
+                                self.game_state.record_fish_catch(fish_type);
+                                self.resource_manager.play_sound(crate::constants::SOUND_ITEM_COLLECTED);
+                            } else {
+                                self.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
                             }
                         }
                     },
@@ -600,6 +1414,9 @@ impl GameManager {
                 }
             }
         }
+        for _ in 0..treasures_opened {
+            self.open_floating_treasure();
+        }
     }
     
     /// Calculate the chance to catch a fish based on depth and fish type
@@ -624,10 +1441,7 @@ impl GameManager {
             
             // Player tool bonus (could be expanded for fishing rod)
             let tool_modifier = if let Some(player) = &self.game_state.player {
-                match player.current_tool {
-                    crate::models::player::Tool::Hook => 1.0,
-                    _ => 0.5, // Other tools are less effective for fishing
-                }
+                player.current_tool.stats().catch_bonus // Non-hook tools are less effective for fishing
             } else {
                 1.0
             };
@@ -645,7 +1459,7 @@ impl GameManager {
             return;
         }
         
-        let collection_range = 20.0; // Manual collection range
+        let collection_range = crate::constants::ITEM_PICKUP_RANGE;
         
         let mut items_to_collect = Vec::new();
         
@@ -662,6 +1476,7 @@ impl GameManager {
         }
         
         // Collect the items
+        let mut treasures_opened = 0;
         for item_id in items_to_collect {
             if let Some(entity) = self.entity_manager.get_entity_mut_by_id(&mut self.entity_storage, item_id) {
                 // Get the item type from the entity
@@ -674,36 +1489,363 @@ impl GameManager {
                         if player.inventory.add_material(item_type, 1) {
                             // Successfully added to inventory, remove the entity
                             let _ = self.entity_manager.remove_entity(&mut self.entity_storage, item_id);
+                            if item_type == crate::models::ocean::FloatingItemType::Treasure {
+                                treasures_opened += 1;
+                            }
+                        } else {
+                            self.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
                         }
                     }
                 }
             }
         }
+        for _ in 0..treasures_opened {
+            self.open_floating_treasure();
+        }
+    }
+    
+    /// Sweep the net: collect every floating item within `radius` of the player in one go.
+    /// Items that don't fit in the inventory stay in the water. Returns how many were caught.
+    pub fn net_collect(&mut self, player_pos: &V3, radius: f32) -> u32 {
+        let in_reach: Vec<(u32, crate::models::ocean::FloatingItemType)> = self.entity_manager
+            .get_entities_in_area(&self.entity_storage, player_pos, radius)
+            .into_iter()
+            .filter_map(|e| match e {
+                crate::components::entities::game_entity::Entity::FloatingItem(item) => Some((item.id, item.item_type)),
+                _ => None,
+            })
+            .collect();
+        let Some(player) = self.game_state.player.as_mut() else { return 0; };
+        let mut collected = 0;
+        let mut treasures_opened = 0;
+        let mut inventory_full = false;
+        for (item_id, item_type) in in_reach {
+            if player.inventory.add_material(item_type, 1) {
+                let _ = self.entity_manager.remove_entity(&mut self.entity_storage, item_id);
+                collected += 1;
+                if item_type == crate::models::ocean::FloatingItemType::Treasure {
+                    treasures_opened += 1;
+                }
+            } else {
+                inventory_full = true;
+            }
+        }
+        if collected > 0 {
+            self.resource_manager.play_sound(crate::constants::SOUND_ITEM_COLLECTED);
+            self.ui_renderer.push_notification(format!("Netted {} items", collected), crate::constants::NOTIFICATION_DURATION);
+        }
+        if inventory_full {
+            self.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
+        }
+        for _ in 0..treasures_opened {
+            self.open_floating_treasure();
+        }
+        collected
+    }
+    
+    /// Roll `TreasureLoot::FLOATING` for a treasure just pulled from the water and stow it.
+    /// Whatever doesn't fit is lost with an "Inventory full" toast.
+    fn open_floating_treasure(&mut self) {
+        let loot = crate::models::loot::TreasureLoot::FLOATING.roll();
+        let Some(player) = self.game_state.player.as_mut() else { return; };
+        let stowed = loot.iter().filter(|&&(item_type, qty)| player.inventory.add_material(item_type, qty)).count();
+        if stowed < loot.len() {
+            self.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
+        }
+        self.ui_renderer.push_notification(format!("Treasure held: {}", crate::models::loot::describe_loot(&loot)), crate::constants::NOTIFICATION_DURATION);
+    }
+    
+    /// Compare player vitals with last frame and emit floating feedback numbers
+    fn update_stat_feedback(&mut self) {
+        let Some(player) = &self.game_state.player else {
+            self.prev_player_vitals = None;
+            return;
+        };
+        let vitals = (player.health, player.hunger, player.thirst);
+        let (sx, sy) = self.render_system.world_to_screen(&player.pos);
+        let (x, y) = (sx - 8.0, sy - 28.0);
+        if let Some((prev_health, prev_hunger, prev_thirst)) = self.prev_player_vitals {
+            let health_delta = vitals.0 - prev_health;
+            if health_delta.abs() >= 1.0 {
+                // Sudden hits/heals show immediately
+                let (text, color) = if health_delta > 0.0 {
+                    (format!("+{}", health_delta.round() as i32), crate::constants::UI_TEXT_GREEN)
+                } else {
+                    (format!("{}", health_delta.round() as i32), crate::constants::UI_TEXT_RED)
+                };
+                self.ui_renderer.push_floating_text(text, x, y, color);
+            } else if health_delta < 0.0 {
+                // Slow drains (starvation, drowning) are batched into whole points
+                self.health_drain_accum += health_delta;
+                if self.health_drain_accum <= -1.0 {
+                    let text = format!("{}", self.health_drain_accum.round() as i32);
+                    self.ui_renderer.push_floating_text(text, x, y, crate::constants::UI_TEXT_RED);
+                    self.health_drain_accum = 0.0;
+                }
+            }
+            let hunger_gain = vitals.1 - prev_hunger;
+            if hunger_gain >= 1.0 {
+                self.ui_renderer.push_floating_text(format!("+{} food", hunger_gain.round() as i32), x, y - 10.0, crate::constants::UI_TEXT_GREEN);
+            }
+            let thirst_gain = vitals.2 - prev_thirst;
+            if thirst_gain >= 1.0 {
+                self.ui_renderer.push_floating_text(format!("+{} water", thirst_gain.round() as i32), x, y - 20.0, crate::constants::UI_TEXT_BLUE);
+            }
+        }
+        self.prev_player_vitals = Some(vitals);
+        self.ui_renderer.update_floating_texts(self.delta_time);
+    }
+    
+    /// Control hint lines using the current key bindings
+    fn control_hint_lines(input_system: &InputSystem) -> Vec<String> {
+        use crate::components::input::input_system::InputKey;
+        let key = |action: InputKey| input_system.key_label(action);
+        vec![
+            format!("{}{}{}{}: Move, {}: Switch Tool, {}: Eat/Drink", key(InputKey::MoveUp), key(InputKey::MoveLeft), key(InputKey::MoveDown), key(InputKey::MoveRight), key(InputKey::SwitchTool), key(InputKey::EatFood)),
+            format!("{}: Inventory, {}: Crafting, {}: Fish Log", key(InputKey::OpenInventory), key(InputKey::OpenCrafting), key(InputKey::OpenCollectionLog)),
+            format!("{}: Cast (release to reel back), {}: Reel faster / tap to fight big fish", key(InputKey::UseTool), key(InputKey::ReelIn)),
+            format!("{}: Sprint, {}: Place Buoy, {}: Hide hints", key(InputKey::Sprint), key(InputKey::DeployBuoy), key(InputKey::ToggleControlHints)),
+        ]
+    }
+    
+    /// Unlock recipes the player's inventory now qualifies for, toasting each discovery
+    pub(crate) fn discover_recipes(&mut self) {
+        let Some(player) = &self.game_state.player else { return; };
+        for name in self.game_state.crafting_system.discover_recipes(&player.inventory) {
+            self.ui_renderer.push_toast(format!("Recipe discovered: {}", name), crate::constants::NOTIFICATION_DURATION);
+        }
+    }
+    
+    /// Queue a recipe from the player's inventory, spending its ingredients now. The result
+    /// arrives later through `update_crafting_queue`; a full queue is toasted instead.
+    pub(crate) fn craft(&mut self, recipe_id: &str) -> bool {
+        let Some(player) = self.game_state.player.as_mut() else { return false; };
+        let crafting = &mut self.game_state.crafting_system;
+        if !crafting.can_craft(recipe_id, &player.inventory) {
+            return false;
+        }
+        if !crafting.craft_item(recipe_id, &mut player.inventory) {
+            self.ui_renderer.push_toast("Crafting queue full".to_string(), crate::constants::NOTIFICATION_DURATION);
+            return false;
+        }
+        let name = crafting.recipes.iter().find(|r| r.id == recipe_id).map(|r| r.name.clone()).unwrap_or_default();
+        self.ui_renderer.push_toast(format!("Crafting {}", name), crate::constants::NOTIFICATION_DURATION);
+        true
+    }
+    
+    /// Advance queued crafts one frame. Deliveries play a sound, toast the result and check
+    /// for newly unlocked recipes.
+    pub(crate) fn update_crafting_queue(&mut self) {
+        let Some(player) = self.game_state.player.as_mut() else { return; };
+        let delivered = self.game_state.crafting_system.update(&mut player.inventory);
+        for name in &delivered {
+            self.resource_manager.play_sound(crate::constants::SOUND_CRAFT_SUCCESS);
+            self.ui_renderer.push_toast(format!("Crafted {}", name), crate::constants::NOTIFICATION_DURATION);
+        }
+        if player.install_hook_upgrades() {
+            let max_hooks = player.max_hooks;
+            self.ui_renderer.push_toast(format!("Hooks out at once: {}", max_hooks), crate::constants::NOTIFICATION_DURATION);
+        }
+        if !delivered.is_empty() {
+            self.discover_recipes();
+        }
+    }
+    
+    /// Raise the kraken once play time reaches its next appearance, sway it along the raft's
+    /// north edge hurting anyone on deck, and pay out its loot once it has been torn apart
+    pub(crate) fn update_kraken(&mut self) {
+        let Some(center) = self.game_state.raft.as_ref().map(|raft| raft.center) else { return; };
+        let Some(kraken) = self.game_state.kraken.as_mut() else {
+            if self.game_state.play_time >= self.game_state.next_kraken_time {
+                let size = (crate::constants::KRAKEN_COLS as f32 * crate::constants::PIXEL_SIZE, crate::constants::KRAKEN_ROWS as f32 * crate::constants::PIXEL_SIZE);
+                let origin = (center.x - size.0 * 0.5, center.y - crate::constants::KRAKEN_SPAWN_DISTANCE - size.1);
+                self.game_state.kraken = Some(crate::models::monster::MonsterGrid::new(origin.0, origin.1, crate::constants::KRAKEN_COLS, crate::constants::KRAKEN_ROWS, crate::constants::KRAKEN_COLOR));
+                self.ui_renderer.push_colored_notification("A kraken rises from the deep!".to_string(), crate::constants::UI_TEXT_RED, crate::constants::NOTIFICATION_DURATION);
+            }
+            return;
+        };
+        if kraken.center_of_mass().is_none() {
+            self.game_state.kraken = None;
+            self.game_state.next_kraken_time = self.game_state.play_time + crate::constants::KRAKEN_RETURN_INTERVAL;
+            let loot = crate::models::loot::TreasureLoot::KRAKEN.roll();
+            if let Some(player) = &mut self.game_state.player {
+                for &(item_type, quantity) in &loot {
+                    if !player.inventory.add_material(item_type, quantity) {
+                        self.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
+                    }
+                }
+            }
+            self.ui_renderer.push_notification(format!("Kraken defeated: {}", crate::models::loot::describe_loot(&loot)), crate::constants::NOTIFICATION_DURATION);
+            return;
+        }
+        kraken.update(
+            center.y - crate::constants::KRAKEN_STANDOFF,
+            center.x - crate::constants::KRAKEN_PATROL_RANGE,
+            center.x + crate::constants::KRAKEN_PATROL_RANGE,
+            crate::constants::KRAKEN_APPROACH_ACCEL,
+        );
+        // Tentacles over the deck slap whoever is standing on it
+        if kraken.grounded
+            && let Some(center) = kraken.center_of_mass()
+            && let Some(player) = self.game_state.player.as_mut().filter(|p| p.on_raft && !p.is_diving)
+        {
+            player.take_hit(crate::constants::KRAKEN_HIT_DAMAGE, V3::new(center.x, center.y, 0.0));
+        }
+    }
+    
+    /// Rain fills any carried water collectors; call once per frame
+    pub(crate) fn update_water_collectors(&mut self) {
+        let raining = self.game_state.ocean.as_ref().is_some_and(|o| o.is_raining());
+        let collectors = self.game_state.player.as_ref()
+            .map_or(0, |p| p.inventory.get_count(crate::models::ocean::FloatingItemType::WaterCollector));
+        self.game_state.collector_water = fill_collector(self.game_state.collector_water, collectors, raining, self.delta_time);
+    }
+    
+    /// Eat/drink input: when thirst is the more pressing need, drink rain during a storm
+    /// (at the surface) or from the water collector. Returns false to fall through to eating.
+    pub(crate) fn try_drink(&mut self) -> bool {
+        let raining = self.game_state.ocean.as_ref().is_some_and(|o| o.is_raining());
+        let Some(player) = self.game_state.player.as_mut() else { return false; };
+        if player.thirst >= 100.0 || player.thirst > player.hunger {
+            return false;
+        }
+        if raining && !player.is_diving {
+            player.drink_water(crate::constants::RAIN_DRINK_AMOUNT);
+            self.ui_renderer.push_notification("Drank rainwater".to_string(), crate::constants::NOTIFICATION_DURATION);
+            return true;
+        }
+        let sip = draw_collector_water(&mut self.game_state.collector_water, 100.0 - player.thirst);
+        if sip <= 0.0 {
+            return false;
+        }
+        player.drink_water(sip);
+        self.ui_renderer.push_notification("Drank from the water collector".to_string(), crate::constants::NOTIFICATION_DURATION);
+        true
+    }
+    
+    /// Throw items back into the water as floating pickups scattered around the player.
+    /// One entity per unit so re-collecting with the hook returns the full stack.
+    pub fn drop_items(&mut self, item_type: crate::models::ocean::FloatingItemType, quantity: u32) {
+        let Some(player_pos) = self.game_state.player.as_ref().map(|p| p.pos) else { return; };
+        for i in 0..quantity {
+            let angle = i as f32 / quantity as f32 * std::f32::consts::TAU;
+            let offset = crate::constants::DROP_ITEM_OFFSET + turbo::random::f32() * crate::constants::DROP_ITEM_SCATTER;
+            let position = match self.game_state.game_mode {
+                GameMode::Raft => V3::new(player_pos.x + angle.cos() * offset, player_pos.y + angle.sin() * offset, player_pos.z),
+                GameMode::Dive => V3::new(player_pos.x + angle.cos() * offset, player_pos.y, player_pos.z + angle.sin() * offset),
+            };
+            let item = self.entity_factory.create_floating_item(position, item_type);
+            let _ = self.entity_manager.create_entity(&mut self.entity_storage, item);
+        }
+    }
+    
+    /// Throw every bag item back into the sea; the hotbar is kept
+    pub(crate) fn drop_bag_items(&mut self) {
+        let Some(player) = self.game_state.player.as_mut() else { return; };
+        let stacks = player.inventory.empty_bag();
+        if stacks.is_empty() {
+            return;
+        }
+        for (item_type, quantity) in stacks {
+            self.drop_items(item_type, quantity);
+        }
+        self.ui_renderer.push_toast("Dropped bag contents".to_string(), crate::constants::NOTIFICATION_DURATION);
+    }
+    
+    /// Anchor a buoy at the player's position, paying BUOY_COST from the inventory.
+    /// Returns a message explaining why nothing was placed.
+    pub fn deploy_buoy(&mut self) -> Result<(), String> {
+        let buoy_count = self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Buoy).len();
+        if buoy_count >= crate::constants::MAX_BUOYS {
+            return Err("Buoy limit reached".to_string());
+        }
+        let Some(player) = &mut self.game_state.player else { return Err("No player".to_string()); };
+        if player.is_diving {
+            return Err("Surface to place a buoy".to_string());
+        }
+        if crate::constants::BUOY_COST.iter().any(|(item, amount)| player.inventory.get_count(*item) < *amount) {
+            return Err(format!("Need {}", crate::models::loot::describe_loot(&crate::constants::BUOY_COST)));
+        }
+        for (item, amount) in crate::constants::BUOY_COST {
+            player.inventory.remove_material(item, amount);
+        }
+        let buoy = self.entity_factory.create_buoy(player.pos);
+        let _ = self.entity_manager.create_entity(&mut self.entity_storage, buoy);
+        Ok(())
+    }
+    
+    /// Prompt for the nearest thing the player can act on: a floating item in pickup range,
+    /// a mineable seabed block within reach, or the surface just above a diver. None clears it.
+    fn interaction_hint(&self) -> Option<String> {
+        use crate::components::input::input_system::InputKey;
+        let player = self.game_state.player.as_ref()?;
+        let key = |action: InputKey| self.input_system.key_label(action);
+        let nearest_item = self.entity_manager.get_entities_near(&self.entity_storage, &player.pos, crate::constants::ITEM_PICKUP_RANGE)
+            .into_iter()
+            .filter_map(|e| match e {
+                crate::components::entities::game_entity::Entity::FloatingItem(item) => Some((item.position.distance_to(&player.pos), item.item_type)),
+                _ => None,
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some((_, item_type)) = nearest_item {
+            return Some(format!("Press {} to collect {}", key(InputKey::CollectItem), item_type.display_name()));
+        }
+        if !player.is_diving {
+            return None;
+        }
+        if let Some(block_type) = self.world_system.nearest_mineable_block(&player.pos, crate::constants::MINE_REACH) {
+            return Some(if player.current_tool.mines_terrain() {
+                format!("Press {} to mine {}", key(InputKey::UseTool), block_type.display_name())
+            } else {
+                format!("Equip an Axe to mine {}", block_type.display_name())
+            });
+        }
+        (player.pos.z > -crate::constants::SURFACE_PROMPT_DEPTH).then(|| format!("Hold {} to surface", key(InputKey::MoveUp)))
+    }
+    
+    /// (tension, progress) of the first hook currently fighting a fish
+    fn active_fish_fight(&self) -> Option<(f32, f32)> {
+        self.entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Hook)
+            .into_iter()
+            .find_map(|hook_id| match self.entity_manager.get_entity(&self.entity_storage, hook_id) {
+                Some(crate::components::entities::game_entity::Entity::Hook(hook_entity)) => hook_entity.hook.fight.as_ref().map(|f| (f.tension, f.progress)),
+                _ => None,
+            })
     }
     
     /// Render UI/HUD elements
     pub fn render_ui(&mut self) {
-        // Create UI renderer if needed
-        let mut ui_renderer = crate::components::renderer::UIRenderer::new();
+        let interaction_hint = self.interaction_hint()
+            .zip(self.game_state.player.as_ref().map(|p| self.render_system.world_to_screen(&p.pos)));
+        let fish_fight = self.active_fish_fight();
+        let ui_renderer = &mut self.ui_renderer;
         
         // Set UI mode based on current scene
         match self.current_scene {
             SceneType::Playing => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Playing),
+            SceneType::Inventory if self.game_state.settings.dock_inventory => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Playing),
             SceneType::Inventory => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Inventory),
             SceneType::Crafting => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Crafting),
             SceneType::Paused => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Paused),
+            SceneType::Options => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Options),
+            SceneType::MainMenu => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::MainMenu),
             _ => ui_renderer.set_ui_mode(crate::components::renderer::ui_renderer::UIMode::Playing),
         }
+        if self.current_scene == SceneType::MainMenu || (self.current_scene == SceneType::Options && self.options_return_scene == SceneType::MainMenu) {
+            ui_renderer.set_main_menu_state(crate::components::renderer::ui_renderer::MainMenuState {
+                seed: self.game_state.world_seed,
+                loadout: self.game_state.loadout.name().to_string(),
+                selected: self.menu_selection,
+            });
+        }
 
         // Feed HUD from authoritative GameState
         if let Some(player) = &self.game_state.player {
-            let tool_name = match player.current_tool { 
-                crate::models::player::Tool::Hook => "Hook",
-                crate::models::player::Tool::Builder => "Builder",
-                crate::models::player::Tool::Axe => "Axe",
-                crate::models::player::Tool::Hammer => "Hammer",
-            }.to_string();
-            let status = if player.is_diving { "Diving" } else if player.on_raft { "On Raft" } else { "Swimming" }.to_string();
+            let tool_name = player.current_tool.name().to_string();
+            let mut status = if player.is_diving { "Diving" } else if player.on_raft { "On Raft" } else { "Swimming" }.to_string();
+            if self.game_state.ocean.as_ref().is_some_and(|o| o.is_raining()) {
+                status.push_str(" (Raining)");
+            }
             let player_pos_str = Some(format!("Player: ({:.1}, {:.1}, {:.1})", player.pos.x, player.pos.y, player.pos.z));
             let raft_pos_str = self.game_state.raft.as_ref().map(|r| format!("Raft: ({:.1}, {:.1}, {:.1})", r.center.x, r.center.y, r.center.z));
             // Build hotbar HUD items from slots 0..9 directly
@@ -715,6 +1857,12 @@ impl GameManager {
                     }
                 }
             }
+            // Quick-craft bar: result color and whether the pinned recipe is craftable now
+            let crafting = &self.game_state.crafting_system;
+            let quick_craft_items: Vec<(u32, bool)> = self.game_state.pinned_recipes.iter()
+                .filter_map(|id| crafting.recipes.iter().find(|r| &r.id == id))
+                .map(|r| (r.result.0.color(), crafting.can_craft(&r.id, &player.inventory)))
+                .collect();
             ui_renderer.set_hud_state(crate::components::renderer::ui_renderer::HudState {
                 tool: tool_name,
                 health: player.health,
@@ -724,15 +1872,31 @@ impl GameManager {
                 player_pos: player_pos_str,
                 raft_pos: raft_pos_str,
                 hotbar_items: Some(hotbar_items),
-                hotbar_active: None,
+                hotbar_active: player.inventory.selected_slot.filter(|&i| i < 10),
+                quick_craft: quick_craft_items,
+                carry_weight: player.inventory.total_weight(),
+                world_seed: self.game_state.world_seed,
+                breath: player.is_diving.then(|| player.breath / player.max_breath()),
+                stamina: (player.stamina < crate::constants::PLAYER_MAX_STAMINA).then(|| (player.stamina / crate::constants::PLAYER_MAX_STAMINA, player.stamina_exhausted)),
+                wind: (self.game_state.wind.x, self.game_state.wind.y),
+                facing: player.facing,
+                control_hints: if self.game_state.settings.show_control_hints { Some(Self::control_hint_lines(&self.input_system)) } else { None },
+                interaction_hint,
+                collector_water: (player.inventory.get_count(crate::models::ocean::FloatingItemType::WaterCollector) > 0).then_some(self.game_state.collector_water),
+                fish_fight,
+                temperature: player.temperature,
+                day: self.game_state.day,
+                survival_time: self.game_state.play_time,
             });
+            ui_renderer.update_displayed_vitals(self.delta_time);
         }
 
         // Minimap: project nearby entities relative to player
         let mut points: Vec<crate::components::renderer::ui_renderer::MinimapPoint> = Vec::new();
-        let center = (40.0, 40.0);
-        let scale = 0.1; // world units to minimap pixels
-        let minimap_range = crate::constants::MINIMAP_RANGE; // Only show entities within range of player
+        let center = (crate::constants::MINIMAP_SIZE * 0.5, crate::constants::MINIMAP_SIZE * 0.5);
+        let scale = ui_renderer.minimap_scale(); // world units to minimap pixels
+        let minimap_range = ui_renderer.minimap_range(); // Only show entities within range of player
+        let (edge_min, edge_max) = (crate::constants::MINIMAP_EDGE_INSET, crate::constants::MINIMAP_SIZE - crate::constants::MINIMAP_EDGE_INSET);
         if let Some(player) = &self.game_state.player {
             // Player at center
             points.push(crate::components::renderer::ui_renderer::MinimapPoint { x: center.0, y: center.1, size: 3.0, color: crate::constants::PLAYER_ON_RAFT_COLOR });
@@ -743,18 +1907,19 @@ impl GameManager {
                 // Calculate distance from player
                 let distance = ((pos.x - player.pos.x).powi(2) + (pos.y - player.pos.y).powi(2)).sqrt();
                 
-                // Only show entities within minimap range
-                if distance <= minimap_range {
+                // Only show entities within minimap range; buoys always show, pinned to the edge
+                if distance <= minimap_range || ety == crate::components::entities::game_entity::EntityType::Buoy {
                     let dx = (pos.x - player.pos.x) * scale;
                     let dy = (pos.y - player.pos.y) * scale;
-                    let x = (center.0 + dx).clamp(4.0, 76.0);
-                    let y = (center.1 + dy).clamp(4.0, 76.0);
+                    let x = (center.0 + dx).clamp(edge_min, edge_max);
+                    let y = (center.1 + dy).clamp(edge_min, edge_max);
                     let (size, color) = match ety {
                         crate::components::entities::game_entity::EntityType::FloatingItem => (2.0, 0xFFFF00FF),
                         crate::components::entities::game_entity::EntityType::Fish => (2.0, 0x00FFFFFF),
                         crate::components::entities::game_entity::EntityType::Raft => (3.0, crate::constants::RAFT_WOOD_FLOOR_COLOR),
                         crate::components::entities::game_entity::EntityType::Monster => (3.0, 0xFF4444FF),
                         crate::components::entities::game_entity::EntityType::Particle => (1.0, 0x888888FF),
+                        crate::components::entities::game_entity::EntityType::Buoy => (3.0, crate::constants::BUOY_COLOR),
                         _ => (1.0, 0xFFFFFFFF),
                     };
                     points.push(crate::components::renderer::ui_renderer::MinimapPoint { x, y, size, color });
@@ -768,7 +1933,12 @@ impl GameManager {
             SceneType::Inventory => {
                 if let Some(player) = &self.game_state.player {
                     // If dragging, show drag preview with the dragged slot's color/qty under mouse
-                    let dragging_preview = if let Some(src) = self.game_state.dragging_slot {
+                    let dragging_preview = if let Some((src, amount)) = self.game_state.split_stack {
+                        player.inventory.get_slot(src).and_then(|slot| slot.item_type).map(|t| {
+                            let mouse = self.input_system.get_screen_mouse_position();
+                            (t.color(), amount, mouse.x, mouse.y)
+                        })
+                    } else if let Some(src) = self.game_state.dragging_slot {
                         if let Some(slot) = player.inventory.get_slot(src) {
                             if let Some(t) = slot.item_type {
                                 let mouse = self.input_system.get_screen_mouse_position();
@@ -776,18 +1946,45 @@ impl GameManager {
                             } else { None }
                         } else { None }
                     } else { None };
-                    ui_renderer.render_inventory_with_data_and_drag(Some(&player.inventory), dragging_preview);
+                    let docked = self.game_state.settings.dock_inventory;
+                    if docked {
+                        ui_renderer.render();
+                    }
+                    ui_renderer.render_inventory_with_data_and_drag(Some(&player.inventory), dragging_preview, self.game_state.inventory_filter, docked);
+                    if !docked {
+                        ui_renderer.render_common_ui();
+                    }
+                    if let Some(menu) = &self.game_state.inventory_context_menu {
+                        ui_renderer.render_inventory_context_menu(menu.screen_x, menu.screen_y);
+                    } else if dragging_preview.is_none() {
+                        let hovered = self.game_state.hovered_slot.and_then(|i| player.inventory.get_slot(i));
+                        if let Some(slot) = hovered.filter(|slot| !slot.is_empty()) {
+                            let mouse = self.input_system.get_screen_mouse_position();
+                            ui_renderer.render_item_tooltip(slot, mouse.x, mouse.y);
+                        }
+                    }
                 } else {
                     ui_renderer.render();
                 }
             },
             SceneType::Crafting => {
                 if let Some(player) = &self.game_state.player {
-                    ui_renderer.render_crafting_with_data(Some(&self.game_state.crafting_system), Some(&player.inventory));
+                    ui_renderer.render_crafting_with_data(Some(&self.game_state.crafting_system), Some(&player.inventory), &self.game_state.pinned_recipes, self.game_state.selected_recipe.as_deref(), Some(&player.current_tool));
+                    ui_renderer.render_common_ui();
                 } else {
                     ui_renderer.render();
                 }
             },
+            SceneType::MainMenu => ui_renderer.render(),
+            SceneType::Options if self.options_return_scene == SceneType::MainMenu => {
+                // Options opened from the title screen draw over it
+                ui_renderer.render_main_menu();
+                ui_renderer.render();
+            },
+            SceneType::CollectionLog => {
+                ui_renderer.render();
+                ui_renderer.render_collection_log(&self.game_state.fish_log);
+            },
             _ => {
                 ui_renderer.render();
                 // Overlay drag preview if dragging a hotbar slot while not in inventory
@@ -810,7 +2007,7 @@ impl GameManager {
 }
 
 /// Apply player input directly (no self borrowing)
-pub(crate) fn apply_player_input(player: &mut Player, input_state: &crate::components::input::input_system::InputState, movement: &V3) {
+pub(crate) fn apply_player_input(player: &mut Player, raft: &Raft, input_state: &crate::components::input::input_system::InputState, movement: &V3, is_night: bool, delta_time: f32) {
     // Tool switching
     if input_state.switch_tool {
         player.switch_tool();
@@ -822,22 +2019,31 @@ pub(crate) fn apply_player_input(player: &mut Player, input_state: &crate::compo
     
     if player.is_moving {
         player.last_movement = *movement;
+        player.facing = movement.y.atan2(movement.x);
     }
     
-    // Movement: raft vs swim vs dive
+    // Movement: raft vs swim vs dive, all slowed by carried weight and the cold. Sprinting only
+    // speeds up swimming; walking on the raft stays within the edge clamp at normal pace
+    let encumbrance = player.encumbrance_multiplier() * player.temperature_multiplier();
+    player.is_sprinting = input_state.sprint && player.is_moving && !player.on_raft && player.can_sprint();
+    let sprint = if player.is_sprinting { crate::constants::SPRINT_SPEED_MULTIPLIER } else { 1.0 };
     if player.on_raft {
         // Raft mode: slower on-raft movement; separate sailing inputs can be applied to raft
-        let move_speed = 1.0;
+        let move_speed = 1.0 * encumbrance;
         player.pos.x += movement.x * move_speed;
         player.pos.y += movement.y * move_speed;
+        // Edges block walking; holding shift steps off into the water on purpose
+        if !input_state.shift_held {
+            player.pos = raft.clamp_to_raft(player.pos);
+        }
     } else if player.is_diving {
         // Dive mode: horizontal is x, vertical is depth (z). Do NOT change world y while diving
-        let move_speed = 2.0;
+        let move_speed = 2.0 * encumbrance * sprint;
         player.pos.x += movement.x * move_speed;
         player.pos.z += movement.y * -move_speed; // up input (negative y) should reduce depth (towards 0)
     } else {
         // Top-down swim outside raft: move in x/y plane
-        let move_speed = 2.0;
+        let move_speed = 2.0 * encumbrance * sprint;
         player.pos.x += movement.x * move_speed;
         player.pos.y += movement.y * move_speed;
     }
@@ -848,14 +2054,462 @@ pub(crate) fn apply_player_input(player: &mut Player, input_state: &crate::compo
         player.consume_item(crate::models::ocean::FloatingItemType::Coconut);
     }
     
-    player.update_cooldowns();
+    player.update_cooldowns(is_night, delta_time);
+}
+
+/// Place a wall pixel at a raft-local point, growing the wall that covers it or starting a new
+/// one whose bottom row rests there. Pixels that would hang unsupported are refused.
+pub(crate) fn place_wall_pixel(walls: &mut Vec<crate::models::wall::WallGrid>, x: f32, y: f32) -> bool {
+    use crate::constants::{PIXEL_SIZE, WALL_GRID_COLS, WALL_GRID_ROWS};
+    if let Some(wall) = walls.iter_mut().find(|wall| wall.cell_at(x, y).is_some()) {
+        // The wall was supported before, so anything popped is the new pixel
+        return wall.absorb_debris(x, y) && wall.pop_unsupported().is_empty();
+    }
+    let snap = |v: f32| (v / PIXEL_SIZE).floor() * PIXEL_SIZE;
+    let origin_x = snap(x) - (WALL_GRID_COLS / 2) as f32 * PIXEL_SIZE;
+    let origin_y = snap(y) - (WALL_GRID_ROWS - 1) as f32 * PIXEL_SIZE;
+    let mut wall = crate::models::wall::WallGrid::new(origin_x, origin_y, WALL_GRID_COLS, WALL_GRID_ROWS, crate::constants::PIXEL_WALL_COLOR);
+    let placed = wall.absorb_debris(x, y);
+    if placed {
+        walls.push(wall);
+    }
+    placed
+}
+
+/// Break wall pixels within `radius` of a raft-local point, then drop whatever was left hanging.
+/// Returns every removed pixel as debris; emptied walls are discarded.
+pub(crate) fn smash_walls(walls: &mut Vec<crate::models::wall::WallGrid>, x: f32, y: f32, radius: f32) -> Vec<crate::math::Vec2> {
+    let mut debris = Vec::new();
+    for wall in walls.iter_mut() {
+        debris.extend(wall.destroy_circle_to_debris(x, y, radius));
+        debris.extend(wall.pop_unsupported());
+    }
+    walls.retain(|wall| !wall.is_empty());
+    debris
+}
+
+/// Collector water level after `dt` seconds: rises while it rains, per carried collector,
+/// and never exceeds COLLECTOR_CAPACITY
+pub(crate) fn fill_collector(level: f32, collectors: u32, raining: bool, dt: f32) -> f32 {
+    if !raining || collectors == 0 {
+        return level;
+    }
+    (level + crate::constants::COLLECTOR_FILL_RATE * collectors as f32 * dt).min(crate::constants::COLLECTOR_CAPACITY)
+}
+
+/// Take up to COLLECTOR_SIP (and no more than `wanted`) from the collector; an empty
+/// collector gives nothing. Returns the amount drunk.
+pub(crate) fn draw_collector_water(level: &mut f32, wanted: f32) -> f32 {
+    let sip = level.min(wanted).min(crate::constants::COLLECTOR_SIP);
+    if sip <= 0.0 {
+        return 0.0;
+    }
+    *level -= sip;
+    sip
+}
+
+/// Compute the hook aim vector from the screen mouse relative to the player's screen position,
+/// in the plane of the current game mode. Top-down maps screen y to world y, while dive
+/// (side view) maps screen y to -z, so aiming down the screen sends the hook deeper.
+pub(crate) fn hook_aim_direction(game_mode: GameMode, mouse: crate::math::Vec2, player_screen: (f32, f32)) -> V3 {
+    let dx = mouse.x - player_screen.0;
+    let dy = mouse.y - player_screen.1;
+    match game_mode {
+        GameMode::Raft => V3::new(dx, dy, 0.0),
+        GameMode::Dive => V3::new(dx, 0.0, -dy),
+    }
+}
+
+/// Launch a new hook unless `max_hooks` are already out; returns the new hook's id
+pub(crate) fn cast_hook(entity_manager: &mut EntityManager, entity_storage: &mut EntityStorage, entity_factory: &mut EntityFactory, player_pos: &V3, direction: V3, max_hooks: u32) -> Option<u32> {
+    let active_hooks = entity_manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Hook)
+        .into_iter()
+        .filter(|&hook_id| matches!(
+            entity_manager.get_entity(entity_storage, hook_id),
+            Some(crate::components::entities::game_entity::Entity::Hook(hook_entity)) if hook_entity.hook.is_active()
+        ))
+        .count() as u32;
+    if active_hooks >= max_hooks {
+        return None;
+    }
+    let hook = entity_factory.create_hook(0); // TODO: Use actual player ID
+    let hook_id = entity_manager.create_entity(entity_storage, hook);
+    if let Some(crate::components::entities::game_entity::Entity::Hook(hook_entity)) = entity_manager.get_entity_mut_by_id(entity_storage, hook_id) {
+        hook_entity.hook.launch(*player_pos, direction);
+        hook_entity.player_pos = *player_pos; // Store player position for line rendering
+    }
+    Some(hook_id)
+}
+
+/// Attach each floating item (id, position, hook radius) whose own hook radius reaches the
+/// tip. `claimed` holds everything already on a hook, so two hooks never share a catch.
+pub(crate) fn attach_items_in_reach(hook: &mut crate::models::hook::Hook, tip: &V3, items: &[(u32, V3, f32)], claimed: &mut std::collections::HashSet<u32>) {
+    for (item_id, item_pos, hook_radius) in items {
+        if tip.distance_to(item_pos) <= *hook_radius && claimed.insert(*item_id) {
+            hook.attach_item(*item_id);
+        }
+    }
+}
+
+/// Difficulty after `play_time` seconds of play: an exponential ease toward 1 with time
+/// constant DIFFICULTY_RAMP_SECONDS, so it never jumps and never decreases
+pub(crate) fn difficulty_after(play_time: f32) -> f32 {
+    1.0 - (-play_time / crate::constants::DIFFICULTY_RAMP_SECONDS).exp()
+}
+
+/// Spawn a thrown spear at the player heading along `direction`
+pub(crate) fn launch_spear(entity_manager: &mut EntityManager, entity_storage: &mut EntityStorage, entity_factory: &mut EntityFactory, player_pos: &V3, direction: V3) -> u32 {
+    let spear = entity_factory.create_projectile(*player_pos, direction);
+    entity_manager.create_entity(entity_storage, spear)
+}
+
+/// What thrown spears did this step
+#[derive(Default)]
+pub(crate) struct ProjectileOutcome {
+    pub resting: Vec<V3>, // Where spent spears came down
+    pub impacts: Vec<V3>, // Where spears struck something
+    pub kills: Vec<V3>,   // Where a spear finished off a fish or monster
+}
+
+/// Move each thrown spear one step. A spear stops at the first fish, monster, kraken pixel or
+/// solid terrain it touches, damaging creatures; spears are removed once spent.
+pub(crate) fn advance_projectiles(entity_manager: &mut EntityManager, entity_storage: &mut EntityStorage, delta_time: f32, hits_terrain: impl Fn(&V3) -> bool, mut kraken: Option<&mut crate::models::monster::MonsterGrid>) -> ProjectileOutcome {
+    use crate::components::entities::game_entity::{Entity, EntityType};
+    let mut outcome = ProjectileOutcome::default();
+    // Creatures a spear can strike, with their hit radii
+    let targets: Vec<(u32, V3, f32)> = [EntityType::Fish, EntityType::Monster].into_iter()
+        .flat_map(|entity_type| entity_manager.get_entity_ids_by_type(entity_type))
+        .filter_map(|id| match entity_manager.get_entity(entity_storage, id) {
+            Some(Entity::Fish(fish)) if fish.health.is_alive() => Some((id, fish.position, fish.fish_type.size() * 0.5)),
+            Some(Entity::Monster(monster)) if monster.health.is_alive() => Some((id, monster.position, monster.render_data.size * 0.5)),
+            _ => None,
+        })
+        .collect();
+    for projectile_id in entity_manager.get_entity_ids_by_type(EntityType::Projectile) {
+        let Some(Entity::Projectile(projectile)) = entity_manager.get_entity_mut_by_id(entity_storage, projectile_id) else { continue; };
+        let bullet = &mut projectile.bullet;
+        bullet.update(delta_time);
+        projectile.render_data.world_position = bullet.position;
+        let struck = targets.iter().find(|(_, pos, radius)| bullet.hits(pos, *radius)).map(|&(id, _, _)| (id, bullet.damage));
+        if struck.is_some() || hits_terrain(&bullet.position) {
+            bullet.spent = true;
+            outcome.impacts.push(bullet.position);
+        } else if let Some(kraken) = kraken.as_deref_mut()
+            && !bullet.spent
+            && kraken.hit_circle(bullet.position.x, bullet.position.y, crate::constants::KRAKEN_SPEAR_HIT_RADIUS) > 0
+        {
+            bullet.spent = true;
+            outcome.impacts.push(bullet.position);
+        }
+        let (spent, rest) = (bullet.spent, bullet.position);
+        if let Some((target_id, damage)) = struck {
+            let health = match entity_manager.get_entity_mut_by_id(entity_storage, target_id) {
+                Some(Entity::Fish(fish)) => Some(&mut fish.health),
+                Some(Entity::Monster(monster)) => Some(&mut monster.health),
+                _ => None,
+            };
+            if let Some(health) = health {
+                health.hp = (health.hp - damage).max(0.0);
+                if !health.is_alive() {
+                    outcome.kills.push(rest);
+                }
+            }
+        }
+        if spent {
+            outcome.resting.push(rest);
+            let _ = entity_manager.remove_entity(entity_storage, projectile_id);
+        }
+    }
+    outcome
+}
+
+/// Chance a hook tip touching a fish at `depth` hooks it; deeper water bites more readily
+/// and the tool's catch bonus (1.5x for the fishing rod) scales the result.
+pub(crate) fn hook_catch_chance(depth: f32, catch_bonus: f32) -> f32 {
+    let base = if depth > 50.0 { 0.6 } else if depth > 20.0 { 0.5 } else { 0.3 };
+    (base * catch_bonus).min(0.95)
 }
 
 /// Apply physics update directly (no self borrowing)
 pub(crate) fn apply_physics_update(player: &mut Player, water_current: &V3, delta_time: f32) {
-    if !player.on_raft {
+    if player.is_diving && !player.is_moving {
+        // Idle divers float gently back toward the surface
+        player.vel.x = 0.0;
+        player.vel.y = 0.0;
+        crate::models::PhysicsBody::diver().step(&mut player.pos, &mut player.vel, delta_time);
+    } else if !player.on_raft {
         // Swimmer is fixed against tide: no passive drift from water current
         player.vel = V3::zero();
         // Position changes only via input handling
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heavy_inventory_moves_slower_than_light() {
+        let raft = Raft::new(V3::zero());
+        let input = crate::components::input::input_system::InputState::default();
+        let movement = V3::new(1.0, 0.0, 0.0);
+        let swim_distance = |loaded: bool| {
+            let mut player = Player::new(V3::new(500.0, 500.0, 0.0));
+            player.on_raft = false;
+            player.inventory = crate::models::player::Inventory::new();
+            if loaded {
+                assert!(player.inventory.add_material(crate::models::ocean::FloatingItemType::Metal, 40));
+            }
+            let start = player.pos.x;
+            apply_player_input(&mut player, &raft, &input, &movement, false, 1.0 / 60.0);
+            player.pos.x - start
+        };
+        let light = swim_distance(false);
+        let heavy = swim_distance(true);
+        assert!(heavy < light, "overloaded swimmer moved {heavy}, unloaded moved {light}");
+    }
+
+    #[test]
+    fn facing_follows_movement_and_holds_while_idle() {
+        let raft = Raft::new(V3::zero());
+        let input = crate::components::input::input_system::InputState::default();
+        let mut player = Player::new(V3::new(500.0, 500.0, 0.0));
+        player.on_raft = false;
+
+        apply_player_input(&mut player, &raft, &input, &V3::new(1.0, 0.0, 0.0), false, 1.0 / 60.0);
+        assert!(player.facing.abs() < 1e-4);
+        // Up on screen is negative y
+        apply_player_input(&mut player, &raft, &input, &V3::new(0.0, -1.0, 0.0), false, 1.0 / 60.0);
+        assert!((player.facing + std::f32::consts::FRAC_PI_2).abs() < 1e-4);
+        apply_player_input(&mut player, &raft, &input, &V3::zero(), false, 1.0 / 60.0);
+        assert!((player.facing + std::f32::consts::FRAC_PI_2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn sprinting_drains_stamina_then_it_regenerates() {
+        use crate::constants::{PLAYER_MAX_STAMINA, STAMINA_RECOVER_THRESHOLD};
+        let raft = Raft::new(V3::zero());
+        let movement = V3::new(1.0, 0.0, 0.0);
+        let sprint = crate::components::input::input_system::InputState { sprint: true, ..Default::default() };
+        let mut player = Player::new(V3::new(500.0, 500.0, 0.0));
+        player.on_raft = false;
+        player.inventory = crate::models::player::Inventory::new();
+
+        let start = player.pos.x;
+        apply_player_input(&mut player, &raft, &sprint, &movement, false, 1.0 / 60.0);
+        let sprint_step = player.pos.x - start;
+        assert!(player.stamina < PLAYER_MAX_STAMINA);
+
+        // Hold sprint until stamina runs out; the player falls back to normal speed
+        for _ in 0..600 {
+            apply_player_input(&mut player, &raft, &sprint, &movement, false, 1.0 / 60.0);
+            if player.stamina_exhausted {
+                break;
+            }
+        }
+        assert_eq!(player.stamina, 0.0);
+        assert!(!player.can_sprint());
+        let start = player.pos.x;
+        apply_player_input(&mut player, &raft, &sprint, &movement, false, 1.0 / 60.0);
+        assert!(player.pos.x - start < sprint_step);
+
+        // Still holding sprint, but exhausted: stamina refills until sprinting is allowed again
+        for _ in 0..600 {
+            apply_player_input(&mut player, &raft, &sprint, &movement, false, 1.0 / 60.0);
+            if player.is_sprinting {
+                break;
+            }
+        }
+        assert!(player.is_sprinting);
+        assert!(player.stamina >= STAMINA_RECOVER_THRESHOLD - 1.0);
+    }
+
+    #[test]
+    fn collector_fills_in_rain_up_to_capacity_and_runs_dry() {
+        use crate::constants::{COLLECTOR_CAPACITY, COLLECTOR_SIP};
+        assert_eq!(fill_collector(10.0, 1, false, 1.0), 10.0);
+        assert_eq!(fill_collector(10.0, 0, true, 1.0), 10.0);
+        assert!(fill_collector(10.0, 1, true, 1.0) > 10.0);
+        assert_eq!(fill_collector(COLLECTOR_CAPACITY - 1.0, 2, true, 60.0), COLLECTOR_CAPACITY);
+
+        let mut level = COLLECTOR_SIP + 5.0;
+        assert_eq!(draw_collector_water(&mut level, 100.0), COLLECTOR_SIP);
+        assert_eq!(draw_collector_water(&mut level, 100.0), 5.0);
+        assert_eq!(draw_collector_water(&mut level, 100.0), 0.0);
+        assert_eq!(level, 0.0);
+    }
+
+    #[test]
+    fn twin_hook_kit_allows_a_second_cast() {
+        let mut player = Player::new(V3::zero());
+        assert!(player.inventory.add_material(crate::models::ocean::FloatingItemType::HookUpgrade, 3));
+        assert!(player.install_hook_upgrades());
+        assert_eq!(player.max_hooks, crate::constants::MAX_HOOKS);
+        assert!(!player.install_hook_upgrades());
+
+        let mut manager = EntityManager::new();
+        let mut storage = EntityStorage::new();
+        let mut factory = EntityFactory::new();
+        let mut cast = |max_hooks| cast_hook(&mut manager, &mut storage, &mut factory, &V3::zero(), V3::new(1.0, 0.0, 0.0), max_hooks);
+        assert!(cast(player.max_hooks).is_some());
+        assert!(cast(player.max_hooks).is_some());
+        assert!(cast(player.max_hooks).is_none());
+        let hooks = manager.get_entity_ids_by_type(crate::components::entities::game_entity::EntityType::Hook);
+        assert_eq!(hooks.len(), 2);
+    }
+
+    #[test]
+    fn thrown_spear_damages_the_fish_it_hits() {
+        use crate::components::entities::game_entity::{Entity, EntityType};
+        let mut manager = EntityManager::new();
+        let mut storage = EntityStorage::new();
+        let mut factory = EntityFactory::new();
+        let fish = factory.create_fish(V3::new(40.0, 0.0, -20.0), crate::components::entities::entity_factory::FishType::SmallFish);
+        let fish_id = manager.create_entity(&mut storage, fish);
+        launch_spear(&mut manager, &mut storage, &mut factory, &V3::new(0.0, 0.0, -20.0), V3::new(1.0, 0.0, 0.0));
+
+        let mut outcome = ProjectileOutcome::default();
+        for _ in 0..60 {
+            outcome = advance_projectiles(&mut manager, &mut storage, 1.0 / 60.0, |_| false, None);
+            if !outcome.impacts.is_empty() {
+                break;
+            }
+        }
+        let Some(Entity::Fish(fish)) = manager.get_entity(&storage, fish_id) else { panic!("fish was removed") };
+        assert_eq!(fish.health.hp, fish.health.max_hp - crate::constants::SPEAR_DAMAGE);
+        assert_eq!(outcome.resting.len(), 1);
+        assert_eq!(manager.get_entity_count(EntityType::Projectile), 0);
+    }
+
+    #[test]
+    fn difficulty_never_decreases_over_time() {
+        assert_eq!(difficulty_after(0.0), 0.0);
+        let samples: Vec<f32> = (0..=240).map(|minute| difficulty_after(minute as f32 * 60.0)).collect();
+        assert!(samples.windows(2).all(|pair| pair[1] >= pair[0]), "difficulty dipped: {:?}", samples);
+        assert!(samples.iter().all(|d| (0.0..=1.0).contains(d)));
+        assert!(samples[240] > 0.9);
+    }
+
+    #[test]
+    fn applied_world_snapshot_restores_entity_positions() {
+        use crate::components::entities::entity_factory::FishType;
+        let mut source = GameManager::new();
+        source.start_new_game();
+        let fish = source.entity_factory.create_fish(V3::new(30.0, 0.0, -45.0), FishType::TropicalFish);
+        source.entity_manager.create_entity(&mut source.entity_storage, fish);
+        let positions = |gm: &GameManager| {
+            let mut positions: Vec<(u32, V3)> = gm.entity_manager.get_all_entities(&gm.entity_storage)
+                .into_iter()
+                .map(|e| (e.get_id(), e.get_world_position()))
+                .collect();
+            positions.sort_by_key(|(id, _)| *id);
+            positions
+        };
+
+        let bytes = source.export_world_snapshot().unwrap();
+        let mut target = GameManager::new();
+        target.apply_world_snapshot(&bytes).unwrap();
+        assert_eq!(positions(&target), positions(&source));
+        assert_eq!(target.game_state.player.as_ref().map(|p| p.pos), source.game_state.player.as_ref().map(|p| p.pos));
+        assert!(target.apply_world_snapshot(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn local_data_keeps_options_and_reads_older_saves() {
+        let old_save = || SaveGameV1 {
+            player: Player::new(V3::new(12.0, 0.0, 0.0)),
+            raft: Raft::new(V3::zero()),
+            discovered_recipes: vec!["net".to_string()],
+            game_mode: GameMode::Raft,
+            world_seed: 42,
+            day: 3,
+            time_of_day: 0.5,
+            walls: Vec::new(),
+        };
+        let options = || SavedOptions {
+            settings: GameSettings { master_volume: 0.3, render_scale: 2.0, ..GameSettings::default() },
+            key_bindings: vec![(crate::components::input::input_system::InputKey::Dive, "X".to_string())],
+        };
+        // Saves written before options were stored still load, with no options
+        let legacy = decode_local_data(&turbo::borsh::to_vec(&old_save()).unwrap());
+        assert_eq!(legacy.save.as_ref().map(|s| s.world_seed), Some(42));
+        assert!(legacy.options.is_none());
+        // Saves from before pins, the fish log, buoys, collector water, the craft queue and play time load with those empty
+        let v1 = decode_local_data(&turbo::borsh::to_vec(&LocalDataV1 { save: Some(old_save()), options: Some(options()) }).unwrap());
+        let v1_save = v1.save.unwrap();
+        assert_eq!(v1_save.day, 3);
+        assert!(v1_save.buoys.is_empty() && v1_save.pinned_recipes.is_empty() && v1_save.craft_queue.is_empty());
+        assert_eq!(v1_save.play_time, 0.0);
+        assert_eq!(v1.options.map(|o| o.settings.render_scale), Some(2.0));
+
+        let save = SaveGame {
+            pinned_recipes: vec!["net".to_string()],
+            fish_log: vec![(crate::components::entities::entity_factory::FishType::TropicalFish, 2)],
+            buoys: vec![V3::new(40.0, -8.0, 0.0)],
+            collector_water: 35.0,
+            craft_queue: vec![crate::models::crafting::QueuedCraft {
+                name: "Rope".to_string(),
+                result: (crate::models::ocean::FloatingItemType::Rope, 1),
+                frames_left: 30,
+                total_frames: 120,
+            }],
+            play_time: 900.0,
+            ..SaveGame::from(old_save())
+        };
+        let data = decode_local_data(&turbo::borsh::to_vec(&LocalData { save: Some(save), options: Some(options()) }).unwrap());
+        let save = data.save.unwrap();
+        assert_eq!(save.day, 3);
+        assert_eq!(save.pinned_recipes, vec!["net".to_string()]);
+        assert_eq!(save.fish_log.len(), 1);
+        assert_eq!(save.buoys.len(), 1);
+        assert_eq!(save.collector_water, 35.0);
+        assert_eq!(save.craft_queue.len(), 1);
+        assert_eq!(save.craft_queue[0].frames_left, 30);
+        assert_eq!(save.play_time, 900.0);
+        let options = data.options.unwrap();
+        assert_eq!(options.settings.master_volume, 0.3);
+        assert_eq!(options.key_bindings[0].1, "X");
+        assert!(decode_local_data(&[1, 2, 3]).save.is_none());
+    }
+
+    #[test]
+    fn fishing_rod_casts_catch_more_readily_than_the_hook() {
+        use crate::models::player::Tool;
+        for depth in [10.0, 30.0, 80.0] {
+            let rod = hook_catch_chance(depth, Tool::FishingRod.stats().catch_bonus);
+            let hook = hook_catch_chance(depth, Tool::Hook.stats().catch_bonus);
+            assert!(rod > hook, "at depth {}: rod {} vs hook {}", depth, rod, hook);
+        }
+    }
+
+    #[test]
+    fn dive_aim_points_deeper_when_aiming_down_the_screen() {
+        let mouse = crate::math::Vec2::new(110.0, 140.0);
+        let aim = hook_aim_direction(GameMode::Dive, mouse, (100.0, 100.0));
+        assert_eq!((aim.x, aim.y, aim.z), (10.0, 0.0, -40.0));
+        // Top-down keeps screen y as world y
+        let aim = hook_aim_direction(GameMode::Raft, mouse, (100.0, 100.0));
+        assert_eq!((aim.x, aim.y, aim.z), (10.0, 40.0, 0.0));
+    }
+
+    #[test]
+    fn barrel_hooks_from_farther_than_a_nail() {
+        use crate::models::ocean::FloatingItemType;
+        let tip = V3::new(40.0, 10.0, 0.0);
+        // Both items sit the same distance from the tip, between the two hook radii
+        let gap = (FloatingItemType::Nail.hook_radius() + FloatingItemType::Barrel.hook_radius()) * 0.5;
+        let items = [
+            (1, tip.add(V3::new(gap, 0.0, 0.0)), FloatingItemType::Barrel.hook_radius()),
+            (2, tip.add(V3::new(0.0, -gap, 0.0)), FloatingItemType::Nail.hook_radius()),
+        ];
+        let mut hook = crate::models::hook::Hook::new(0);
+        let mut claimed = std::collections::HashSet::new();
+        attach_items_in_reach(&mut hook, &tip, &items, &mut claimed);
+        assert_eq!(hook.attached_items, vec![1]);
+
+        // A barrel already on another hook stays there
+        let mut other = crate::models::hook::Hook::new(0);
+        attach_items_in_reach(&mut other, &tip, &items, &mut claimed);
+        assert!(other.attached_items.is_empty());
+    }
+}
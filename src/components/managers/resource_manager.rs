@@ -7,6 +7,7 @@ pub struct ResourceManager {
     sounds: HashMap<String, SoundResource>,
     data_files: HashMap<String, DataResource>,
     resource_cache: HashMap<String, CachedResource>,
+    master_volume: f32, // 0..1 applied to every sound played
 }
 
 impl ResourceManager {
@@ -16,6 +17,7 @@ impl ResourceManager {
             sounds: HashMap::new(),
             data_files: HashMap::new(),
             resource_cache: HashMap::new(),
+            master_volume: crate::constants::MASTER_VOLUME,
         }
     }
     
@@ -31,6 +33,22 @@ impl ResourceManager {
         self.sounds.insert(name.to_string(), sound);
     }
     
+    /// Volume every sound plays at, clamped to 0..1 (0 mutes)
+    pub fn set_master_volume(&mut self, volume: f32) {
+        self.master_volume = volume.clamp(0.0, 1.0);
+    }
+    
+    /// Play a registered sound through turbo's audio API. Unregistered names are ignored,
+    /// so callers never need to check; returns whether playback was requested.
+    pub fn play_sound(&self, name: &str) -> bool {
+        if !self.sounds.contains_key(name) || self.master_volume <= 0.0 {
+            return false;
+        }
+        turbo::audio::set_volume(name, self.master_volume);
+        turbo::audio::play(name);
+        true
+    }
+    
     /// Register a data file resource
     pub fn register_data_file(&mut self, name: &str, path: &str, file_type: DataFileType) {
         let data = DataResource::new(name, path, file_type);
@@ -150,6 +168,11 @@ impl ResourceManager {
         }).sum()
     }
     
+    /// Whether every named sprite has been loaded by the runtime's asset pipeline
+    pub fn sprites_available(&self, names: &[&str]) -> bool {
+        names.iter().all(|name| turbo::utils::sprite::get_source_data(name).is_some())
+    }
+    
     /// Preload a list of resources
     pub fn preload_resources(&mut self, resource_names: &[String]) -> Vec<String> {
         let mut failed_resources = Vec::new();
@@ -1,3 +1,5 @@
+use crate::timer::Timer;
+
 /// Manages different game scenes and transitions
 #[turbo::serialize]
 pub struct SceneManager {
@@ -17,6 +19,7 @@ impl SceneManager {
         scene_data.insert(SceneType::Inventory, SceneData::new("Inventory"));
         scene_data.insert(SceneType::Crafting, SceneData::new("Crafting"));
         scene_data.insert(SceneType::Paused, SceneData::new("Paused"));
+        scene_data.insert(SceneType::Options, SceneData::new("Options"));
         
         Self {
             current_scene: SceneType::MainMenu,
@@ -47,8 +50,9 @@ impl SceneManager {
     /// Check if transition to scene is allowed
     fn can_transition_to(&self, scene: &SceneType) -> bool {
         match (self.current_scene, scene) {
-            // Main menu can go to playing
+            // Main menu can go to playing or options
             (SceneType::MainMenu, SceneType::Playing) => true,
+            (SceneType::MainMenu, SceneType::Options) => true,
             
             // Playing can go to inventory, crafting, or paused
             (SceneType::Playing, SceneType::Inventory) => true,
@@ -59,8 +63,14 @@ impl SceneManager {
             (SceneType::Inventory, SceneType::Playing) => true,
             (SceneType::Crafting, SceneType::Playing) => true,
             
-            // Paused can return to playing
+            // Paused can return to playing, open options or quit to the main menu
             (SceneType::Paused, SceneType::Playing) => true,
+            (SceneType::Paused, SceneType::Options) => true,
+            (SceneType::Paused, SceneType::MainMenu) => true,
+            
+            // Options return to whichever menu opened them
+            (SceneType::Options, SceneType::MainMenu) => true,
+            (SceneType::Options, SceneType::Paused) => true,
             
             // Default: no transition allowed
             _ => false,
@@ -133,6 +143,7 @@ pub enum SceneType {
     Inventory,
     Crafting,
     Paused,
+    Options,
 }
 
 /// Data associated with a scene
@@ -189,8 +200,7 @@ impl SceneData {
 pub struct SceneTransition {
     pub from_scene: SceneType,
     pub to_scene: SceneType,
-    pub progress: f32,
-    pub duration: f32,
+    pub timer: Timer,
     pub transition_type: TransitionType,
 }
 
@@ -199,44 +209,44 @@ impl SceneTransition {
         Self {
             from_scene,
             to_scene,
-            progress: 0.0,
-            duration: 0.5, // 0.5 seconds
+            timer: Timer::new(0.5), // 0.5 seconds
             transition_type: TransitionType::Fade,
         }
     }
     
     /// Update transition
     pub fn update(&mut self, delta_time: f32) -> bool {
-        self.progress += delta_time / self.duration;
-        self.progress < 1.0
+        self.timer.tick(delta_time);
+        !self.timer.is_finished()
     }
     
     /// Get transition progress (0.0 to 1.0)
     pub fn get_progress(&self) -> f32 {
-        self.progress.min(1.0)
+        self.timer.progress()
     }
     
     /// Get transition alpha for rendering
     pub fn get_alpha(&self) -> f32 {
+        let progress = self.get_progress();
         match self.transition_type {
             TransitionType::Fade => {
-                if self.progress < 0.5 {
+                if progress < 0.5 {
                     // Fade out: 0.0 -> 1.0
-                    self.progress * 2.0
+                    progress * 2.0
                 } else {
                     // Fade in: 1.0 -> 0.0
-                    2.0 - self.progress * 2.0
+                    2.0 - progress * 2.0
                 }
             }
-            TransitionType::Slide => self.progress,
-            TransitionType::Zoom => self.progress,
-            TransitionType::Dissolve => self.progress,
+            TransitionType::Slide => progress,
+            TransitionType::Zoom => progress,
+            TransitionType::Dissolve => progress,
         }
     }
     
     /// Set transition duration
     pub fn set_duration(&mut self, duration: f32) {
-        self.duration = duration;
+        self.timer.duration = duration;
     }
 }
 
@@ -0,0 +1,5 @@
+use super::*;
+
+pub fn update(_gm: &mut GameManager) {
+    // Read-only view of GameState::fish_log; toggled by handle_scene_transitions
+}
@@ -4,33 +4,68 @@ use super::*;
 
 pub fn update(gm: &mut GameManager) {
     // Update recipe discovery
-    if let Some(player) = &gm.game_state.player {
-        gm.game_state.crafting_system.discover_recipes(&player.inventory);
-    }
+    gm.discover_recipes();
 
     // Handle crafting input (simplified - in a full implementation you'd track selected recipe)
     if gm.input_system.is_key_just_pressed(InputKey::CraftItem) {
         // Try to craft the first available recipe that can be crafted
         let available_recipes = gm.game_state.crafting_system.get_available_recipes();
-        if let Some(player) = &mut gm.game_state.player {
-            // First, find a craftable recipe id using only immutable access
-            let craftable_id: Option<String> = available_recipes
-                .into_iter()
-                .find(|recipe| gm.game_state.crafting_system.can_craft(&recipe.id, &player.inventory))
-                .map(|r| r.id.clone());
+        // First, find a craftable recipe id using only immutable access
+        let craftable_id: Option<String> = gm.game_state.player.as_ref().and_then(|player| available_recipes
+            .into_iter()
+            .find(|recipe| gm.game_state.crafting_system.can_craft(&recipe.id, &player.inventory))
+            .map(|r| r.id.clone()));
 
-            // Then, craft using a separate mutable borrow
-            if let Some(id) = craftable_id {
-                let _ = gm.game_state.crafting_system.craft_item(&id, &mut player.inventory);
-            }
+        // Then, craft using a separate mutable borrow
+        if let Some(id) = craftable_id {
+            gm.craft(&id);
         }
     }
     
     // Quick craft specific items with number keys
     if gm.input_system.is_key_just_pressed(InputKey::QuickItem1) {
-        if let Some(player) = &mut gm.game_state.player {
-            let _ = gm.game_state.crafting_system.craft_item("planks", &mut player.inventory);
+        gm.craft("planks");
+    }
+
+    // Left-click on CRAFT crafts the recipe; elsewhere on a row selects it for the detail/comparison view.
+    // Right-click pins it to the quick-craft bar
+    if gm.input_system.is_mouse_left_just_pressed() {
+        let mouse = gm.input_system.get_screen_mouse_position();
+        if let Some(id) = craft_button_at(gm, mouse) {
+            // The button is only drawn for craftable recipes; craft re-checks so a stale click consumes nothing
+            gm.craft(&id);
+        } else if let Some(id) = recipe_row_at(gm, mouse) {
+            gm.game_state.selected_recipe = Some(id);
+        }
+    }
+    if gm.input_system.is_mouse_right_just_pressed() {
+        let mouse = gm.input_system.get_screen_mouse_position();
+        if let Some(id) = recipe_row_at(gm, mouse) {
+            gm.game_state.toggle_pinned_recipe(&id);
         }
     }
 }
 
+/// Recipe id under a screen position (mirrors render_crafting_with_data layout)
+fn recipe_row_at(gm: &GameManager, mouse: V2) -> Option<String> {
+    let (w, h) = turbo::resolution();
+    let hit = |(x, y, rw, rh): (f32, f32, f32, f32)| mouse.x >= x && mouse.x <= x + rw && mouse.y >= y && mouse.y <= y + rh;
+    gm.game_state.crafting_system.get_available_recipes()
+        .into_iter()
+        .take(crate::components::renderer::ui_renderer::CRAFTING_VISIBLE_RECIPES)
+        .enumerate()
+        .find(|(index, _)| hit(crate::components::renderer::ui_renderer::crafting_recipe_row_rect(w, h, *index)))
+        .map(|(_, r)| r.id.clone())
+}
+
+/// Recipe id whose CRAFT button is under a screen position
+fn craft_button_at(gm: &GameManager, mouse: V2) -> Option<String> {
+    let (w, h) = turbo::resolution();
+    let hit = |(x, y, bw, bh): (f32, f32, f32, f32)| mouse.x >= x && mouse.x <= x + bw && mouse.y >= y && mouse.y <= y + bh;
+    gm.game_state.crafting_system.get_available_recipes()
+        .into_iter()
+        .take(crate::components::renderer::ui_renderer::CRAFTING_VISIBLE_RECIPES)
+        .enumerate()
+        .find(|(index, _)| hit(crate::components::renderer::ui_renderer::craft_button_rect(w, h, *index)))
+        .map(|(_, r)| r.id.clone())
+}
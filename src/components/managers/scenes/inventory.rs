@@ -4,25 +4,34 @@ use super::*;
 
 pub fn update(gm: &mut GameManager) {
     // Update recipe discovery based on current inventory
-    if let Some(player) = &gm.game_state.player {
-        gm.game_state.crafting_system.discover_recipes(&player.inventory);
+    gm.discover_recipes();
+
+    // Cycle the category filter (All -> Building -> Food -> Special)
+    if gm.input_system.is_key_just_pressed(InputKey::CycleInventoryFilter) {
+        gm.game_state.inventory_filter = crate::models::ocean::ItemCategory::cycle(gm.game_state.inventory_filter);
+    }
+
+    // Switch between the full-screen modal and the docked side panel
+    if gm.input_system.is_key_just_pressed(InputKey::ToggleInventoryDock) {
+        gm.game_state.settings.dock_inventory = !gm.game_state.settings.dock_inventory;
     }
 
-    // Mouse-based inventory interactions: select/drag/drop; context menu for Use/Destroy
+    // Mouse-based inventory interactions: select/drag/drop; context menu for Use/Destroy/To Hotbar/Drop
+    let mut dropped: Option<(crate::models::ocean::FloatingItemType, u32)> = None;
+    let mut drop_bag = false;
+    let sort_includes_hotbar = gm.game_state.settings.sort_includes_hotbar;
     if let Some(player) = &mut gm.game_state.player {
         let inv = &mut player.inventory;
         let mouse = gm.input_system.get_screen_mouse_position();
         let left_click = gm.input_system.is_mouse_left_just_pressed();
         let left_held = gm.input_system.is_mouse_left_held();
         let right_click = gm.input_system.is_mouse_right_just_pressed();
+        let right_held = gm.input_system.is_mouse_right_held();
+        let shift_held = gm.input_system.is_shift_held();
 
-        // Recreate panel layout to match full-screen 10-column UI
+        // Recreate panel layout to match the 10-column UI (full-screen or docked)
         let (w, h) = turbo::resolution();
-        let panel_margin = 8.0_f32;
-        let panel_x = panel_margin;
-        let panel_y = panel_margin;
-        let panel_w = w as f32 - panel_margin * 2.0;
-        let panel_h = h as f32 - panel_margin * 2.0;
+        let (panel_x, panel_y, panel_w, _) = crate::components::renderer::ui_renderer::inventory_panel_rect(w, h, gm.game_state.settings.dock_inventory);
 
         // Grid metrics
         let cols = 10usize; // full-screen bag grid columns
@@ -68,28 +77,60 @@ pub fn update(gm: &mut GameManager) {
             }
         }
 
-        // Handle context menu actions (Use/Destroy) if open and clicked
+        // The docked panel leaves the HUD hotbar visible; it is the same slots 0..9
+        if hovered_slot.is_none() && gm.game_state.settings.dock_inventory {
+            hovered_slot = crate::components::renderer::ui_renderer::hud_hotbar_slot_at(mouse.x, mouse.y, w, h);
+        }
+
+        gm.game_state.hovered_slot = hovered_slot;
+
+        // Sort / Drop All buttons beside the title
+        let clicked_button = (0..crate::components::renderer::ui_renderer::INVENTORY_BUTTONS.len()).find(|&i| {
+            let (bx, by, bw, bh) = crate::components::renderer::ui_renderer::inventory_button_rect(w, h, gm.game_state.settings.dock_inventory, i);
+            mouse.x >= bx && mouse.x <= bx + bw && mouse.y >= by && mouse.y <= by + bh
+        });
+        if left_click && gm.game_state.inventory_context_menu.is_none() {
+            match clicked_button {
+                Some(0) => inv.sort(sort_includes_hotbar),
+                Some(1) => drop_bag = true,
+                _ => {}
+            }
+        }
+
+        // Handle context menu actions (Use/Destroy/To Hotbar/Drop) if open and clicked
         if let Some(menu) = &gm.game_state.inventory_context_menu {
-            // Very simple hit areas below the cursor: two buttons stacked
-            let btn_w = 80.0_f32; let btn_h = 16.0_f32; let pad = 2.0_f32;
-            let use_rect = (menu.screen_x, menu.screen_y, btn_w, btn_h);
-            let destroy_rect = (menu.screen_x, menu.screen_y + btn_h + pad, btn_w, btn_h);
-            let clicked = left_click;
-            let mx = mouse.x; let my = mouse.y;
-            if clicked {
-                if mx >= use_rect.0 && mx <= use_rect.0 + use_rect.2 && my >= use_rect.1 && my <= use_rect.1 + use_rect.3 {
-                    // Use one item from the slot if consumable
-                    if let Some(slot) = inv.get_slot_mut(menu.slot_index) {
-                        if let Some(item_type) = slot.item_type { if item_type.is_consumable() { let _ = slot.remove_items(1); } }
+            // Buttons stacked below the cursor, shared with the menu renderer
+            let clicked_option = (0..crate::components::renderer::ui_renderer::CONTEXT_MENU_OPTIONS.len()).find(|&i| {
+                let (bx, by, bw, bh) = crate::components::renderer::ui_renderer::context_menu_button_rect(menu.screen_x, menu.screen_y, i);
+                mouse.x >= bx && mouse.x <= bx + bw && mouse.y >= by && mouse.y <= by + bh
+            });
+            if left_click {
+                match clicked_option {
+                    Some(0) => {
+                        // Use one item from the slot if consumable
+                        if let Some(slot) = inv.get_slot_mut(menu.slot_index) {
+                            if let Some(item_type) = slot.item_type { if item_type.is_consumable() { let _ = slot.remove_items(1); } }
+                        }
+                    }
+                    Some(1) => {
+                        if let Some(slot) = inv.get_slot_mut(menu.slot_index) { let _ = slot.remove_items(slot.quantity); }
+                    }
+                    Some(2) => {
+                        // Does nothing if the hotbar has no matching or empty slot
+                        if let Some(dst) = inv.move_to_hotbar(menu.slot_index) {
+                            inv.selected_slot = Some(dst);
+                        }
+                    }
+                    Some(3) => {
+                        // Empty the slot back into the sea; spawned once the inventory borrow ends
+                        if let Some(slot) = inv.get_slot_mut(menu.slot_index) {
+                            dropped = slot.item_type.map(|item_type| (item_type, slot.quantity));
+                            let _ = slot.remove_items(slot.quantity);
+                        }
                     }
-                    gm.game_state.inventory_context_menu = None;
-                } else if mx >= destroy_rect.0 && mx <= destroy_rect.0 + destroy_rect.2 && my >= destroy_rect.1 && my <= destroy_rect.1 + destroy_rect.3 {
-                    if let Some(slot) = inv.get_slot_mut(menu.slot_index) { let _ = slot.remove_items(slot.quantity); }
-                    gm.game_state.inventory_context_menu = None;
-                } else {
-                    // Clicked elsewhere closes menu
-                    gm.game_state.inventory_context_menu = None;
+                    _ => {} // Clicked elsewhere closes menu
                 }
+                gm.game_state.inventory_context_menu = None;
             }
         }
 
@@ -100,38 +141,43 @@ pub fn update(gm: &mut GameManager) {
         if !left_held {
             if let Some(src) = gm.game_state.dragging_slot.take() {
                 if let Some(dst) = hovered_slot {
+                    // Hotbar and bag share one slot space, so any slot can drop on any other
                     if src != dst {
-                        // Try merge first if same type and room, else swap
-                        let (src_type, src_qty, src_max) = if let Some(s) = inv.get_slot(src) { (s.item_type, s.quantity, s.max_stack) } else { (None, 0, 0) };
-                        let (dst_type, dst_qty, dst_max) = if let Some(s) = inv.get_slot(dst) { (s.item_type, s.quantity, s.max_stack) } else { (None, 0, 0) };
-                        let merged = if let (Some(st), Some(dt)) = (src_type, dst_type) {
-                            if st == dt && dst_qty < dst_max {
-                                let to_move = (dst_max - dst_qty).min(src_qty);
-                                if to_move > 0 {
-                                    if let Some(s) = inv.get_slot_mut(src) { let _ = s.remove_items(to_move); }
-                                    if let Some(d) = inv.get_slot_mut(dst) { let _ = d.add_items(st, to_move); }
-                                    true
-                                } else { false }
-                            } else { false }
-                        } else { false };
-                        if !merged {
-                            let _ = inv.swap_slots(src, dst);
-                        }
-                        inv.selected_slot = Some(dst);
-                    } else {
-                        inv.selected_slot = Some(dst);
+                        inv.merge_or_swap(src, dst);
                     }
+                    inv.selected_slot = Some(dst);
                 }
             }
         }
 
-        // Right click: open context menu for Use/Destroy on inventory slot
-        if right_click {
+        // Shift+right-drag: carry half the stack and drop it on an empty or matching slot on release.
+        // The items stay in the source slot until dropped; whatever doesn't fit stays behind.
+        if right_click && shift_held && gm.game_state.dragging_slot.is_none() {
+            gm.game_state.split_stack = hovered_slot
+                .and_then(|i| inv.get_slot(i).map(|s| (i, s.quantity / 2)))
+                .filter(|&(_, half)| half > 0);
+        }
+        let released_split = if right_held { None } else { gm.game_state.split_stack.take() };
+        if let (Some((src, amount)), Some(dst)) = (released_split, hovered_slot) {
+            let moved = inv.move_items(src, dst, amount);
+            if moved > 0 {
+                inv.selected_slot = Some(dst);
+            }
+        }
+
+        // Right click: open context menu for Use/Destroy/To Hotbar/Drop on inventory slot
+        if right_click && !shift_held {
             if let Some(slot_idx) = hovered_slot {
                 // Open context menu at mouse position
                 gm.game_state.inventory_context_menu = Some(super::super::game_manager::InventoryContextMenu { slot_index: slot_idx, screen_x: mouse.x, screen_y: mouse.y });
             }
         }
     }
-}
 
+    if let Some((item_type, quantity)) = dropped {
+        gm.drop_items(item_type, quantity);
+    }
+    if drop_bag {
+        gm.drop_bag_items();
+    }
+}
@@ -1,6 +1,54 @@
+use crate::components::input_system::InputKey;
+use crate::components::renderer::ui_renderer::{UIClickEvent, MAIN_MENU_BUTTONS};
+
 use super::*;
 
-pub fn update(_gm: &mut GameManager) {
-    // Mutate state only for this scene (none for now)
+pub fn update(gm: &mut GameManager) {
+    // Seed entry: digit keys append, Backspace erases, R rolls a random seed
+    let digit_keys = [
+        InputKey::QuickItem0, InputKey::QuickItem1, InputKey::QuickItem2, InputKey::QuickItem3, InputKey::QuickItem4,
+        InputKey::QuickItem5, InputKey::QuickItem6, InputKey::QuickItem7, InputKey::QuickItem8, InputKey::QuickItem9,
+    ];
+    let seed = &mut gm.game_state.world_seed;
+    for (digit, key) in digit_keys.into_iter().enumerate() {
+        if gm.input_system.is_key_just_pressed(key) && *seed < 10u32.pow(crate::constants::MAX_SEED_DIGITS - 1) {
+            *seed = *seed * 10 + digit as u32;
+        }
+    }
+    if gm.input_system.is_key_just_pressed(InputKey::EraseSeedDigit) {
+        *seed /= 10;
+    }
+    if gm.input_system.is_key_just_pressed(InputKey::RandomizeSeed) {
+        *seed = turbo::random::u32() % 10u32.pow(crate::constants::MAX_SEED_DIGITS);
+    }
+    // Starting loadout: Hardcore -> Normal -> Builder
+    if gm.input_system.is_key_just_pressed(InputKey::CycleLoadout) {
+        gm.game_state.loadout = gm.game_state.loadout.cycle();
+    }
+    
+    // Buttons: W/S or arrows move the highlight (skipping Continue without a save), Enter picks it
+    let count = MAIN_MENU_BUTTONS.len();
+    let step = |from: usize, forward: bool| if forward { (from + 1) % count } else { (from + count - 1) % count };
+    let up = gm.input_system.is_key_just_pressed(InputKey::MenuUp) || gm.input_system.is_key_just_pressed(InputKey::MoveUp);
+    let down = gm.input_system.is_key_just_pressed(InputKey::MenuDown) || gm.input_system.is_key_just_pressed(InputKey::MoveDown);
+    if up != down {
+        let mut next = step(gm.menu_selection, down);
+        if MAIN_MENU_BUTTONS[next].0 == "menu_continue" && !gm.save_available {
+            next = step(next, down);
+        }
+        gm.menu_selection = next;
+    }
+    let mut chosen = gm.input_system.is_key_just_pressed(InputKey::MenuConfirm).then(|| MAIN_MENU_BUTTONS[gm.menu_selection].0.to_string());
+    if gm.input_system.is_mouse_left_just_pressed() {
+        let mouse = gm.input_system.get_screen_mouse_position();
+        if let Some(UIClickEvent::ButtonClicked { element_id, .. }) = gm.ui_renderer.handle_click(&mouse) {
+            chosen = Some(element_id);
+        }
+    }
+    match chosen.as_deref() {
+        Some("menu_start") => gm.start_new_game(),
+        Some("menu_continue") => gm.continue_game(),
+        Some("menu_options") => gm.open_options(),
+        _ => {}
+    }
 }
-
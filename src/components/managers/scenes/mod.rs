@@ -3,6 +3,8 @@ pub mod playing;
 pub mod inventory;
 pub mod crafting;
 pub mod paused;
+pub mod collection_log;
+pub mod options;
 
 use crate::math::Vec2 as V2;
 use crate::components::managers::game_manager::GameManager;
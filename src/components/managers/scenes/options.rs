@@ -0,0 +1,50 @@
+use crate::components::input_system::InputKey;
+use crate::components::renderer::ui_renderer::UIClickEvent;
+
+use super::*;
+
+/// Actions the options panel can rebind, with their row captions
+pub(crate) const REBINDABLE_ACTIONS: [(InputKey, &str); 6] = [
+    (InputKey::UseTool, "Use tool / cast"),
+    (InputKey::ReelIn, "Reel in"),
+    (InputKey::Dive, "Dive"),
+    (InputKey::Sprint, "Sprint"),
+    (InputKey::EatFood, "Eat / drink"),
+    (InputKey::SwitchTool, "Switch tool"),
+];
+
+pub fn update(gm: &mut GameManager) {
+    // Waiting on a rebind: the next key or click becomes the binding, Escape cancels
+    if let Some(action) = gm.rebinding_action {
+        if gm.input_system.get_input_state().toggle_pause {
+            gm.rebinding_action = None;
+            gm.refresh_options_menu();
+        } else if let Some(key) = gm.input_system.just_pressed_key() {
+            gm.input_system.rebind(action, key);
+            gm.rebinding_action = None;
+            gm.refresh_options_menu();
+        }
+        return;
+    }
+
+    if !gm.input_system.is_mouse_left_just_pressed() {
+        return;
+    }
+    let mouse = gm.input_system.get_screen_mouse_position();
+    let Some(UIClickEvent::ButtonClicked { element_id, .. }) = gm.ui_renderer.handle_click(&mouse) else { return; };
+    match element_id.as_str() {
+        "options_volume_down" => gm.adjust_master_volume(-crate::constants::MASTER_VOLUME_STEP),
+        "options_volume_up" => gm.adjust_master_volume(crate::constants::MASTER_VOLUME_STEP),
+        "options_render_scale" => gm.cycle_render_scale(),
+        "options_sort_hotbar" => gm.game_state.settings.sort_includes_hotbar = !gm.game_state.settings.sort_includes_hotbar,
+        "options_back" => {
+            gm.close_options();
+            return;
+        }
+        id => {
+            let index = id.strip_prefix("options_bind_").and_then(|i| i.parse::<usize>().ok());
+            gm.rebinding_action = index.and_then(|i| REBINDABLE_ACTIONS.get(i)).map(|(action, _)| *action);
+        }
+    }
+    gm.refresh_options_menu();
+}
@@ -1,6 +1,22 @@
+use crate::components::renderer::ui_renderer::UIClickEvent;
+
 use super::*;
 
-pub fn update(_gm: &mut GameManager) {
-    // Mutate state only for this scene (none for now)
+pub fn update(gm: &mut GameManager) {
+    // Pause panel buttons are hit-tested through the UI element layer
+    if !gm.input_system.is_mouse_left_just_pressed() {
+        return;
+    }
+    let mouse = gm.input_system.get_screen_mouse_position();
+    let Some(UIClickEvent::ButtonClicked { element_id, .. }) = gm.ui_renderer.handle_click(&mouse) else { return; };
+    match element_id.as_str() {
+        "pause_resume" => gm.set_paused(false),
+        "pause_save" => match gm.save_game() {
+            Ok(()) => gm.ui_renderer.push_toast("Game saved".to_string(), crate::constants::NOTIFICATION_DURATION),
+            Err(code) => gm.ui_renderer.push_colored_notification(format!("Save failed ({})", code), crate::constants::UI_TEXT_ORANGE, crate::constants::NOTIFICATION_DURATION),
+        },
+        "pause_options" => gm.open_options(),
+        "pause_quit" => gm.quit_to_main_menu(),
+        _ => {}
+    }
 }
-
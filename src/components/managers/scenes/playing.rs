@@ -22,15 +22,108 @@ pub fn update(gm: &mut GameManager) {
     if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::QuickItem9) { if let Some(p) = &mut gm.game_state.player { let _ = p.use_quick_item(8); } }
     if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::QuickItem0) { if let Some(p) = &mut gm.game_state.player { let _ = p.use_quick_item(9); } }
 
+    gm.game_state.advance_time(gm.delta_time);
+    gm.game_state.play_time += gm.delta_time;
+    let difficulty = gm.difficulty();
+    if let Some(ocean) = gm.game_state.ocean.as_mut() {
+        ocean.update_wind(gm.game_state.play_time, gm.game_state.world_seed, difficulty);
+        gm.game_state.wind = ocean.wind();
+    }
+    gm.update_water_collectors();
+    gm.update_kraken();
+    gm.update_shark_attacks();
+    gm.update_projectiles();
+
+    // Control hints: manual toggle sticks; otherwise they fade out once the player has settled in
+    let settings = &mut gm.game_state.settings;
+    if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::ToggleControlHints) {
+        settings.show_control_hints = !settings.show_control_hints;
+        settings.control_hints_user_set = true;
+    } else if !settings.control_hints_user_set && gm.game_state.play_time > crate::constants::CONTROL_HINTS_AUTO_HIDE_SECONDS {
+        settings.show_control_hints = false;
+    }
+    if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::Sleep) && !gm.try_sleep() {
+        gm.ui_renderer.push_colored_notification("Can't sleep now".to_string(), crate::constants::UI_TEXT_GRAY, crate::constants::NOTIFICATION_DURATION);
+    }
+
+    if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::ToggleAutoEat) {
+        gm.game_state.settings.auto_eat = !gm.game_state.settings.auto_eat;
+        let label = if gm.game_state.settings.auto_eat { "Auto-eat on" } else { "Auto-eat off" };
+        gm.ui_renderer.push_notification(label.to_string(), crate::constants::NOTIFICATION_DURATION);
+    }
+    let auto_eaten = if gm.game_state.settings.auto_eat { gm.game_state.player.as_mut().and_then(|p| p.auto_eat()) } else { None };
+    if let Some(eaten) = auto_eaten {
+        gm.ui_renderer.push_colored_notification(format!("Auto-ate {:?}", eaten), crate::constants::UI_TEXT_ORANGE, crate::constants::NOTIFICATION_DURATION);
+    }
+
+    if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::DeployBuoy) {
+        match gm.deploy_buoy() {
+            Ok(()) => gm.ui_renderer.push_notification("Buoy placed".to_string(), crate::constants::NOTIFICATION_DURATION),
+            Err(reason) => gm.ui_renderer.push_colored_notification(reason, crate::constants::UI_TEXT_GRAY, crate::constants::NOTIFICATION_DURATION),
+        }
+    }
+
+    // Render distance slider: [ and ] step through 1-6 chunks
+    let distance_step = if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::RenderDistanceUp) {
+        1
+    } else if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::RenderDistanceDown) {
+        -1
+    } else {
+        0
+    };
+    if distance_step != 0 {
+        gm.world_system.set_render_distance(gm.game_state.settings.render_distance + distance_step);
+        gm.game_state.settings.render_distance = gm.world_system.render_distance();
+        let side = gm.game_state.settings.render_distance * 2 + 1;
+        let label = format!("View distance {} ({} chunks)", gm.game_state.settings.render_distance, side * side);
+        gm.ui_renderer.push_notification(label, crate::constants::NOTIFICATION_DURATION);
+    }
+
+    // Camera zoom: = and - step between MIN/MAX_CAMERA_ZOOM; with shift held they zoom the
+    // minimap instead (zooming in narrows the range)
+    let zoom_step = if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::CameraZoomIn) {
+        1.0
+    } else if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::CameraZoomOut) {
+        -1.0
+    } else {
+        0.0
+    };
+    if zoom_step != 0.0 {
+        if gm.input_system.is_shift_held() {
+            gm.ui_renderer.adjust_minimap_range(-zoom_step * crate::constants::MINIMAP_RANGE_STEP);
+        } else {
+            gm.render_system.adjust_zoom(zoom_step * crate::constants::CAMERA_ZOOM_STEP);
+        }
+    }
+
+    // Quick-craft bar: F1-F4 craft the pinned recipes if ingredients are available
+    use crate::components::input::input_system::InputKey;
+    let quick_craft_keys = [InputKey::QuickCraft1, InputKey::QuickCraft2, InputKey::QuickCraft3, InputKey::QuickCraft4];
+    for (i, key) in quick_craft_keys.into_iter().enumerate() {
+        if !gm.input_system.is_key_just_pressed(key) {
+            continue;
+        }
+        if let Some(id) = gm.game_state.pinned_recipes.get(i).cloned() {
+            gm.craft(&id);
+        }
+    }
+
     // Handle item collection first to avoid borrowing conflicts
     let mut should_collect = false;
     let mut use_hook = false;
     let mut player_pos_for_collection = None;
     
+    // Clicks on the docked inventory panel belong to the inventory, not the hook
+    let (screen_w, screen_h) = turbo::resolution();
+    let (panel_x, panel_y, panel_w, panel_h) = crate::components::renderer::ui_renderer::inventory_panel_rect(screen_w, screen_h, true);
+    let pointer_over_inventory = gm.current_scene == super::super::game_manager::SceneType::Inventory
+        && input_state.mouse_pos.x >= panel_x && input_state.mouse_pos.x <= panel_x + panel_w
+        && input_state.mouse_pos.y >= panel_y && input_state.mouse_pos.y <= panel_y + panel_h;
+    let use_tool = input_state.use_tool && !pointer_over_inventory;
     if let Some(player) = &gm.game_state.player {
-        if input_state.collect_item || (input_state.use_tool && player.current_tool == crate::models::player::Tool::Hook) {
+        if input_state.collect_item || (use_tool && player.current_tool.casts_hook()) {
             should_collect = true;
-            use_hook = player.current_tool == crate::models::player::Tool::Hook;
+            use_hook = player.current_tool.casts_hook();
             player_pos_for_collection = Some(player.pos.clone());
         }
     }
@@ -39,15 +132,9 @@ pub fn update(gm: &mut GameManager) {
     if should_collect {
         if let Some(pos) = player_pos_for_collection {
             if use_hook {
-                // Convert screen mouse to world coords based on camera centered at player in current view
-                // In TopDown, world.y maps to screen.y with camera at player
-                let (screen_w, screen_h) = turbo::resolution();
-                let mouse = input_state.mouse_pos;
-                let world_mouse = crate::math::Vec2::new(
-                    mouse.x - screen_w as f32 * 0.5 + pos.x,
-                    mouse.y - screen_h as f32 * 0.5 + pos.y,
-                );
-                let hook_direction = crate::math::Vec2::new(world_mouse.x - pos.x, world_mouse.y - pos.y);
+                // Aim in the plane of the current view (x/y top-down, x/z while diving)
+                let player_screen = gm.render_system.world_to_screen(&pos);
+                let hook_direction = super::super::game_manager::hook_aim_direction(gm.game_state.game_mode, input_state.mouse_pos, player_screen);
                 gm.launch_hook(&pos, hook_direction);
             } else {
                 gm.handle_item_collection(&pos, false);
@@ -55,46 +142,117 @@ pub fn update(gm: &mut GameManager) {
         }
     }
 
+    // The net sweeps up every floating item around the player at once
+    let net_sweep = match &mut gm.game_state.player {
+        Some(player) if use_tool && player.can_sweep_net() => {
+            player.net_cooldown.reset();
+            Some(player.pos)
+        }
+        _ => None,
+    };
+    if let Some(pos) = net_sweep {
+        gm.net_collect(&pos, crate::constants::NET_RADIUS);
+    }
+
+    // The spear is thrown along the aim line and used up until it's fished back out
+    let spear_throw = match &gm.game_state.player {
+        Some(player) if use_tool && player.can_throw_spear() => {
+            let player_screen = gm.render_system.world_to_screen(&player.pos);
+            Some((player.pos, super::super::game_manager::hook_aim_direction(gm.game_state.game_mode, input_state.mouse_pos, player_screen)))
+        }
+        _ => None,
+    };
+    if let Some((pos, direction)) = spear_throw {
+        gm.throw_spear(&pos, direction);
+    }
+
+    // Axe/Hammer break the seabed block under the cursor while diving
+    let mine_cell = match &gm.game_state.player {
+        Some(player) if use_tool && player.is_diving && player.current_tool.mines_terrain() => {
+            let player_screen = gm.render_system.world_to_screen(&player.pos);
+            // Screen offset back to world units at the current zoom
+            let offset = super::super::game_manager::hook_aim_direction(gm.game_state.game_mode, input_state.mouse_pos, player_screen).scale(1.0 / gm.render_system.zoom());
+            let target = player.pos.add(offset);
+            (offset.length() <= crate::constants::MINE_REACH).then(|| {
+                ((target.x / crate::constants::PIXEL_SIZE).floor() as i32, (-target.z / crate::constants::PIXEL_SIZE).floor() as i32)
+            })
+        }
+        _ => None,
+    };
+    if let Some((cell_x, cell_y)) = mine_cell {
+        let block = gm.world_system.get_block(cell_x, cell_y);
+        if let (Some(block), Some(item)) = (block, gm.world_system.mine_block(cell_x, cell_y)) {
+            let drops = gm.world_system.block_drops(&block.block_type);
+            if let Some(player) = &mut gm.game_state.player {
+                for &(drop_type, quantity) in &drops {
+                    if !player.inventory.add_material(drop_type, quantity) {
+                        gm.ui_renderer.push_toast("Inventory full".to_string(), crate::constants::NOTIFICATION_DURATION);
+                    }
+                }
+            }
+            gm.ai_system.clear_path_cache();
+            let message = match block.block_type {
+                crate::models::terrain::BlockType::TreasureChest => format!("Chest opened: {}", crate::models::loot::describe_loot(&drops)),
+                _ => format!("Mined {}", item.display_name()),
+            };
+            gm.ui_renderer.push_notification(message, crate::constants::NOTIFICATION_DURATION);
+        }
+    }
+
+    // Builder paints wall pixels onto the raft while held; the Hammer smashes them
+    let wall_action = match (&gm.game_state.player, &gm.game_state.raft) {
+        (Some(player), Some(raft)) if !player.is_diving && !pointer_over_inventory => {
+            let building = input_state.use_tool_held && player.can_build();
+            let smashing = use_tool && player.current_tool == crate::models::player::Tool::Hammer;
+            if building || smashing {
+                let player_screen = gm.render_system.world_to_screen(&player.pos);
+                let offset = super::super::game_manager::hook_aim_direction(gm.game_state.game_mode, input_state.mouse_pos, player_screen).scale(1.0 / gm.render_system.zoom());
+                let target = player.pos.add(offset);
+                (offset.length() <= crate::constants::WALL_BUILD_REACH && raft.is_on_raft(&target))
+                    .then_some((building, target.x - raft.center.x, target.y - raft.center.y))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+    match wall_action {
+        Some((true, x, y)) => gm.build_wall(x, y),
+        Some((false, x, y)) => gm.hammer_walls(x, y),
+        None => {}
+    }
+
+    // Eat/drink: thirst is quenched from rain or the collector first, otherwise eat as before
+    let mut input_state = input_state;
+    if input_state.eat_food && gm.try_drink() {
+        input_state.eat_food = false;
+    }
+
+    let is_night = gm.game_state.is_night();
     if let (Some(player), Some(raft)) = (&mut gm.game_state.player, &mut gm.game_state.raft) {
         // Hotbar drag & drop (HUD) when not in inventory scene
-        // Geometry mirrors UIRenderer::render_hotbar
         let (screen_w, screen_h) = turbo::resolution();
-        let slot_size = 24.0_f32;
-        let margin = 4.0_f32;
-        let count = 10usize;
-        let total_w = count as f32 * slot_size + (count as f32 - 1.0) * margin;
-        let start_x = (screen_w as f32 - total_w) * 0.5;
-        let y = screen_h as f32 - slot_size - 8.0;
         let mouse = gm.input_system.get_screen_mouse_position();
         let left_click = gm.input_system.is_mouse_left_just_pressed();
         let left_held = gm.input_system.is_mouse_left_held();
-        // Only allow when not in inventory scene
+        // Only allow when not in inventory scene; the inventory scene handles the HUD row itself
         if gm.current_scene == super::super::game_manager::SceneType::Playing {
-            // Hit-test hotbar slot under mouse
-            let mut hovered_hotbar: Option<usize> = None;
-            for i in 0..count {
-                let x = start_x + i as f32 * (slot_size + margin);
-                if mouse.x >= x && mouse.x <= x + slot_size && mouse.y >= y && mouse.y <= y + slot_size {
-                    hovered_hotbar = Some(i);
-                    break;
-                }
-            }
+            let hovered_hotbar = crate::components::renderer::ui_renderer::hud_hotbar_slot_at(mouse.x, mouse.y, screen_w, screen_h);
             // Begin drag on press
             if left_click && gm.game_state.dragging_slot.is_none() {
                 if let Some(idx) = hovered_hotbar { gm.game_state.dragging_slot = Some(idx); }
             }
-            // On release, drop into hovered hotbar slot; swap inventory slots 0..9
+            // On release, merge or swap into the hovered hotbar slot and select it
             if !left_held {
-                if let Some(src) = gm.game_state.dragging_slot.take() {
-                    if let Some(dst) = hovered_hotbar {
-                        if src != dst {
-                            let _ = player.inventory.swap_slots(src, dst);
-                        }
+                if let (Some(src), Some(dst)) = (gm.game_state.dragging_slot.take(), hovered_hotbar) {
+                    if src != dst {
+                        player.inventory.merge_or_swap(src, dst);
                     }
+                    player.inventory.selected_slot = Some(dst);
                 }
             }
         }
-        super::super::game_manager::apply_player_input(player, &input_state, &movement);
+        super::super::game_manager::apply_player_input(player, raft, &input_state, &movement, is_night, gm.delta_time);
         super::super::game_manager::apply_physics_update(player, &gm.game_state.wind, gm.delta_time);
 
         player.on_raft = raft.is_on_raft(&player.pos);
@@ -110,18 +268,22 @@ pub fn update(gm: &mut GameManager) {
             player.pos.z = -10.0;
             player.depth = -10;
             player.is_diving = true;
+            gm.resource_manager.play_sound(crate::constants::SOUND_DIVE_SPLASH);
             // Camera anchoring handled inside RenderSystem based on world z
         }
 
         if new_mode == super::super::game_manager::GameMode::Dive {
             // Depth is derived from world z (negative below surface)
-            player.depth = (-player.pos.z).max(0.0) as i32;
+            player.depth = player.pos.z.min(0.0) as i32;
             player.is_diving = player.pos.z < 0.0;
             if player.pos.z >= 0.0 {
                 new_mode = super::super::game_manager::GameMode::Raft;
                 player.pos = player.pos.clone();
                 player.pos.z = 0.0;
                 player.is_diving = false;
+                if player.record_dive() {
+                    gm.ui_renderer.push_colored_notification("Lung Capacity Increased".to_string(), crate::constants::UI_TEXT_GRAY, crate::constants::NOTIFICATION_DURATION);
+                }
                 gm.render_system.set_camera_target(player.pos);
                 // Camera anchoring handled inside RenderSystem
             }
@@ -139,18 +301,40 @@ pub fn update(gm: &mut GameManager) {
             gm.render_system.set_render_mode(crate::components::renderer::render_system::RenderViewMode::TopDown);
         }
         super::super::game_manager::GameMode::Dive => {
-            gm.spawn_system.set_spawn_rate(SpawnType::FloatingItem, u32::MAX);
+            gm.spawn_system.set_spawn_rate(SpawnType::FloatingItem, crate::constants::SPAWN_RATE_NEVER);
             gm.spawn_system.set_view_mode(crate::components::systems::spawn_system::ViewMode::SideScroll);
             gm.render_system.set_render_mode(crate::components::renderer::render_system::RenderViewMode::SideScroll);
+            if let Some(player) = &gm.game_state.player {
+                gm.render_system.set_depth_tint(player.get_depth_tint());
+            }
         }
     }
     gm.update_spawning_internal(&player_pos);
     gm.update_ai();
     gm.world_system.update(&player_pos);
-    gm.render_system.set_camera_target(player_pos);
+    if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::ToggleCameraFollow) {
+        gm.render_system.toggle_camera_follow_target();
+    }
+    if gm.input_system.is_key_just_pressed(crate::components::input::input_system::InputKey::ToggleItemLabels) {
+        let show = !gm.render_system.show_labels();
+        gm.render_system.set_show_labels(show);
+    }
+    // Raft-follow only applies top-down; diving always tracks the player
+    let camera_target = match (gm.render_system.camera_follow_target(), gm.game_state.game_mode, &gm.game_state.raft) {
+        (crate::components::renderer::render_system::CameraFollowTarget::Raft, super::super::game_manager::GameMode::Raft, Some(raft)) => raft.center,
+        _ => player_pos,
+    };
+    // Look ahead of the player while moving; the raft camera and dive view stay centered
+    let lead_velocity = match (&gm.game_state.player, camera_target == player_pos) {
+        (Some(player), true) if player.is_moving => player.last_movement.scale(gm.game_state.settings.camera_lead),
+        _ => crate::math::Vec3::zero(),
+    };
+    gm.render_system.set_camera_lead_velocity(lead_velocity);
+    gm.render_system.set_camera_target(camera_target);
+    gm.render_system.set_camera_smoothing(gm.game_state.settings.camera_smoothing);
     gm.render_system.update_camera(gm.delta_time);
     if gm.frame_count < 10 {
-        gm.render_system.update_camera(1.0);
+        gm.render_system.snap_camera();
     }
 }
 
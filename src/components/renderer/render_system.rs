@@ -1,5 +1,6 @@
 use super::*;
 use crate::math::Vec3;
+use crate::color::Color;
 use crate::components::entities::game_entity::{Entity, EntityType, RenderData, RenderLayer};
 // CameraSystem removed; use turbo camera API directly
 // use crate::constants::*;
@@ -8,55 +9,164 @@ use crate::components::entities::game_entity::{Entity, EntityType, RenderData, R
 #[turbo::serialize]
 pub struct RenderSystem {
     camera_pos: (f32, f32),
+    target_pos: (f32, f32),     // Where the camera is easing toward, in the view plane
+    camera_smoothing: f32,      // Follow rate per second; 0 snaps straight to the target
     render_queue: Vec<RenderCommand>,
     background_layers: Vec<BackgroundLayer>,
     view_mode: RenderViewMode,
-    transition_alpha: f32,
+    transition_fade: crate::timer::Timer, // Black overlay fading out after a view switch
     last_player_world_pos: Option<Vec3>,
+    camera_follow_target: CameraFollowTarget,
+    frame_count: u64, // Drives idle/swim animation
+    camera_lead_velocity: Vec3, // Scaled player movement the camera looks ahead along
+    camera_lead: (f32, f32),    // Current eased screen-plane lead offset
+    sprites_available: bool,    // False draws colored shapes in place of missing art
+    zoom: f32,                  // World-to-screen scale around the camera center
+    depth_tint: u32,            // Underwater overlay color for the side view
+    terrain_tiles: Vec<(i32, i32, u32)>, // On-screen terrain cells (cell x, cell y, color) for this frame
+    walls: Vec<crate::models::wall::WallGrid>, // Raft walls for this frame's top-down view
+    wall_anchor: (f32, f32),    // Raft center the walls are positioned from
+    monster: Option<crate::models::monster::MonsterGrid>, // Boss for this frame's top-down view
+    show_labels: bool,          // Name floating items near the player
+    item_labels: Vec<(Vec3, String)>, // Queued floating items this frame (world position, name)
 }
 
 impl RenderSystem {
     pub fn new() -> Self {
         Self {
             camera_pos: (0.0, 0.0),
+            target_pos: (0.0, 0.0),
+            camera_smoothing: crate::constants::CAMERA_SMOOTHING,
             render_queue: Vec::new(),
             background_layers: Vec::new(),
             view_mode: RenderViewMode::TopDown,
-            transition_alpha: 0.0,
+            transition_fade: crate::timer::Timer::finished(crate::constants::TRANSITION_FADE_SECONDS),
             last_player_world_pos: None,
+            camera_follow_target: CameraFollowTarget::Player,
+            frame_count: 0,
+            camera_lead_velocity: Vec3::zero(),
+            camera_lead: (0.0, 0.0),
+            sprites_available: false,
+            zoom: 1.0,
+            depth_tint: 0,
+            terrain_tiles: Vec::new(),
+            walls: Vec::new(),
+            wall_anchor: (0.0, 0.0),
+            monster: None,
+            show_labels: false,
+            item_labels: Vec::new(),
         }
     }
     
-    /// Convert world position to screen position using current camera (centered)
-    fn world_to_screen(&self, world_pos: &Vec3) -> (f32, f32) {
+    /// Convert world position to screen position using current camera (centered) and view mode
+    pub(crate) fn world_to_screen(&self, world_pos: &Vec3) -> (f32, f32) {
         let (screen_w, screen_h) = resolution();
+        let plane_y = match self.view_mode {
+            RenderViewMode::TopDown => world_pos.y,
+            RenderViewMode::SideScroll => -world_pos.z,
+        };
+        self.plane_to_screen((world_pos.x, plane_y), self.camera_pos, screen_w, screen_h)
+    }
+    
+    /// Project a point in the view plane to the screen, scaled by zoom around the camera center
+    fn plane_to_screen(&self, plane: (f32, f32), camera_pos: (f32, f32), screen_w: u32, screen_h: u32) -> (f32, f32) {
+        (
+            (plane.0 - camera_pos.0) * self.zoom + screen_w as f32 * 0.5,
+            (plane.1 - camera_pos.1) * self.zoom + screen_h as f32 * 0.5,
+        )
+    }
+    
+    /// Inverse of plane_to_screen: the view-plane point under a screen position
+    fn screen_to_plane(&self, screen: (f32, f32), camera_pos: (f32, f32), screen_w: u32, screen_h: u32) -> (f32, f32) {
         (
-            world_pos.x - self.camera_pos.0 + screen_w as f32 * 0.5,
-            world_pos.y - self.camera_pos.1 + screen_h as f32 * 0.5,
+            (screen.0 - screen_w as f32 * 0.5) / self.zoom + camera_pos.0,
+            (screen.1 - screen_h as f32 * 0.5) / self.zoom + camera_pos.1,
         )
     }
     
-    /// Set camera target from world position; compute screen-plane y based on view mode
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+    
+    /// Set zoom, clamped to MIN/MAX_CAMERA_ZOOM
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.clamp(crate::constants::MIN_CAMERA_ZOOM, crate::constants::MAX_CAMERA_ZOOM);
+    }
+    
+    /// Step zoom by `delta` (positive zooms in), clamped like set_zoom
+    pub fn adjust_zoom(&mut self, delta: f32) {
+        self.set_zoom(self.zoom + delta);
+    }
+    
+    /// Set the movement the camera should lead along (already scaled by the lead factor)
+    pub fn set_camera_lead_velocity(&mut self, velocity: Vec3) {
+        self.camera_lead_velocity = velocity;
+    }
+    
+    /// Set camera target from world position; compute screen-plane y based on view mode.
+    /// Top-down adds an eased, clamped lead toward the direction of movement. The camera
+    /// itself moves toward the target in `update_camera`.
     pub fn set_camera_target(&mut self, world: Vec3) {
         let cam_y = match self.view_mode {
             RenderViewMode::TopDown => world.y,
             RenderViewMode::SideScroll => -world.z,
         };
-        self.camera_pos = (world.x, cam_y);
-        camera::set_xy(self.camera_pos.0, self.camera_pos.1);
+        let target_lead = match self.view_mode {
+            RenderViewMode::TopDown => {
+                let (lx, ly) = (self.camera_lead_velocity.x, self.camera_lead_velocity.y);
+                let len = (lx * lx + ly * ly).sqrt();
+                let clamp = if len > crate::constants::CAMERA_LEAD_MAX { crate::constants::CAMERA_LEAD_MAX / len } else { 1.0 };
+                (lx * clamp, ly * clamp)
+            }
+            RenderViewMode::SideScroll => (0.0, 0.0), // Disorienting while diving
+        };
+        let ease = crate::constants::CAMERA_LEAD_EASE;
+        self.camera_lead.0 += (target_lead.0 - self.camera_lead.0) * ease;
+        self.camera_lead.1 += (target_lead.1 - self.camera_lead.1) * ease;
+        self.target_pos = (world.x + self.camera_lead.0, cam_y + self.camera_lead.1);
+    }
+    
+    /// How quickly the camera closes on its target, per second (0 or less snaps)
+    pub fn set_camera_smoothing(&mut self, factor: f32) {
+        self.camera_smoothing = factor.max(0.0);
     }
     
-    /// Update camera
+    /// Jump straight to the camera target, e.g. on the first frames of a scene
+    pub fn snap_camera(&mut self) {
+        self.camera_pos = self.target_pos;
+        camera::set_xyz(self.camera_pos.0, self.camera_pos.1, self.zoom);
+    }
+    
+    pub fn camera_follow_target(&self) -> CameraFollowTarget {
+        self.camera_follow_target
+    }
+
+    /// Switch the top-down camera between following the player and the raft
+    pub fn toggle_camera_follow_target(&mut self) {
+        self.camera_follow_target = match self.camera_follow_target {
+            CameraFollowTarget::Player => CameraFollowTarget::Raft,
+            CameraFollowTarget::Raft => CameraFollowTarget::Player,
+        };
+    }
+    
+    /// Ease the camera toward its target and advance the transition fade
     pub fn update_camera(&mut self, delta_time: f32) {
-        // No smoothing; camera already set via set_camera_target
-        if self.transition_alpha > 0.0 {
-            self.transition_alpha = (self.transition_alpha - delta_time * 2.0).max(0.0);
-        }
+        self.camera_pos = smooth_camera(self.camera_pos, self.target_pos, self.camera_smoothing, delta_time);
+        camera::set_xyz(self.camera_pos.0, self.camera_pos.1, self.zoom);
+        self.transition_fade.tick(delta_time);
+    }
+    
+    /// Whether something `size` world units across at `world_pos` lands on screen, allowing
+    /// RENDER_CULL_MARGIN pixels of slack. Uses the camera as set for this frame.
+    pub fn is_in_view(&self, world_pos: &Vec3, size: f32) -> bool {
+        let (screen_w, screen_h) = resolution();
+        let (x, y) = self.world_to_screen(world_pos);
+        let extent = size * self.zoom + crate::constants::RENDER_CULL_MARGIN;
+        x > -extent && x < screen_w as f32 + extent && y > -extent && y < screen_h as f32 + extent
     }
     
     /// Add entity to render queue
     pub fn add_entity(&mut self, entity: &Entity) {
-        let mut render_data = entity.get_render_data();
         let entity_type = entity.get_entity_type();
         
         // Hide entities based on view mode
@@ -68,31 +178,48 @@ impl RenderSystem {
                 }
             },
             EntityType::FloatingItem => {
-                // Hide floating items in side-scroll mode
-                if self.view_mode == RenderViewMode::SideScroll {
+                // Hide floating items in side-scroll mode, except sunken ones resting at depth
+                let sunken = matches!(entity, Entity::FloatingItem(item) if item.item_type.sinks());
+                if self.view_mode == RenderViewMode::SideScroll && !sunken {
                     return;
                 }
             },
             _ => {} // Other entities visible in both modes
         }
         
-        // Project world position into current view
+        // Cull before cloning render data. The raft spans more than its size once expanded and
+        // the hook's line runs back to the player, so both are always queued; buoys stay queued
+        // for their off-screen edge markers.
         let world_pos = entity.get_world_position();
+        let always_drawn = matches!(entity_type, EntityType::Raft | EntityType::Hook | EntityType::Buoy);
+        if !entity.render_data().visible || (!always_drawn && !self.is_in_view(&world_pos, entity.render_data().size)) {
+            return;
+        }
+        
+        if let (true, Entity::FloatingItem(item)) = (self.show_labels, entity) {
+            self.item_labels.push((world_pos, item.item_type.display_name().to_string()));
+        }
+        
+        // Project world position into current view
+        let mut render_data = entity.get_render_data();
         render_data.screen_position = match self.view_mode {
             RenderViewMode::TopDown => Some((world_pos.x, world_pos.y)),
             RenderViewMode::SideScroll => Some((world_pos.x, -world_pos.z)),
         };
-        if render_data.visible {
-            let command = RenderCommand::Entity {
-                data: render_data.clone(),
-                entity_type,
-            };
-            self.render_queue.push(command);
-        }
+        self.render_queue.push(RenderCommand::Entity {
+            data: render_data,
+            entity_type,
+            entity_id: entity.get_id(),
+        });
     }
     
-    /// Add player entity with movement data
-    pub fn add_player_entity(&mut self, entity: &Entity, is_moving: bool, last_movement: &crate::math::Vec3) {
+    /// Add player entity with movement data. Never culled: the frame reads the player's
+    /// position from the queue, including for the off-screen player marker.
+    pub fn add_player_entity(&mut self, entity: &Entity, is_moving: bool, last_movement: &crate::math::Vec3, hurt: bool) {
+        let world_pos = entity.get_world_position();
+        if !entity.render_data().visible {
+            return;
+        }
         let mut render_data = entity.get_render_data();
         let entity_type = entity.get_entity_type();
         
@@ -101,20 +228,122 @@ impl RenderSystem {
             // Store movement data in render data for player sprite selection
             render_data.player_is_moving = is_moving;
             render_data.player_last_movement = *last_movement;
+            render_data.hurt_flash = hurt;
         }
         
         // Project world position into current view
-        let world_pos = entity.get_world_position();
         render_data.screen_position = match self.view_mode {
             RenderViewMode::TopDown => Some((world_pos.x, world_pos.y)),
             RenderViewMode::SideScroll => Some((world_pos.x, -world_pos.z)),
         };
-        if render_data.visible {
-            let command = RenderCommand::Entity {
-                data: render_data.clone(),
-                entity_type,
-            };
-            self.render_queue.push(command);
+        self.render_queue.push(RenderCommand::Entity {
+            data: render_data,
+            entity_type,
+            entity_id: entity.get_id(),
+        });
+    }
+    
+    /// Whether floating items near the player are drawn with their names
+    pub fn show_labels(&self) -> bool {
+        self.show_labels
+    }
+    
+    /// Turn the in-world floating item name labels on or off
+    pub fn set_show_labels(&mut self, show: bool) {
+        self.show_labels = show;
+        self.item_labels.clear();
+    }
+    
+    /// Update the frame counter used for animation
+    pub fn set_frame_count(&mut self, frame_count: u64) {
+        self.frame_count = frame_count;
+    }
+    
+    /// Switch between sprite art and colored-shape fallbacks
+    pub fn set_sprites_available(&mut self, available: bool) {
+        self.sprites_available = available;
+    }
+    
+    /// Overlay color drawn over the side view (0xRRGGBBAA; zero alpha draws nothing)
+    pub fn set_depth_tint(&mut self, color: u32) {
+        self.depth_tint = color;
+    }
+    
+    /// Collect the on-screen solid cells of the loaded terrain for this frame's side view.
+    /// Whole chunks outside the view are skipped before their cells are visited.
+    pub fn queue_terrain(&mut self, world: &crate::components::systems::world_system::WorldSystem) {
+        self.terrain_tiles.clear();
+        if self.view_mode != RenderViewMode::SideScroll {
+            return;
+        }
+        let cell_size = crate::constants::PIXEL_SIZE;
+        let chunk_cells = crate::constants::CHUNK_SIZE as i32;
+        let chunk_half = chunk_cells as f32 * cell_size * 0.5;
+        for chunk in world.get_visible_chunks() {
+            let (origin_x, origin_y) = (chunk.x * chunk_cells, chunk.y * chunk_cells);
+            let chunk_center = Vec3::new((origin_x as f32 * cell_size) + chunk_half, 0.0, -(origin_y as f32 * cell_size) - chunk_half);
+            if !self.is_in_view(&chunk_center, chunk_half) {
+                continue;
+            }
+            for (i, cell) in chunk.cells.iter().enumerate() {
+                let color = cell.block_type.color();
+                if color & 0xFF == 0 {
+                    continue;
+                }
+                let (cell_x, cell_y) = (origin_x + i as i32 % chunk_cells, origin_y + i as i32 / chunk_cells);
+                let cell_center = Vec3::new((cell_x as f32 + 0.5) * cell_size, 0.0, -(cell_y as f32 + 0.5) * cell_size);
+                if self.is_in_view(&cell_center, cell_size) {
+                    self.terrain_tiles.push((cell_x, cell_y, color));
+                }
+            }
+        }
+    }
+    
+    /// Collect the raft's walls for this frame's top-down view
+    pub fn queue_walls(&mut self, walls: &[crate::models::wall::WallGrid], raft_center: &Vec3) {
+        self.walls.clear();
+        if self.view_mode != RenderViewMode::TopDown {
+            return;
+        }
+        self.walls.extend_from_slice(walls);
+        self.wall_anchor = (raft_center.x, raft_center.y);
+    }
+    
+    /// Collect the boss monster, if any, for this frame's top-down view
+    pub fn queue_monster(&mut self, monster: Option<&crate::models::monster::MonsterGrid>) {
+        self.monster = monster.filter(|_| self.view_mode == RenderViewMode::TopDown).cloned();
+    }
+    
+    /// Draw the queued walls standing on the raft deck
+    fn render_walls(&self) {
+        let (anchor_x, anchor_y) = self.wall_anchor;
+        for wall in &self.walls {
+            wall.render(self.zoom, |x, y| self.world_to_screen(&Vec3::new(anchor_x + x, anchor_y + y, 0.0)));
+        }
+    }
+    
+    /// Draw the names of queued floating items within ITEM_LABEL_RADIUS of the player, centered
+    /// above each item in the current view's projection
+    fn render_item_labels(&self) {
+        let Some(player_pos) = &self.last_player_world_pos else { return; };
+        for (world_pos, name) in &self.item_labels {
+            if world_pos.distance_to(player_pos) > crate::constants::ITEM_LABEL_RADIUS {
+                continue;
+            }
+            let (x, y) = self.world_to_screen(world_pos);
+            let width = name.len() as f32 * 5.0;
+            rect!(x = x - width * 0.5 - 2.0, y = y - 18.0, w = width + 4.0, h = 10.0, color = 0x00000088, fixed = true);
+            text!(name.as_str(), x = x - width * 0.5, y = y - 16.0, color = 0xFFFFFFFF, fixed = true);
+        }
+    }
+    
+    /// Draw the queued terrain cells as solid tiles behind the entities
+    fn render_terrain(&self) {
+        let cell_size = crate::constants::PIXEL_SIZE;
+        let tile = (cell_size * self.zoom).ceil();
+        for &(cell_x, cell_y, color) in &self.terrain_tiles {
+            let (x, y) = self.world_to_screen(&Vec3::new(cell_x as f32 * cell_size, 0.0, -(cell_y as f32) * cell_size));
+            rect!(x = x, y = y, w = tile, h = tile, color = color, fixed = true);
         }
     }
     
@@ -131,7 +360,7 @@ impl RenderSystem {
         // Cache player world position (if present) for distance-based effects
         self.last_player_world_pos = None;
         for command in &self.render_queue {
-            if let RenderCommand::Entity { data, entity_type } = command {
+            if let RenderCommand::Entity { data, entity_type, .. } = command {
                 if let EntityType::Player = entity_type {
                     self.last_player_world_pos = Some(data.world_position.clone());
                     break;
@@ -142,61 +371,88 @@ impl RenderSystem {
         // Clear screen
         self.clear_screen();
         
-        // Sort render queue by layer, ensuring player renders on top
-        self.render_queue.sort_by(|a, b| {
-            let layer_a = match a {
-                RenderCommand::Entity { data, .. } => data.layer,
-                RenderCommand::Background { layer, .. } => *layer,
-                RenderCommand::UI { layer, .. } => *layer,
-            };
-            let layer_b = match b {
-                RenderCommand::Entity { data, .. } => data.layer,
-                RenderCommand::Background { layer, .. } => *layer,
-                RenderCommand::UI { layer, .. } => *layer,
-            };
-            
-            // Get entity type for priority calculation
-            let entity_priority_a = match a {
-                RenderCommand::Entity { entity_type, .. } => match entity_type {
-                    EntityType::Player => 100, // Highest priority
-                    EntityType::Raft => 50,
-                    _ => 0,
-                },
-                _ => 0,
-            };
-            let entity_priority_b = match b {
-                RenderCommand::Entity { entity_type, .. } => match entity_type {
-                    EntityType::Player => 100, // Highest priority
-                    EntityType::Raft => 50,
-                    _ => 0,
-                },
-                _ => 0,
-            };
-            
-            // First sort by entity priority (player on top)
-            if entity_priority_a != entity_priority_b {
-                return entity_priority_a.cmp(&entity_priority_b);
-            }
-            
-            // Then sort by layer
-            layer_a.cmp(&layer_b)
-        });
+        // Sort render queue by layer, ensuring player renders on top; see render_order for tiebreaks
+        self.render_queue.sort_by(Self::render_order);
         
         // Render background layers
         self.render_background_layers(camera_pos, screen_w, screen_h);
         
+        // Ocean floor sits between the background and the entities swimming over it
+        if self.view_mode == RenderViewMode::SideScroll {
+            self.render_terrain();
+        }
+        
         // Render entities
         self.render_entities(camera_pos, screen_w, screen_h);
         
+        // Walls stand up off the deck, over the raft and whoever is behind them
+        if self.view_mode == RenderViewMode::TopDown {
+            self.render_walls();
+            if let Some(monster) = &self.monster {
+                monster.render(self.zoom, |x, y| self.world_to_screen(&Vec3::new(x, y, 0.0)));
+            }
+        }
+        
+        // Item names float over the entities, only around the player to keep the view readable
+        if self.show_labels {
+            self.render_item_labels();
+        }
+        
+        // Point toward the player when the camera is parked on the raft and they swim out of view
+        if self.camera_follow_target == CameraFollowTarget::Raft && self.view_mode == RenderViewMode::TopDown {
+            self.render_offscreen_player_indicator(screen_w, screen_h);
+        }
+        
+        // Buoys are navigation landmarks: keep an edge marker pointing at any that are out of view
+        if self.view_mode == RenderViewMode::TopDown {
+            self.render_offscreen_buoy_indicators(screen_w, screen_h);
+        }
+        
+        // Depth tint sits over the world but under the fade and the HUD
+        if self.view_mode == RenderViewMode::SideScroll && self.depth_tint & 0xFF != 0 {
+            rect!(x = 0.0, y = 0.0, w = screen_w as f32, h = screen_h as f32, color = self.depth_tint, fixed = true);
+        }
+        
         // Fade overlay
-        if self.transition_alpha > 0.0 {
-            let alpha = (self.transition_alpha * 255.0) as u32;
-            let color = (0x00 << 24) | (0x00 << 16) | (0x00 << 8) | alpha;
+        if !self.transition_fade.is_finished() {
+            let alpha = 1.0 - self.transition_fade.progress();
+            let color = Color::from_rgba(0, 0, 0, (alpha * 255.0) as u8);
             rect!(x = 0.0, y = 0.0, w = screen_w as f32, h = screen_h as f32, color = color, fixed = true);
         }
         
         // Clear render queue
         self.render_queue.clear();
+        self.terrain_tiles.clear();
+        self.walls.clear();
+        self.monster = None;
+        self.item_labels.clear();
+    }
+
+    /// Total draw order: entity priority (player over raft over the rest), then layer,
+    /// then screen-plane y (lower on screen draws later), then entity id. The id tiebreak
+    /// keeps overlapping same-layer entities from swapping order between frames, since the
+    /// queue is filled from an unordered map.
+    fn render_order(a: &RenderCommand, b: &RenderCommand) -> std::cmp::Ordering {
+        fn key(command: &RenderCommand) -> (i32, RenderLayer, f32, u32) {
+            match command {
+                RenderCommand::Entity { data, entity_type, entity_id } => {
+                    let priority = match entity_type {
+                        EntityType::Player => 100, // Highest priority
+                        EntityType::Raft => 50,
+                        _ => 0,
+                    };
+                    let plane_y = data.screen_position.map_or(0.0, |(_, y)| y);
+                    (priority, data.layer, plane_y, *entity_id)
+                }
+                RenderCommand::Background { layer, .. } | RenderCommand::UI { layer, .. } => (0, *layer, 0.0, 0),
+            }
+        }
+        let (priority_a, layer_a, y_a, id_a) = key(a);
+        let (priority_b, layer_b, y_b, id_b) = key(b);
+        priority_a.cmp(&priority_b)
+            .then(layer_a.cmp(&layer_b))
+            .then(y_a.total_cmp(&y_b))
+            .then(id_a.cmp(&id_b))
     }
 
     pub fn set_render_mode(&mut self, mode: RenderViewMode) {
@@ -204,7 +460,7 @@ impl RenderSystem {
     }
 
     pub fn trigger_transition_fade(&mut self) {
-        self.transition_alpha = 1.0;
+        self.transition_fade = crate::timer::Timer::new(crate::constants::TRANSITION_FADE_SECONDS);
     }
     
     /// Clear the screen
@@ -235,16 +491,13 @@ impl RenderSystem {
     fn render_sky_gradient(&self, camera_pos: (f32, f32), screen_w: u32, screen_h: u32) {
         for y in 0..screen_h {
             let screen_y = y as f32;
-            let world_y = camera_pos.1 + (screen_y - screen_h as f32 * 0.5);
+            let world_y = self.screen_to_plane((0.0, screen_y), camera_pos, screen_w, screen_h).1;
             
             if world_y < 0.0 {
                 // Above sea level - sky that gets darker when viewed from depth
                 let view_depth_factor = (camera_pos.1 / 200.0).clamp(0.0, 0.8);
                 let sky_brightness = 1.0 - view_depth_factor;
-                let sky_r = (0x87 as f32 * sky_brightness) as u32;
-                let sky_g = (0xCE as f32 * sky_brightness) as u32;
-                let sky_b = (0xEB as f32 * sky_brightness) as u32;
-                let sky_color = (sky_r << 24) | (sky_g << 16) | (sky_b << 8) | 0xFF;
+                let sky_color = Color::scale_brightness(0x87CEEBFF, sky_brightness);
                 
                 rect!(
                     x = 0.0,
@@ -262,16 +515,13 @@ impl RenderSystem {
     fn render_ocean_gradient(&self, camera_pos: (f32, f32), screen_w: u32, screen_h: u32) {
         for y in 0..screen_h {
             let screen_y = y as f32;
-            let world_y = camera_pos.1 + (screen_y - screen_h as f32 * 0.5);
+            let world_y = self.screen_to_plane((0.0, screen_y), camera_pos, screen_w, screen_h).1;
             
             if world_y >= 0.0 {
                 // Below sea level - underwater that gets darker with depth
                 let depth_factor = (world_y / 400.0).clamp(0.0, 1.0);
-                let ocean_brightness = 1.0 - (depth_factor * 0.9);
-                let ocean_r = (0x41 as f32 * ocean_brightness) as u32;
-                let ocean_g = (0x69 as f32 * ocean_brightness) as u32;
-                let ocean_b = (0xE1 as f32 * ocean_brightness) as u32;
-                let ocean_color = (ocean_r << 24) | (ocean_g << 16) | (ocean_b << 8) | 0xFF;
+                let surface_color: u32 = 0x4169E1FF;
+                let ocean_color = Color::lerp(surface_color, Color::scale_brightness(surface_color, 0.1), depth_factor);
                 
                 rect!(
                     x = 0.0,
@@ -287,12 +537,12 @@ impl RenderSystem {
     
     /// Render water surface
     fn render_water_surface(&self, camera_pos: (f32, f32), screen_w: u32, screen_h: u32) {
-        let water_surface_screen_y = -camera_pos.1 + screen_h as f32 * 0.5;
+        let water_surface_screen_y = self.plane_to_screen((0.0, 0.0), camera_pos, screen_w, screen_h).1;
         
         if water_surface_screen_y >= -10.0 && water_surface_screen_y <= screen_h as f32 + 10.0 {
             for x in 0..screen_w as i32 {
-                let world_x = (x as f32 - screen_w as f32 * 0.5) + camera_pos.0;
-                let wave = (world_x * 0.02).sin() * 3.0;
+                let world_x = self.screen_to_plane((x as f32, 0.0), camera_pos, screen_w, screen_h).0;
+                let wave = (world_x * 0.02).sin() * 3.0 * self.zoom;
                 let surface_y = water_surface_screen_y + wave;
                 
                 // Bright surface line visible from both above and below
@@ -318,8 +568,8 @@ impl RenderSystem {
                 let distance = (dx * dx + dy * dy).sqrt();
                 
                 if distance > 0.6 {
-                    let alpha = ((distance - 0.6) * 2.0 * 128.0) as u32;
-                    let tint_color = 0x00112200 | (alpha.min(128) << 24);
+                    let alpha = ((distance - 0.6) * 2.0 * 128.0).min(128.0) as u8;
+                    let tint_color = Color::with_alpha(0x00112200, alpha);
                     rect!(x = x as f32, y = y as f32, w = 1.0, h = 1.0, color = tint_color, fixed = true);
                 }
             }
@@ -329,7 +579,7 @@ impl RenderSystem {
     /// Render entities
     fn render_entities(&self, camera_pos: (f32, f32), screen_w: u32, screen_h: u32) {
         for command in &self.render_queue {
-            if let RenderCommand::Entity { data, entity_type } = command {
+            if let RenderCommand::Entity { data, entity_type, .. } = command {
                 self.render_entity(data, entity_type, camera_pos, screen_w, screen_h);
             }
         }
@@ -338,9 +588,11 @@ impl RenderSystem {
     /// Render a single entity
     fn render_entity(&self, data: &RenderData, entity_type: &EntityType, camera_pos: (f32, f32), screen_w: u32, screen_h: u32) {
         if let Some(screen_position) = data.screen_position {
-            let screen_x = screen_position.0 - camera_pos.0 + screen_w as f32 * 0.5;
-            let screen_y = screen_position.1 - camera_pos.1 + screen_h as f32 * 0.5;
-        
+            let (screen_x, screen_y) = self.plane_to_screen(screen_position, camera_pos, screen_w, screen_h);
+            // Screen-space shapes scale with zoom; sprites drawn in world space are zoomed by the camera
+            let mut zoomed = data.clone();
+            zoomed.size *= self.zoom;
+            let data = &zoomed;
 
             // Check if entity is on screen
             if screen_x > -data.size && screen_x < screen_w as f32 + data.size &&
@@ -370,6 +622,9 @@ impl RenderSystem {
                     EntityType::Hook => {
                         self.render_hook(screen_x, screen_y, data);
                     },
+                    EntityType::Buoy => {
+                        self.render_buoy(screen_x, screen_y, data);
+                    },
                     _ => {
                         // Default rendering for other entity types
                         circ!(d = data.size, position = (screen_x, screen_y), color = data.color, fixed = true);
@@ -381,6 +636,10 @@ impl RenderSystem {
     
     /// Render player
     fn render_player(&self, data: &RenderData) {
+        // Flicker through the i-frames after a hit
+        if data.hurt_flash && (self.frame_count / 4).is_multiple_of(2) {
+            return;
+        }
         // Determine sprite based on movement, direction, and whether on raft
         let sprite_name = if data.player_is_moving {
             // Player is moving, determine direction and raft state
@@ -451,13 +710,35 @@ impl RenderSystem {
                 }
             }
         };
+        // Vertical bob: slow float when idle in water, quicker while swimming, gentle sway on the raft
+        let (bob_speed, bob_amplitude) = match (data.player_on_raft, data.player_is_moving) {
+            (true, true) => (0.0, 0.0),
+            (true, false) => crate::constants::PLAYER_BOB_RAFT,
+            (false, true) => crate::constants::PLAYER_BOB_SWIM,
+            (false, false) => crate::constants::PLAYER_BOB_FLOAT,
+        };
+        let bob = (self.frame_count as f32 * bob_speed).sin() * bob_amplitude;
+        if !self.sprites_available {
+            // Colored circle, tinted by raft/water like the minimap marker
+            let color = if data.player_on_raft { crate::constants::PLAYER_ON_RAFT_COLOR } else { crate::constants::PLAYER_SWIMMING_COLOR };
+            let d = crate::constants::PLAYER_RADIUS * 2.0;
+            circ!(d = d, position = (data.world_position.x - d * 0.5, data.world_position.y - d * 0.5 + bob), color = color);
+            return;
+        }
         // Try to render player sprite using world coordinates
-        sprite!(sprite_name, position = (data.world_position.x - 40.0, data.world_position.y - 40.0), size = (80.0, 80.0), origin = (40.0, 40.0));
+        sprite!(sprite_name, position = (data.world_position.x - 40.0, data.world_position.y - 40.0 + bob), size = (80.0, 80.0), origin = (40.0, 40.0));
     }
     
     /// Render fish
     fn render_fish(&self, x: f32, y: f32, data: &RenderData) {
-        circ!(d = data.size, position = (x, y), color = data.color, fixed = true);
+        // Attack telegraph: blink red with a warning ring
+        let blink_on = data.warning_flash && (self.frame_count / 4).is_multiple_of(2);
+        if data.warning_flash {
+            let ring = data.size + 8.0;
+            circ!(d = ring, position = (x - 4.0, y - 4.0), color = 0xFF333366, fixed = true);
+        }
+        let color = if blink_on { 0xFF3333FF } else { data.color };
+        circ!(d = data.size, position = (x, y), color = color, fixed = true);
     }
     
     /// Render monster
@@ -480,6 +761,16 @@ impl RenderSystem {
         let bobbing = (x * 0.05).sin() * 3.0;
         let final_y = y + bobbing;
         
+        // Item art when it's loaded; otherwise fall through to shapes by size
+        let sprite_name = data.item_type
+            .and_then(|item_type| item_type.sprite_name())
+            .filter(|name| turbo::utils::sprite::get_source_data(name).is_some());
+        if let Some(name) = sprite_name {
+            let s = data.size * 1.5;
+            sprite!(name, position = (x - s * 0.5, final_y - s * 0.5), size = (s, s), fixed = true);
+            return;
+        }
+        
         // Render different shapes based on size (which indicates item type)
         if data.size >= 12.0 {
             // Large items (Wood, Barrel) - render as rectangles
@@ -507,7 +798,7 @@ impl RenderSystem {
         }
         
         // Add a subtle outline for better visibility
-        let outline_color = (data.color & 0xFFFFFF00) | 0x80; // Same color with 50% alpha
+        let outline_color = Color::with_alpha(data.color, 0x80); // Same color with 50% alpha
         if data.size >= 8.0 {
             rect!(
                 x = x - data.size * 0.5 - 1.0,
@@ -524,7 +815,8 @@ impl RenderSystem {
     
     /// Render particle
     fn render_particle(&self, x: f32, y: f32, data: &RenderData) {
-        rect!(x = x - 1.0, y = y - 1.0, w = 2.0, h = 2.0, color = data.color, fixed = true);
+        let half = data.size * 0.5;
+        rect!(x = x - half, y = y - half, w = data.size, h = data.size, color = data.color, fixed = true);
     }
     
     /// Render raft
@@ -550,7 +842,19 @@ impl RenderSystem {
                 }
             }
         };
-        if let RenderViewMode::TopDown = self.view_mode {
+        if self.view_mode == RenderViewMode::TopDown && !data.raft_tiles.is_empty() {
+            // One planked square per occupied tile at its grid offset
+            let tile = crate::constants::RAFT_TILE_SIZE * self.zoom;
+            for (corner, tile_type) in &data.raft_tiles {
+                let (tile_x, tile_y) = self.world_to_screen(corner);
+                rect!(x = tile_x, y = tile_y, w = tile, h = tile, color = tile_type.color(), fixed = true);
+                for i in 1..4 {
+                    let t = i as f32 / 4.0;
+                    rect!(x = tile_x, y = tile_y + tile * t, w = tile, h = 1.0, color = 0x8B4513FF, fixed = true);
+                    rect!(x = tile_x + tile * t, y = tile_y, w = 1.0, h = tile, color = 0x8B4513FF, fixed = true);
+                }
+            }
+        } else if let RenderViewMode::TopDown = self.view_mode {
             // Draw a square raft centered at (x, y)
             rect!(
                 x = x - raft_size * 0.5,
@@ -590,17 +894,14 @@ impl RenderSystem {
     }
 
     fn render_ocean_fullscreen(&self, camera_pos: (f32, f32), screen_w: u32, screen_h: u32) {
+        let tile: f32 = 32.0;
+        let Some((min_gx, min_gy, max_gx, max_gy)) = ocean_tile_range(camera_pos, self.zoom, screen_w, screen_h, tile) else { return; };
         // Top-down ocean using a repeating, tile-aligned depth pattern (structured, non-random)
         // Draw per world tile to minimize draw calls and avoid stutter
-        let tile: f32 = 32.0;
         let pattern_size: i32 = 8; // 8x8 cells repeat
-        let screen_w_f = screen_w as f32;
-        let screen_h_f = screen_h as f32;
 
         // Base ocean color (steel blue-ish)
-        let base_r = 0x41 as f32;
-        let base_g = 0x69 as f32;
-        let base_b = 0xE1 as f32;
+        let base_color: u32 = 0x4169E1FF;
 
         // Discrete shade multipliers (dark -> light)
         let shades: [f32; 3] = [0.72, 0.82, 0.92];
@@ -617,13 +918,7 @@ impl RenderSystem {
             [1,1,1,2,2,2,1,1],
         ];
 
-        // Compute visible world tile range
-        let world_left = camera_pos.0 - screen_w_f * 0.5;
-        let world_top  = camera_pos.1 - screen_h_f * 0.5;
-        let min_gx = (world_left / tile).floor() as i32 - 1;
-        let min_gy = (world_top  / tile).floor() as i32 - 1;
-        let max_gx = ((world_left + screen_w_f) / tile).ceil() as i32 + 1;
-        let max_gy = ((world_top  + screen_h_f) / tile).ceil() as i32 + 1;
+        let tile_screen = tile * self.zoom;
 
         // Collect wave positions to draw after filling tiles, so they are not overdrawn
         let mut wave_positions: Vec<(f32, f32)> = Vec::new();
@@ -643,15 +938,11 @@ impl RenderSystem {
                 shade = (shade + ripple).clamp(0.6, 1.0);
 
                 // Convert world tile to screen rect
-                let screen_x = (gx as f32 * tile - camera_pos.0) + screen_w_f * 0.5;
-                let screen_y = (gy as f32 * tile - camera_pos.1) + screen_h_f * 0.5;
+                let (screen_x, screen_y) = self.plane_to_screen((gx as f32 * tile, gy as f32 * tile), camera_pos, screen_w, screen_h);
 
-                let r = (base_r * shade) as u32;
-                let g = (base_g * shade) as u32;
-                let b = (base_b * shade) as u32;
-            let color = (r << 24) | (g << 16) | (b << 8) | 0xFF;
+                let color = Color::scale_brightness(base_color, shade);
 
-                rect!(x = screen_x, y = screen_y, w = tile, h = tile, color = color, fixed = true);
+                rect!(x = screen_x, y = screen_y, w = tile_screen, h = tile_screen, color = color, fixed = true);
 
                 // Queue wave sprite world positions for a second pass
                 if idx == 2 && ((gx + gy) & 1) == 0 {
@@ -664,7 +955,12 @@ impl RenderSystem {
 
         // Second pass: draw waves on top so they are not truncated by later tile fills
         for (wx, wy) in wave_positions.into_iter() {
-            sprite!("waves", position = (wx, wy), size = (20.0, 20.0), origin = (10.0, 10.0));
+            if self.sprites_available {
+                sprite!("waves", position = (wx, wy), size = (20.0, 20.0), origin = (10.0, 10.0));
+            } else {
+                // Faint crest line in place of the wave art
+                rect!(x = wx - 6.0, y = wy - 1.0, w = 12.0, h = 2.0, color = 0xFFFFFF44);
+            }
         }
     }
 }
@@ -678,12 +974,36 @@ pub enum BackgroundLayer {
     UnderwaterLighting,
 }
 
+/// Move `current` toward `target`, closing `smoothing * delta_time` of the gap (clamped to
+/// a full snap). Zero smoothing snaps immediately.
+pub(crate) fn smooth_camera(current: (f32, f32), target: (f32, f32), smoothing: f32, delta_time: f32) -> (f32, f32) {
+    let t = if smoothing <= 0.0 { 1.0 } else { (smoothing * delta_time).clamp(0.0, 1.0) };
+    (current.0 + (target.0 - current.0) * t, current.1 + (target.1 - current.1) * t)
+}
+
+/// Inclusive (min_x, min_y, max_x, max_y) grid range of `tile`-sized ocean cells covering the
+/// screen, with a one-tile border and at most MAX_OCEAN_TILES_PER_AXIS per axis. None when
+/// there is nothing to cover (a 0-sized screen or a non-finite camera).
+pub(crate) fn ocean_tile_range(camera_pos: (f32, f32), zoom: f32, screen_w: u32, screen_h: u32, tile: f32) -> Option<(i32, i32, i32, i32)> {
+    if screen_w == 0 || screen_h == 0 || !camera_pos.0.is_finite() || !camera_pos.1.is_finite() {
+        return None;
+    }
+    let half_w = screen_w as f32 * 0.5 / zoom;
+    let half_h = screen_h as f32 * 0.5 / zoom;
+    let min_gx = ((camera_pos.0 - half_w) / tile).floor() as i32 - 1;
+    let min_gy = ((camera_pos.1 - half_h) / tile).floor() as i32 - 1;
+    let max_gx = (((camera_pos.0 + half_w) / tile).ceil() as i32 + 1).min(min_gx + crate::constants::MAX_OCEAN_TILES_PER_AXIS);
+    let max_gy = (((camera_pos.1 + half_h) / tile).ceil() as i32 + 1).min(min_gy + crate::constants::MAX_OCEAN_TILES_PER_AXIS);
+    Some((min_gx, min_gy, max_gx, max_gy))
+}
+
 /// Render commands for the render queue
 #[turbo::serialize]
 pub enum RenderCommand {
     Entity {
         data: RenderData,
         entity_type: EntityType,
+        entity_id: u32,
     },
     Background {
         layer: RenderLayer,
@@ -702,19 +1022,65 @@ pub enum RenderViewMode {
     SideScroll,
 }
 
+/// What the top-down camera centers on
+#[derive(Copy, PartialEq)]
+#[turbo::serialize]
+pub enum CameraFollowTarget {
+    Player,
+    Raft,
+}
+
 impl RenderSystem {
+    /// Draw a marker clamped to the screen edge in the direction of an off-screen player
+    fn render_offscreen_player_indicator(&self, screen_w: u32, screen_h: u32) {
+        let Some(player_world) = &self.last_player_world_pos else { return; };
+        let (sx, sy) = self.world_to_screen(player_world);
+        let (w, h) = (screen_w as f32, screen_h as f32);
+        if sx >= 0.0 && sx <= w && sy >= 0.0 && sy <= h {
+            return;
+        }
+        let margin = 10.0;
+        let x = sx.clamp(margin, w - margin);
+        let y = sy.clamp(margin, h - margin);
+        circ!(d = 10.0, position = (x - 5.0, y - 5.0), color = crate::constants::PLAYER_SWIMMING_COLOR, fixed = true);
+        circ!(d = 4.0, position = (x - 2.0, y - 2.0), color = 0xFFFFFFFF, fixed = true);
+    }
+
+    /// Edge markers for buoys outside the view, with a small tick toward the buoy
+    fn render_offscreen_buoy_indicators(&self, screen_w: u32, screen_h: u32) {
+        let (w, h) = (screen_w as f32, screen_h as f32);
+        let margin = 8.0;
+        for command in &self.render_queue {
+            let RenderCommand::Entity { data, entity_type: EntityType::Buoy, .. } = command else { continue; };
+            let (sx, sy) = self.world_to_screen(&data.world_position);
+            if sx >= 0.0 && sx <= w && sy >= 0.0 && sy <= h {
+                continue;
+            }
+            let x = sx.clamp(margin, w - margin);
+            let y = sy.clamp(margin, h - margin);
+            let (dx, dy) = (sx - x, sy - y);
+            let len = (dx * dx + dy * dy).sqrt().max(1.0);
+            circ!(d = 8.0, position = (x - 4.0, y - 4.0), color = data.color, fixed = true);
+            let (tx, ty) = (x + dx / len * 6.0, y + dy / len * 6.0);
+            rect!(x = tx - 1.5, y = ty - 1.5, w = 3.0, h = 3.0, color = data.color, fixed = true);
+        }
+    }
+
+    /// Render buoy as a striped float with a flag pole
+    fn render_buoy(&self, x: f32, y: f32, data: &RenderData) {
+        let bobbing = (self.frame_count as f32 * 0.05).sin() * 1.5;
+        let y = y + bobbing;
+        rect!(x = x - 0.5, y = y - data.size * 1.4, w = 1.0, h = data.size, color = 0xDDDDDDFF, fixed = true);
+        rect!(x = x + 0.5, y = y - data.size * 1.4, w = 5.0, h = 3.0, color = data.color, fixed = true);
+        circ!(d = data.size, position = (x - data.size * 0.5, y - data.size * 0.5), color = data.color, fixed = true);
+        rect!(x = x - data.size * 0.5, y = y - 1.0, w = data.size, h = 2.0, color = 0xFFFFFFFF, fixed = true);
+    }
+
     /// Render hook with rectangular body, hook tip, and line to player
     fn render_hook(&self, x: f32, y: f32, _data: &RenderData) {
         // Compute player's screen position from cached world position and camera
-        let (screen_w, screen_h) = resolution();
-        let (cam_x, cam_y) = self.camera_pos;
-
         if let Some(player_world) = &self.last_player_world_pos {
-            let player_screen_x = (player_world.x - cam_x) + screen_w as f32 * 0.5;
-            let player_screen_y = match self.view_mode {
-                RenderViewMode::TopDown => (player_world.y - cam_y) + screen_h as f32 * 0.5,
-                RenderViewMode::SideScroll => (-player_world.z - cam_y) + screen_h as f32 * 0.5,
-            };
+            let (player_screen_x, player_screen_y) = self.world_to_screen(player_world);
 
             // Draw thin line from hook to player using small rect segments
             let dx = player_screen_x - x;
@@ -786,3 +1152,25 @@ impl RenderSystem {
         circ!(d = 3.0, position = (x + 7.0, y), color = 0x8B4513FF, fixed = true);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ocean_tile_range_handles_tiny_screens() {
+        assert_eq!(ocean_tile_range((0.0, 0.0), 1.0, 0, 0, 32.0), None);
+        assert_eq!(ocean_tile_range((0.0, 0.0), 1.0, 0, 360, 32.0), None);
+        assert_eq!(ocean_tile_range((f32::NAN, 0.0), 1.0, 640, 360, 32.0), None);
+
+        // A single pixel still covers one tile plus the border
+        let (min_x, min_y, max_x, max_y) = ocean_tile_range((10.0, 10.0), 1.0, 1, 1, 32.0).unwrap();
+        assert!(min_x <= 0 && max_x >= 0 && min_y <= 0 && max_y >= 0);
+        assert!(max_x - min_x <= 3 && max_y - min_y <= 3);
+
+        // Zoomed far out, the loop extent is capped
+        let (min_x, min_y, max_x, max_y) = ocean_tile_range((0.0, 0.0), 0.001, 640, 360, 32.0).unwrap();
+        assert_eq!(max_x - min_x, crate::constants::MAX_OCEAN_TILES_PER_AXIS);
+        assert_eq!(max_y - min_y, crate::constants::MAX_OCEAN_TILES_PER_AXIS);
+    }
+}
@@ -9,6 +9,11 @@ pub struct UIRenderer {
     current_ui_mode: UIMode,
     hud_state: Option<HudState>,
     minimap_points: Vec<MinimapPoint>,
+    minimap_range: f32, // World units from the player to the minimap edge
+    floating_texts: Vec<FloatingText>,
+    notifications: Vec<Notification>, // Stacked toasts, oldest first
+    displayed_vitals: Option<(f32, f32, f32)>, // Smoothed (health, hunger, thirst) shown in the HUD
+    main_menu: Option<MainMenuState>,
 }
 
 impl UIRenderer {
@@ -18,6 +23,11 @@ impl UIRenderer {
             current_ui_mode: UIMode::Playing,
             hud_state: None,
             minimap_points: Vec::new(),
+            minimap_range: MINIMAP_RANGE,
+            floating_texts: Vec::new(),
+            notifications: Vec::new(),
+            displayed_vitals: None,
+            main_menu: None,
         }
     }
     
@@ -31,11 +41,90 @@ impl UIRenderer {
         self.hud_state = Some(state);
     }
 
+    /// Set what the title screen shows (seed, loadout, highlighted button)
+    pub fn set_main_menu_state(&mut self, state: MainMenuState) {
+        self.main_menu = Some(state);
+    }
+
     /// Set minimap points (world-space projected externally)
     pub fn set_minimap_points(&mut self, points: Vec<MinimapPoint>) {
         self.minimap_points = points;
     }
     
+    /// World units shown from the player to the minimap edge
+    pub fn minimap_range(&self) -> f32 {
+        self.minimap_range
+    }
+
+    /// Widen (positive) or narrow (negative) the minimap range, clamped to its limits
+    pub fn adjust_minimap_range(&mut self, delta: f32) {
+        self.minimap_range = (self.minimap_range + delta).clamp(MIN_MINIMAP_RANGE, MAX_MINIMAP_RANGE);
+    }
+
+    /// World units to minimap pixels, so the range edge lands on the box edge
+    pub fn minimap_scale(&self) -> f32 {
+        (MINIMAP_SIZE * 0.5 - MINIMAP_EDGE_INSET) / self.minimap_range
+    }
+    
+    /// Queue a floating feedback number at a screen position; the oldest is dropped past the cap
+    pub fn push_floating_text(&mut self, text: String, x: f32, y: f32, color: u32) {
+        if self.floating_texts.len() >= MAX_FLOATING_TEXTS {
+            self.floating_texts.remove(0);
+        }
+        self.floating_texts.push(FloatingText { text, x, y, color, age: 0.0, lifetime: FLOATING_TEXT_LIFETIME });
+    }
+
+    /// Advance floating text animation and drop expired entries
+    pub fn update_floating_texts(&mut self, delta_time: f32) {
+        for t in &mut self.floating_texts {
+            t.age += delta_time;
+        }
+        self.floating_texts.retain(|t| t.age < t.lifetime);
+    }
+    
+    /// Queue a corner toast shown for `duration` seconds
+    pub fn push_notification(&mut self, text: String, duration: f32) {
+        self.push_colored_notification(text, UI_TEXT_WHITE, duration);
+    }
+
+    /// Queue a corner toast in a specific color; the oldest is dropped past the cap
+    pub fn push_colored_notification(&mut self, text: String, color: u32, duration: f32) {
+        if self.notifications.len() >= MAX_NOTIFICATIONS {
+            self.notifications.remove(0);
+        }
+        self.notifications.push(Notification { text, color, remaining: duration });
+    }
+
+    /// Queue an event toast ("Recipe discovered", "Inventory full"). Repeats of a toast
+    /// that is still showing refresh it instead of stacking copies.
+    pub fn push_toast(&mut self, text: String, duration: f32) {
+        if let Some(existing) = self.notifications.iter_mut().find(|n| n.text == text) {
+            existing.remaining = existing.remaining.max(duration);
+            return;
+        }
+        self.push_notification(text, duration);
+    }
+
+    /// Count down toasts and drop expired ones
+    pub fn update_notifications(&mut self, delta_time: f32) {
+        for n in &mut self.notifications {
+            n.remaining -= delta_time;
+        }
+        self.notifications.retain(|n| n.remaining > 0.0);
+    }
+    
+    /// Ease the displayed survival values toward the HUD state (snaps on first frame)
+    pub fn update_displayed_vitals(&mut self, delta_time: f32) {
+        let Some(hud) = &self.hud_state else { return; };
+        let target = (hud.health, hud.hunger, hud.thirst);
+        let t = (SURVIVAL_BAR_LERP_RATE * delta_time).clamp(0.0, 1.0);
+        let ease = |from: f32, to: f32| if (to - from).abs() < 0.05 { to } else { from + (to - from) * t };
+        self.displayed_vitals = Some(match self.displayed_vitals {
+            Some((h, f, w)) => (ease(h, target.0), ease(f, target.1), ease(w, target.2)),
+            None => target,
+        });
+    }
+    
     /// Add UI element
     pub fn add_ui_element(&mut self, element: UIElement) {
         self.ui_elements.push(element);
@@ -46,6 +135,67 @@ impl UIRenderer {
         self.ui_elements.retain(|e| e.id != id);
     }
     
+    /// Lay out the pause panel buttons for the current resolution
+    pub fn show_pause_menu(&mut self) {
+        self.hide_pause_menu();
+        let (w, h) = resolution();
+        for (i, (id, label)) in PAUSE_MENU_BUTTONS.into_iter().enumerate() {
+            let (x, y, bw, bh) = pause_button_rect(w as f32, h as f32, i);
+            self.add_ui_element(UIElement::new_button(id, V2::new(x, y), V2::new(bw, bh), label, UI_BUTTON_BG));
+        }
+    }
+    
+    /// Drop the pause panel buttons
+    pub fn hide_pause_menu(&mut self) {
+        for (id, _) in PAUSE_MENU_BUTTONS {
+            self.remove_ui_element(id);
+        }
+    }
+    
+    /// Lay out the title screen buttons; Continue is grayed out without a save to load
+    pub fn show_main_menu(&mut self, can_continue: bool) {
+        self.hide_main_menu();
+        let (w, h) = resolution();
+        for (i, (id, label)) in MAIN_MENU_BUTTONS.into_iter().enumerate() {
+            let (x, y, bw, bh) = main_menu_button_rect(w as f32, h as f32, i);
+            let color = if id == "menu_continue" && !can_continue { UI_BUTTON_DISABLED } else { UI_BUTTON_BG };
+            self.add_ui_element(UIElement::new_button(id, V2::new(x, y), V2::new(bw, bh), label, color));
+        }
+    }
+    
+    /// Drop the title screen buttons
+    pub fn hide_main_menu(&mut self) {
+        for (id, _) in MAIN_MENU_BUTTONS {
+            self.remove_ui_element(id);
+        }
+    }
+    
+    /// Lay out the options panel: one caption per row with its buttons right-aligned,
+    /// plus a Back button. Every element id starts with "options_".
+    pub fn show_options_menu(&mut self, rows: &[OptionsRow]) {
+        self.hide_options_menu();
+        let (w, h) = resolution();
+        let (w, h) = (w as f32, h as f32);
+        for (i, row) in rows.iter().enumerate() {
+            let (x, y, row_w, row_h) = options_row_rect(w, h, i);
+            self.add_ui_element(UIElement::new_text(&format!("options_label_{}", i), V2::new(x, y + 6.0), &row.label, UI_TEXT_WHITE));
+            let mut right = x + row_w;
+            for (id, label) in row.buttons.iter().rev() {
+                let bw = (label.len() as f32 * 6.0 + 12.0).max(24.0);
+                right -= bw;
+                self.add_ui_element(UIElement::new_button(id, V2::new(right, y), V2::new(bw, row_h), label, UI_BUTTON_BG));
+                right -= 4.0;
+            }
+        }
+        let (panel_x, panel_y, panel_w, panel_h) = options_panel_rect(w, h);
+        self.add_ui_element(UIElement::new_button("options_back", V2::new(panel_x + (panel_w - 80.0) * 0.5, panel_y + panel_h - 50.0), V2::new(80.0, 20.0), "Back", UI_BUTTON_BG));
+    }
+    
+    /// Drop every options panel element
+    pub fn hide_options_menu(&mut self) {
+        self.ui_elements.retain(|e| !e.id.starts_with("options_"));
+    }
+    
     /// Render all UI based on current mode
     pub fn render(&self) {
         match self.current_ui_mode {
@@ -53,12 +203,51 @@ impl UIRenderer {
             UIMode::Inventory => self.render_inventory(),
             UIMode::Crafting => self.render_crafting(),
             UIMode::Paused => self.render_paused(),
+            UIMode::Options => self.render_options(),
+            UIMode::MainMenu => self.render_main_menu(),
         }
         
         // Render common UI elements
         self.render_common_ui();
     }
     
+    /// Render the title screen: title art, the editable world seed and starting loadout, and a
+    /// highlight around the selected button (the buttons are UI elements drawn by render_common_ui)
+    pub fn render_main_menu(&self) {
+        let Some(menu) = &self.main_menu else { return; };
+        let (screen_w, screen_h) = resolution();
+        let (w, h) = (screen_w as f32, screen_h as f32);
+        let cx = w * 0.5;
+        let cy = h * 0.5;
+        rect!(x = 0.0, y = 0.0, w = w, h = h, color = 0x0B1E3AFF, fixed = true);
+        self.render_title_art(cx, cy - 150.0);
+        text!("PIXEL FORGE", x = cx - 44.0, y = cy - 92.0, color = UI_TEXT_WHITE, fixed = true);
+        let t_seed = format!("World seed: {}", menu.seed);
+        rect!(x = cx - 80.0, y = cy - 72.0, w = 160.0, h = 20.0, color = 0x00000088, fixed = true);
+        text!(t_seed.as_str(), x = cx - 72.0, y = cy - 66.0, color = UI_TEXT_WHITE, fixed = true);
+        text!("0-9: type seed  Backspace: erase  R: random", x = cx - 110.0, y = cy - 44.0, color = UI_TEXT_GRAY, fixed = true);
+        let t_loadout = format!("Loadout: {} (T to change)", menu.loadout);
+        text!(t_loadout.as_str(), x = cx - 72.0, y = cy - 28.0, color = UI_TEXT_WHITE, fixed = true);
+        let (x, y, bw, bh) = main_menu_button_rect(w, h, menu.selected);
+        rect!(x = x - 2.0, y = y - 2.0, w = bw + 4.0, h = bh + 4.0, color = UI_TEXT_WHITE, fixed = true);
+        text!("W/S or arrows: select  Enter: confirm", x = cx - 92.0, y = cy + 100.0, color = UI_TEXT_GRAY, fixed = true);
+    }
+    
+    /// Pixel banner above the title: a sun over the waves with a small raft riding them
+    fn render_title_art(&self, cx: f32, top: f32) {
+        circ!(d = 28.0, position = (cx + 30.0, top), color = 0xFFC857FF, fixed = true);
+        for (i, color) in [0x1E5A8CFFu32, 0x17497AFF, 0x113A66FF].into_iter().enumerate() {
+            let y = top + 22.0 + i as f32 * 6.0;
+            for k in 0..12 {
+                let crest = if (k + i) % 2 == 0 { 0.0 } else { 2.0 };
+                rect!(x = cx - 96.0 + k as f32 * 16.0, y = y + crest, w = 16.0, h = 6.0 - crest, color = color, fixed = true);
+            }
+        }
+        rect!(x = cx - 34.0, y = top + 16.0, w = 28.0, h = 6.0, color = RAFT_WOOD_FLOOR_COLOR, fixed = true);
+        rect!(x = cx - 21.0, y = top - 4.0, w = 2.0, h = 20.0, color = 0x8B5A2BFF, fixed = true);
+        rect!(x = cx - 19.0, y = top - 2.0, w = 12.0, h = 12.0, color = 0xEEE8D5FF, fixed = true);
+    }
+    
     /// Render HUD for playing mode
     fn render_hud(&self) {
         let (screen_w, _screen_h) = resolution();
@@ -66,13 +255,43 @@ impl UIRenderer {
             // Tool info
             let t1 = format!("Tool: {}", hud.tool);
             text!(t1.as_str(), x = 10, y = 10, color = UI_TEXT_WHITE, fixed = true);
-            // Survival stats
-            let t2 = format!("Health: {}/100", hud.health as i32);
-            let t3 = format!("Hunger: {}/100", hud.hunger as i32);
-            let t4 = format!("Thirst: {}/100", hud.thirst as i32);
-            text!(t2.as_str(), x = 10, y = 26, color = UI_TEXT_RED, fixed = true);
-            text!(t3.as_str(), x = 10, y = 42, color = UI_TEXT_ORANGE, fixed = true);
-            text!(t4.as_str(), x = 10, y = 58, color = UI_TEXT_BLUE, fixed = true);
+            // Survival stats (smoothed values with bars)
+            let (health, hunger, thirst) = self.displayed_vitals.unwrap_or((hud.health, hud.hunger, hud.thirst));
+            let stats = [("Health", health, UI_TEXT_RED, 26.0), ("Hunger", hunger, UI_TEXT_ORANGE, 42.0), ("Thirst", thirst, UI_TEXT_BLUE, 58.0)];
+            for (label, value, color, y) in stats {
+                let t = format!("{}: {}/100", label, value.round() as i32);
+                text!(t.as_str(), x = 10.0, y = y, color = color, fixed = true);
+                let bar_w = 60.0;
+                rect!(x = 110.0, y = y + 1.0, w = bar_w, h = 5.0, color = 0x333333CC, fixed = true);
+                rect!(x = 110.0, y = y + 1.0, w = bar_w * (value / 100.0).clamp(0.0, 1.0), h = 5.0, color = color, fixed = true);
+            }
+            // Carry weight turns orange once it starts slowing swimming
+            let weight_color = if hud.carry_weight > CARRY_WEIGHT_FREE { UI_TEXT_ORANGE } else { UI_TEXT_WHITE };
+            let t_weight = format!("Weight: {}/{}", hud.carry_weight.round() as i32, CARRY_WEIGHT_MAX as i32);
+            text!(t_weight.as_str(), x = 10, y = 74, color = weight_color, fixed = true);
+            // Warmth turns blue once the cold starts to bite
+            let warmth_color = if hud.temperature < COLD_THRESHOLD { UI_TEXT_BLUE } else { UI_TEXT_WHITE };
+            let t_warmth = format!("Warmth: {}", hud.temperature.round() as i32);
+            text!(t_warmth.as_str(), x = 110, y = 74, color = warmth_color, fixed = true);
+            let t_seed = format!("Seed: {}", hud.world_seed);
+            text!(t_seed.as_str(), x = 10, y = 90, color = UI_TEXT_GRAY, fixed = true);
+            let minutes = (hud.survival_time / 60.0).floor() as u32;
+            let t_day = format!("Day {}  {}h{:02}m", hud.day, minutes / 60, minutes % 60);
+            text!(t_day.as_str(), x = 110, y = 90, color = UI_TEXT_WHITE, fixed = true);
+            if let Some(water) = hud.collector_water {
+                let t_water = format!("Collector: {}/{}", water.floor() as i32, COLLECTOR_CAPACITY as i32);
+                text!(t_water.as_str(), x = 10, y = 106, color = UI_TEXT_BLUE, fixed = true);
+            }
+            if let Some(breath) = hud.breath {
+                self.render_breath_meter(breath, screen_w);
+            }
+            // Stamina bar while recovering; gray until sprinting is allowed again
+            if let Some((stamina, exhausted)) = hud.stamina {
+                let color = if exhausted { UI_TEXT_GRAY } else { UI_TEXT_GREEN };
+                text!("Stamina", x = 10.0, y = 118.0, color = color, fixed = true);
+                rect!(x = 60.0, y = 119.0, w = 100.0, h = 4.0, color = 0x333333CC, fixed = true);
+                rect!(x = 60.0, y = 119.0, w = 100.0 * stamina.clamp(0.0, 1.0), h = 4.0, color = color, fixed = true);
+            }
             // Game status
             let t5 = format!("Status: {}", hud.status);
             text!(t5.as_str(), x = 10, y = 130, color = UI_TEXT_WHITE, fixed = true);
@@ -92,15 +311,149 @@ impl UIRenderer {
             text!("Status: --", x = 10, y = 130, color = UI_TEXT_WHITE, fixed = true);
         }
         
-        // Controls
-        text!("WASD: Move, E: Switch Tool, F: Eat", x = 10, y = 90, color = UI_TEXT_WHITE, fixed = true);
-        text!("I: Inventory, C: Crafting", x = 10, y = 106, color = UI_TEXT_WHITE, fixed = true);
+        // Control hints, bottom-left above the hotbar row
+        if let Some(lines) = self.hud_state.as_ref().and_then(|h| h.control_hints.as_ref()) {
+            let (_, screen_h) = resolution();
+            let line_h = 10.0;
+            let start_y = screen_h as f32 - 40.0 - lines.len() as f32 * line_h;
+            for (i, line) in lines.iter().enumerate() {
+                text!(line.as_str(), x = 10.0, y = start_y + i as f32 * line_h, color = UI_TEXT_GRAY, fixed = true);
+            }
+        }
+        
+        // Interaction prompt floating above the player
+        if let Some((hint, (player_x, player_y))) = self.hud_state.as_ref().and_then(|h| h.interaction_hint.as_ref()) {
+            let w = hint.len() as f32 * 5.0 + 8.0;
+            let x = player_x - w * 0.5;
+            let y = player_y - 28.0;
+            rect!(x = x, y = y, w = w, h = 12.0, color = 0x000000AA, fixed = true);
+            text!(hint.as_str(), x = x + 4.0, y = y + 3.0, color = UI_TEXT_WHITE, fixed = true);
+        }
+        
+        if let Some((tension, progress)) = self.hud_state.as_ref().and_then(|h| h.fish_fight) {
+            self.render_fish_fight(tension, progress, screen_w);
+        }
         
         // Minimap
         self.render_minimap(screen_w);
+        if let Some(hud) = &self.hud_state {
+            self.render_wind_compass(hud.wind, screen_w);
+            self.render_heading_indicator(hud.facing, screen_w);
+        }
 
         // Hotbar (10 fixed slots like Minecraft)
         self.render_hotbar();
+        self.render_quick_craft_bar();
+
+        // Damage/heal popups above the player
+        self.render_floating_texts();
+    }
+
+    /// Wind compass left of the minimap: the arrow points downwind and grows with strength
+    fn render_wind_compass(&self, wind: (f32, f32), screen_w: u32) {
+        let d = 30.0;
+        let cx = screen_w as f32 - 80.0 - 8.0 - 6.0 - d * 0.5;
+        let cy = 8.0 + d * 0.5;
+        circ!(d = d, position = (cx - d * 0.5, cy - d * 0.5), color = 0x00000088, border_size = 1, border_color = UI_TEXT_GRAY, fixed = true);
+        let strength = (wind.0 * wind.0 + wind.1 * wind.1).sqrt();
+        if strength > 0.0 {
+            let (dx, dy) = (wind.0 / strength, wind.1 / strength);
+            let len = (d * 0.5 - 2.0) * (strength / WIND_MAX_STRENGTH).clamp(0.2, 1.0);
+            // Shaft as a dotted line from the tail (upwind) to the head
+            let steps = 8;
+            for i in 0..=steps {
+                let t = i as f32 / steps as f32 * 2.0 - 1.0;
+                rect!(x = cx + dx * len * t - 1.0, y = cy + dy * len * t - 1.0, w = 2.0, h = 2.0, color = UI_TEXT_WHITE, fixed = true);
+            }
+            rect!(x = cx + dx * len - 2.0, y = cy + dy * len - 2.0, w = 4.0, h = 4.0, color = UI_TEXT_ORANGE, fixed = true);
+        }
+        let label = format!("Wind {:.1}", strength);
+        text!(label.as_str(), x = cx - d * 0.5, y = cy + d * 0.5 + 3.0, color = UI_TEXT_GRAY, fixed = true);
+    }
+
+    /// Heading dial under the wind compass: the arrow points where the player last moved
+    fn render_heading_indicator(&self, facing: f32, screen_w: u32) {
+        let d = 30.0;
+        let cx = screen_w as f32 - 80.0 - 8.0 - 6.0 - d * 0.5;
+        let cy = 8.0 + d + 16.0 + d * 0.5;
+        circ!(d = d, position = (cx - d * 0.5, cy - d * 0.5), color = 0x00000088, border_size = 1, border_color = UI_TEXT_GRAY, fixed = true);
+        let (dx, dy) = (facing.cos(), facing.sin());
+        let len = d * 0.5 - 3.0;
+        for i in 0..=6 {
+            let t = i as f32 / 6.0;
+            rect!(x = cx + dx * len * t - 1.0, y = cy + dy * len * t - 1.0, w = 2.0, h = 2.0, color = UI_TEXT_WHITE, fixed = true);
+        }
+        rect!(x = cx + dx * len - 2.0, y = cy + dy * len - 2.0, w = 4.0, h = 4.0, color = UI_TEXT_GREEN, fixed = true);
+        text!("Heading", x = cx - d * 0.5, y = cy + d * 0.5 + 3.0, color = UI_TEXT_GRAY, fixed = true);
+    }
+
+    /// Fish fight bar centered above the hotbar: the marker is line tension, the green band the
+    /// zone to hold it in, and the strip underneath how close the fish is to being landed
+    fn render_fish_fight(&self, tension: f32, progress: f32, screen_w: u32) {
+        let (_, screen_h) = resolution();
+        let (w, h) = (140.0, 8.0);
+        let x = (screen_w as f32 - w) * 0.5;
+        let y = screen_h as f32 - 80.0;
+        let label = "Fish on! Tap reel to hold the line";
+        text!(label, x = (screen_w as f32 - label.len() as f32 * 5.0) * 0.5, y = y - 12.0, color = UI_TEXT_WHITE, fixed = true);
+        rect!(x = x, y = y, w = w, h = h, color = 0x333333CC, fixed = true);
+        rect!(x = x + w * FISH_FIGHT_ZONE_MIN, y = y, w = w * (FISH_FIGHT_ZONE_MAX - FISH_FIGHT_ZONE_MIN), h = h, color = 0x2E8B5799, fixed = true);
+        let in_zone = (FISH_FIGHT_ZONE_MIN..=FISH_FIGHT_ZONE_MAX).contains(&tension);
+        let marker = if in_zone { UI_TEXT_GREEN } else { UI_TEXT_RED };
+        rect!(x = x + w * tension.clamp(0.0, 1.0) - 1.5, y = y - 2.0, w = 3.0, h = h + 4.0, color = marker, fixed = true);
+        rect!(x = x, y = y + h + 3.0, w = w, h = 3.0, color = 0x333333CC, fixed = true);
+        rect!(x = x, y = y + h + 3.0, w = w * progress.clamp(0.0, 1.0), h = 3.0, color = UI_TEXT_BLUE, fixed = true);
+    }
+
+    /// Oxygen bar under the vitals while diving: blue, flashing red below BREATH_LOW_FRACTION,
+    /// plus a pulsing screen border and banner once it's empty and health is draining
+    fn render_breath_meter(&self, breath: f32, screen_w: u32) {
+        let flash_on = (turbo::time::tick() / 8).is_multiple_of(2);
+        let low = breath < BREATH_LOW_FRACTION;
+        let color = if low && flash_on { UI_TEXT_RED } else { UI_TEXT_BLUE };
+        let y = 106.0;
+        text!("Breath", x = 10.0, y = y, color = color, fixed = true);
+        let bar_w = 100.0;
+        rect!(x = 60.0, y = y + 1.0, w = bar_w, h = 6.0, color = 0x333333CC, fixed = true);
+        rect!(x = 60.0, y = y + 1.0, w = bar_w * breath.clamp(0.0, 1.0), h = 6.0, color = color, fixed = true);
+        
+        if breath <= 0.0 {
+            let (_, screen_h) = resolution();
+            let (w, h) = (screen_w as f32, screen_h as f32);
+            let border = if flash_on { 0xFF0000AA } else { 0xFF000055 };
+            rect!(x = 0.0, y = 0.0, w = w, h = 4.0, color = border, fixed = true);
+            rect!(x = 0.0, y = h - 4.0, w = w, h = 4.0, color = border, fixed = true);
+            rect!(x = 0.0, y = 0.0, w = 4.0, h = h, color = border, fixed = true);
+            rect!(x = w - 4.0, y = 0.0, w = 4.0, h = h, color = border, fixed = true);
+            let msg = "OUT OF AIR - SURFACE!";
+            let msg_x = (w - msg.len() as f32 * 5.0) * 0.5;
+            rect!(x = msg_x - 6.0, y = 36.0, w = msg.len() as f32 * 5.0 + 12.0, h = 14.0, color = 0x000000AA, fixed = true);
+            text!(msg, x = msg_x, y = 40.0, color = UI_TEXT_RED, fixed = true);
+        }
+    }
+
+    /// Render toasts right-aligned under the minimap, newest at the bottom, fading out at the end
+    fn render_notifications(&self, screen_w: u32) {
+        let line_h = 14.0;
+        let start_y = 96.0;
+        for (i, n) in self.notifications.iter().enumerate() {
+            let alpha = (n.remaining / NOTIFICATION_FADE_TIME).clamp(0.0, 1.0);
+            let w = n.text.len() as f32 * 5.0 + 8.0;
+            let x = screen_w as f32 - w - 8.0;
+            let y = start_y + i as f32 * line_h;
+            rect!(x = x, y = y, w = w, h = line_h - 2.0, color = crate::color::Color::with_alpha(0x000000FF, (alpha * 136.0) as u8), fixed = true);
+            let color = crate::color::Color::with_alpha(n.color, (alpha * 255.0) as u8);
+            text!(n.text.as_str(), x = x + 4.0, y = y + 2.0, color = color, fixed = true);
+        }
+    }
+
+    /// Render floating feedback numbers rising and fading out
+    fn render_floating_texts(&self) {
+        for t in &self.floating_texts {
+            let p = (t.age / t.lifetime).clamp(0.0, 1.0);
+            let color = crate::color::Color::with_alpha(t.color, ((1.0 - p) * 255.0) as u8);
+            text!(t.text.as_str(), x = t.x, y = t.y - p * FLOATING_TEXT_RISE, color = color, fixed = true);
+        }
     }
     
     /// Render inventory UI
@@ -113,26 +466,70 @@ impl UIRenderer {
         }
     }
     
+    /// Render the inventory right-click menu at its anchor
+    pub fn render_inventory_context_menu(&self, menu_x: f32, menu_y: f32) {
+        for (i, label) in CONTEXT_MENU_OPTIONS.iter().enumerate() {
+            let (x, y, w, h) = context_menu_button_rect(menu_x, menu_y, i);
+            rect!(x = x, y = y, w = w, h = h, color = 0x222222EE, fixed = true);
+            rect!(x = x, y = y + h - 1.0, w = w, h = 1.0, color = 0x555555FF, fixed = true);
+            text!(label, x = x + 4.0, y = y + 4.0, color = UI_TEXT_WHITE, fixed = true);
+        }
+    }
+    
+    /// Item name, count and (for perishables) freshness beside the cursor, kept fully on screen
+    pub fn render_item_tooltip(&self, slot: &crate::models::player::InventorySlot, mouse_x: f32, mouse_y: f32) {
+        let Some(item_type) = slot.item_type else { return; };
+        let (screen_w, screen_h) = resolution();
+        let (name, quantity) = (item_type.display_name(), slot.quantity);
+        let label = if item_type.spoil_time().is_none() {
+            format!("{} x{}", name, quantity)
+        } else if slot.is_spoiled() {
+            format!("{} x{} (Spoiled)", name, quantity)
+        } else {
+            format!("{} x{} ({:.0}% fresh)", name, quantity, slot.freshness * 100.0)
+        };
+        let w = label.len() as f32 * 5.0 + 8.0;
+        let h = 14.0_f32;
+        let x = (mouse_x + 10.0).min(screen_w as f32 - w).max(0.0);
+        let y = (mouse_y + 10.0).min(screen_h as f32 - h).max(0.0);
+        rect!(x = x, y = y, w = w, h = h, color = 0x000000DD, fixed = true);
+        rect!(x = x, y = y + h - 1.0, w = w, h = 1.0, color = 0x555555FF, fixed = true);
+        text!(&label, x = x + 4.0, y = y + 4.0, color = UI_TEXT_WHITE, fixed = true);
+    }
+    
     /// Render inventory UI with actual player data
     pub fn render_inventory_with_data(&self, inventory_data: Option<&crate::models::player::Inventory>) {
-        self.render_inventory_with_data_and_drag(inventory_data, None);
+        self.render_inventory_with_data_and_drag(inventory_data, None, None, false);
     }
 
-    /// Render inventory UI with drag preview
-    pub fn render_inventory_with_data_and_drag(&self, inventory_data: Option<&crate::models::player::Inventory>, dragging: Option<(u32, u32, f32, f32)>) {
+    /// Render inventory UI with drag preview; slots outside `filter` are dimmed
+    pub fn render_inventory_with_data_and_drag(&self, inventory_data: Option<&crate::models::player::Inventory>, dragging: Option<(u32, u32, f32, f32)>, filter: Option<crate::models::ocean::ItemCategory>, docked: bool) {
         let (w, h) = resolution();
-        // Full-screen panel with small margins
-        let panel_margin = 8.0_f32;
-        let panel_x = panel_margin;
-        let panel_y = panel_margin;
-        let panel_w = w as f32 - panel_margin * 2.0;
-        let panel_h = h as f32 - panel_margin * 2.0;
+        if w < MIN_RENDER_DIMENSION || h < MIN_RENDER_DIMENSION {
+            return;
+        }
+        let (panel_x, panel_y, panel_w, panel_h) = inventory_panel_rect(w, h, docked);
         
         // Background
         rect!(x = panel_x, y = panel_y, w = panel_w, h = panel_h, color = UI_PANEL_BG, fixed = true);
         
         // Title
         text!("INVENTORY", x = panel_x + 10.0, y = panel_y + 10.0, color = UI_TEXT_WHITE, fixed = true);
+        for (i, label) in INVENTORY_BUTTONS.into_iter().enumerate() {
+            let (bx, by, bw, bh) = inventory_button_rect(w, h, docked, i);
+            rect!(x = bx, y = by, w = bw, h = bh, color = UI_BUTTON_BG, fixed = true);
+            text!(label, x = bx + (bw - label.len() as f32 * 5.0) * 0.5, y = by + 4.0, color = UI_TEXT_WHITE, fixed = true);
+        }
+        let filter_text = format!("Filter: {} (Tab)", filter.map(|c| c.name()).unwrap_or("All"));
+        text!(filter_text.as_str(), x = panel_x + panel_w - 140.0, y = panel_y + 10.0, color = UI_TEXT_GRAY, fixed = true);
+        // Slots whose item doesn't match the active filter get a dark overlay
+        let is_dimmed = |slot: &crate::models::player::InventorySlot| -> bool {
+            match (filter, slot.item_type) {
+                (Some(category), Some(item_type)) => item_type.category() != category,
+                (Some(_), None) => true,
+                _ => false,
+            }
+        };
         
         if let Some(inventory) = inventory_data {
             // Layout: 10-wide full-screen grid
@@ -181,6 +578,9 @@ impl UIRenderer {
                 // Index label (1-9,0)
                 let label = if i < 9 { (i + 1).to_string() } else { "0".to_string() };
                 text!(label.as_str(), x = slot_x + 2.0, y = slot_y + 2.0, color = UI_TEXT_WHITE, fixed = true);
+                if inventory.get_slot(i).is_some_and(is_dimmed) {
+                    rect!(x = slot_x, y = slot_y, w = hotbar_slot_size, h = hotbar_slot_size, color = 0x000000AA, fixed = true);
+                }
             }
 
             // Inventory grid below hotbar
@@ -223,6 +623,9 @@ impl UIRenderer {
                             text!(qty_text.as_str(), x = slot_x + slot_size - 16.0, y = slot_y + slot_size - 12.0, color = UI_TEXT_WHITE, fixed = true);
                         }
                     }
+                    if is_dimmed(slot) {
+                        rect!(x = slot_x, y = slot_y, w = slot_size, h = slot_size, color = 0x000000AA, fixed = true);
+                    }
                 }
             }
             
@@ -248,21 +651,21 @@ impl UIRenderer {
         let instr_y1 = panel_y + panel_h - 52.0;
         let instr_y2 = panel_y + panel_h - 32.0;
         text!("Click to select, Drag to move, Right-click for quick slot", x = panel_x + 10.0, y = instr_y1, color = UI_TEXT_GRAY, fixed = true);
-        text!("Press I to close", x = panel_x + 10.0, y = instr_y2, color = UI_TEXT_GRAY, fixed = true);
+        text!("Press I to close, B to dock/undock", x = panel_x + 10.0, y = instr_y2, color = UI_TEXT_GRAY, fixed = true);
     }
     
     /// Render crafting UI
     fn render_crafting(&self) {
-        self.render_crafting_with_data(None, None);
+        self.render_crafting_with_data(None, None, &[], None, None);
     }
     
     /// Render crafting UI with actual game data
-    pub fn render_crafting_with_data(&self, crafting_system: Option<&crate::models::crafting::CraftingSystem>, inventory: Option<&crate::models::player::Inventory>) {
+    pub fn render_crafting_with_data(&self, crafting_system: Option<&crate::models::crafting::CraftingSystem>, inventory: Option<&crate::models::player::Inventory>, pinned: &[String], selected: Option<&str>, equipped: Option<&crate::models::player::Tool>) {
         let (w, h) = resolution();
-        let panel_w = 600.0;
-        let panel_h = 500.0;
-        let panel_x = (w as f32 - panel_w) * 0.5;
-        let panel_y = (h as f32 - panel_h) * 0.5;
+        if w < MIN_RENDER_DIMENSION || h < MIN_RENDER_DIMENSION {
+            return;
+        }
+        let (panel_x, panel_y, panel_w, panel_h) = crafting_panel_rect(w, h);
         
         // Background
         rect!(x = panel_x, y = panel_y, w = panel_w, h = panel_h, color = UI_PANEL_BG, fixed = true);
@@ -270,6 +673,20 @@ impl UIRenderer {
         // Title
         text!("CRAFTING", x = panel_x + 10.0, y = panel_y + 10.0, color = UI_TEXT_WHITE, fixed = true);
         
+        // Front of the craft queue beside the title, with a progress bar
+        if let Some((front, queued, waiting)) = crafting_system.and_then(|c| c.queue.first().map(|f| (f, c.queue.len(), c.is_waiting_for_space()))) {
+            let color = if waiting { UI_TEXT_ORANGE } else { UI_TEXT_GREEN };
+            let label = if waiting { format!("{} (inventory full)", front.name) } else { front.name.clone() };
+            text!(label.as_str(), x = panel_x + 100.0, y = panel_y + 10.0, color = color, fixed = true);
+            let (bar_x, bar_w) = (panel_x + 300.0, 180.0);
+            rect!(x = bar_x, y = panel_y + 11.0, w = bar_w, h = 6.0, color = 0x333333CC, fixed = true);
+            rect!(x = bar_x, y = panel_y + 11.0, w = bar_w * front.progress(), h = 6.0, color = color, fixed = true);
+            if queued > 1 {
+                let more = format!("+{} queued", queued - 1);
+                text!(more.as_str(), x = bar_x + bar_w + 8.0, y = panel_y + 10.0, color = UI_TEXT_GRAY, fixed = true);
+            }
+        }
+        
         if let (Some(crafting), Some(inventory)) = (crafting_system, inventory) {
             let categories = vec![
                 crate::models::crafting::CraftingCategory::Tools,
@@ -301,20 +718,25 @@ impl UIRenderer {
             
             // Show all available recipes (simplified for now)
             let available_recipes = crafting.get_available_recipes();
-            let mut y_offset = 0.0;
             
-            for recipe in available_recipes.iter().take(8) { // Limit to 8 visible recipes
-                let recipe_y = list_start_y + y_offset;
-                let recipe_height = 45.0;
+            for (index, recipe) in available_recipes.iter().take(CRAFTING_VISIBLE_RECIPES).enumerate() {
+                let (_, recipe_y, _, recipe_height) = crafting_recipe_row_rect(w, h, index);
                 
                 // Recipe background
                 let can_craft = crafting.can_craft(&recipe.id, inventory);
                 let recipe_color = if can_craft { 0x444444FF } else { 0x222222FF };
+                if selected == Some(recipe.id.as_str()) {
+                    rect!(x = panel_x + 19.0, y = recipe_y - 1.0, w = panel_w - 38.0, h = recipe_height + 2.0, color = UI_TEXT_WHITE, fixed = true);
+                }
                 rect!(x = panel_x + 20.0, y = recipe_y, w = panel_w - 40.0, h = recipe_height, color = recipe_color, fixed = true);
                 
                 // Recipe name and description
                 let name_color = if can_craft { UI_TEXT_WHITE } else { UI_TEXT_GRAY };
                 text!(recipe.name.as_str(), x = panel_x + 30.0, y = recipe_y + 5.0, color = name_color, fixed = true);
+                if let Some(slot) = pinned.iter().position(|id| id == &recipe.id) {
+                    let pin_text = format!("[F{}]", slot + 1);
+                    text!(pin_text.as_str(), x = panel_x + panel_w - 150.0, y = recipe_y + 5.0, color = UI_TEXT_ORANGE, fixed = true);
+                }
                 text!(recipe.description.as_str(), x = panel_x + 30.0, y = recipe_y + 18.0, color = UI_TEXT_GRAY, fixed = true);
                 
                 // Ingredients
@@ -335,13 +757,20 @@ impl UIRenderer {
                 let result_text = format!("-> {}x{:?}", result_amount, result_type);
                 text!(result_text.as_str(), x = panel_x + panel_w - 150.0, y = recipe_y + 18.0, color = UI_TEXT_WHITE, fixed = true);
                 
-                // Craft button area (visual indication only for now)
+                // Craft button (clicks handled by scenes::crafting via craft_button_rect)
                 if can_craft {
-                    rect!(x = panel_x + panel_w - 80.0, y = recipe_y + 5.0, w = 60.0, h = 20.0, color = 0x00AA00FF, fixed = true);
-                    text!("CRAFT", x = panel_x + panel_w - 75.0, y = recipe_y + 8.0, color = UI_TEXT_WHITE, fixed = true);
+                    let (btn_x, btn_y, btn_w, btn_h) = craft_button_rect(w, h, index);
+                    rect!(x = btn_x, y = btn_y, w = btn_w, h = btn_h, color = 0x00AA00FF, fixed = true);
+                    text!("CRAFT", x = btn_x + 5.0, y = btn_y + 3.0, color = UI_TEXT_WHITE, fixed = true);
                 }
-                
-                y_offset += recipe_height + 5.0;
+            }
+            
+            // Tool comparison for the selected recipe vs the equipped tool
+            let selected_stats = selected
+                .and_then(|id| available_recipes.iter().find(|r| r.id == id))
+                .and_then(|r| r.tool_stats.map(|stats| (r.name.as_str(), stats)));
+            if let (Some((name, stats)), Some(tool)) = (selected_stats, equipped) {
+                self.render_tool_comparison(name, &stats, tool, panel_x + panel_w - 230.0, h as f32 - 80.0);
             }
             
             if available_recipes.is_empty() {
@@ -353,15 +782,35 @@ impl UIRenderer {
             text!("Loading crafting system...", x = panel_x + 20.0, y = panel_y + 50.0, color = UI_TEXT_GRAY, fixed = true);
         }
         
-        text!("Click recipe to craft (when available)", x = panel_x + 10.0, y = panel_y + panel_h - 50.0, color = UI_TEXT_GRAY, fixed = true);
+        text!("Click recipe to select, Right-click to pin to F1-F4", x = panel_x + 10.0, y = panel_y + panel_h - 50.0, color = UI_TEXT_GRAY, fixed = true);
         text!("Press C to close", x = panel_x + 10.0, y = panel_y + panel_h - 30.0, color = UI_TEXT_GRAY, fixed = true);
     }
     
+    /// Render a stat-by-stat comparison of a tool recipe against the equipped tool
+    fn render_tool_comparison(&self, name: &str, stats: &crate::models::player::ToolStats, equipped: &crate::models::player::Tool, x: f32, y: f32) {
+        let current = equipped.stats();
+        rect!(x = x, y = y, w = 210.0, h = 64.0, color = 0x111822EE, fixed = true);
+        let header = format!("{} vs {}", name, equipped.name());
+        text!(header.as_str(), x = x + 6.0, y = y + 4.0, color = UI_TEXT_WHITE, fixed = true);
+        // (label, new, current, higher_is_better)
+        let rows = [
+            ("Hook length", stats.hook_length, current.hook_length, true),
+            ("Catch bonus", stats.catch_bonus, current.catch_bonus, true),
+            ("Cooldown", stats.cooldown, current.cooldown, false),
+        ];
+        for (i, (label, new, old, higher_is_better)) in rows.into_iter().enumerate() {
+            let better = if higher_is_better { new > old } else { new < old };
+            let color = if (new - old).abs() < 1e-3 { UI_TEXT_GRAY } else if better { UI_TEXT_GREEN } else { UI_TEXT_RED };
+            let line = format!("{}: {:.2} ({:.2})", label, new, old);
+            text!(line.as_str(), x = x + 6.0, y = y + 18.0 + i as f32 * 14.0, color = color, fixed = true);
+        }
+    }
+    
     /// Render paused UI
     fn render_paused(&self) {
         let (w, h) = resolution();
         let panel_w = 300.0;
-        let panel_h = 200.0;
+        let panel_h = 230.0;
         let panel_x = (w as f32 - panel_w) * 0.5;
         let panel_y = (h as f32 - panel_h) * 0.5;
         
@@ -371,16 +820,60 @@ impl UIRenderer {
         // Title
         text!("PAUSED", x = panel_x + 10.0, y = panel_y + 10.0, color = UI_TEXT_WHITE, fixed = true);
         
-        text!("Game is paused", x = panel_x + 20.0, y = panel_y + 50.0, color = UI_TEXT_GRAY, fixed = true);
-        text!("Press ESC to resume", x = panel_x + 10.0, y = panel_y + panel_h - 30.0, color = UI_TEXT_GRAY, fixed = true);
+        text!("Game is paused", x = panel_x + 20.0, y = panel_y + 40.0, color = UI_TEXT_GRAY, fixed = true);
+        text!("Progress saved", x = panel_x + 20.0, y = panel_y + 55.0, color = UI_TEXT_GRAY, fixed = true);
+        // Resume/Save/Quit buttons are UI elements drawn by render_common_ui
+        text!("Press ESC to resume", x = panel_x + 10.0, y = panel_y + panel_h - 20.0, color = UI_TEXT_GRAY, fixed = true);
     }
     
-    /// Render common UI elements
-    fn render_common_ui(&self) {
+    /// Render the options panel backdrop; rows and buttons are UI elements drawn by render_common_ui
+    fn render_options(&self) {
+        let (w, h) = resolution();
+        rect!(x = 0.0, y = 0.0, w = w as f32, h = h as f32, color = 0x000000AA, fixed = true);
+        let (panel_x, panel_y, panel_w, panel_h) = options_panel_rect(w as f32, h as f32);
+        rect!(x = panel_x, y = panel_y, w = panel_w, h = panel_h, color = UI_PANEL_BG, fixed = true);
+        text!("OPTIONS", x = panel_x + 10.0, y = panel_y + 10.0, color = UI_TEXT_WHITE, fixed = true);
+        text!("Click a binding, then press the new key", x = panel_x + 10.0, y = panel_y + panel_h - 24.0, color = UI_TEXT_GRAY, fixed = true);
+        text!("Esc: back (or cancel a rebind)", x = panel_x + 10.0, y = panel_y + panel_h - 12.0, color = UI_TEXT_GRAY, fixed = true);
+    }
+    
+    /// Render the fish collection log: every fish type, caught ones with counts
+    pub fn render_collection_log(&self, fish_log: &[(crate::components::entities::entity_factory::FishType, u32)]) {
+        use crate::components::entities::entity_factory::FishType;
+        let (w, h) = resolution();
+        let panel_w = 260.0;
+        let panel_h = 60.0 + FishType::ALL.len() as f32 * 22.0;
+        let panel_x = (w as f32 - panel_w) * 0.5;
+        let panel_y = (h as f32 - panel_h) * 0.5;
+        
+        rect!(x = panel_x, y = panel_y, w = panel_w, h = panel_h, color = UI_PANEL_BG, fixed = true);
+        let caught_kinds = fish_log.len();
+        let title = format!("COLLECTION LOG  {}/{}", caught_kinds, FishType::ALL.len());
+        text!(title.as_str(), x = panel_x + 10.0, y = panel_y + 10.0, color = UI_TEXT_WHITE, fixed = true);
+        
+        for (i, fish_type) in FishType::ALL.iter().enumerate() {
+            let y = panel_y + 34.0 + i as f32 * 22.0;
+            let count = fish_log.iter().find(|(t, _)| t == fish_type).map_or(0, |(_, c)| *c);
+            if count > 0 {
+                let line = format!("{}  x{}", fish_type.name(), count);
+                text!(line.as_str(), x = panel_x + 20.0, y = y, color = UI_TEXT_GREEN, fixed = true);
+            } else {
+                text!("??? (not caught)", x = panel_x + 20.0, y = y, color = UI_TEXT_GRAY, fixed = true);
+            }
+        }
+        text!("Press L to close", x = panel_x + 10.0, y = panel_y + panel_h - 16.0, color = UI_TEXT_GRAY, fixed = true);
+    }
+    
+    /// Render common UI elements; full-screen panels that bypass `render` call this directly
+    pub(crate) fn render_common_ui(&self) {
         // Render any persistent UI elements here
         for element in &self.ui_elements {
             self.render_ui_element(element);
         }
+        
+        // Toasts stacked under the minimap, over every UI mode
+        let (screen_w, _) = resolution();
+        self.render_notifications(screen_w);
     }
     
     /// Render a single UI element
@@ -448,7 +941,7 @@ impl UIRenderer {
     
     /// Render minimap
     fn render_minimap(&self, screen_w: u32) {
-        let minimap_size = 80.0;
+        let minimap_size = MINIMAP_SIZE;
         let minimap_x = screen_w as f32 - minimap_size - 8.0;
         let minimap_y = 8.0;
         
@@ -489,22 +982,19 @@ impl UIRenderer {
         
         // Minimap title
         text!("Map", x = minimap_x, y = minimap_y - 12.0, color = UI_TEXT_WHITE, fixed = true);
+        let range = format!("{}u", self.minimap_range.round() as i32);
+        text!(range.as_str(), x = minimap_x + 2.0, y = minimap_y + minimap_size - 9.0, color = UI_TEXT_GRAY, fixed = true);
     }
 
     /// Render 10-slot hotbar anchored at bottom center
     fn render_hotbar(&self) {
         let (w, h) = resolution();
-        let slot_size = 24.0_f32;
-        let margin = 4.0_f32;
-        let count = 10usize;
-        let total_w = count as f32 * slot_size + (count as f32 - 1.0) * margin;
-        let start_x = (w as f32 - total_w) * 0.5;
-        let y = h as f32 - slot_size - 8.0;
+        let count = HUD_HOTBAR_SLOTS;
         let active_index: Option<usize> = if let Some(h) = &self.hud_state { h.hotbar_active } else { None };
         let items: Option<Vec<Option<(u32, u32)>>> = if let Some(h) = &self.hud_state { h.hotbar_items.clone() } else { None };
 
         for i in 0..count {
-            let x = start_x + i as f32 * (slot_size + margin);
+            let (x, y, slot_size) = hud_hotbar_slot_rect(i, w, h);
             // Background
             rect!(x = x, y = y, w = slot_size, h = slot_size, color = 0x333333CC, fixed = true);
             // Border
@@ -524,6 +1014,32 @@ impl UIRenderer {
         }
     }
     
+    /// Render pinned-recipe slots (F1-F4) to the right of the hotbar
+    fn render_quick_craft_bar(&self) {
+        let Some(hud) = &self.hud_state else { return; };
+        let (w, h) = resolution();
+        let slot_size = 24.0_f32;
+        let margin = 4.0_f32;
+        let hotbar_w = 10.0 * slot_size + 9.0 * margin;
+        let start_x = (w as f32 + hotbar_w) * 0.5 + 16.0;
+        let y = h as f32 - slot_size - 8.0;
+
+        for i in 0..MAX_PINNED_RECIPES {
+            let x = start_x + i as f32 * (slot_size + margin);
+            rect!(x = x - 1.0, y = y - 1.0, w = slot_size + 2.0, h = slot_size + 2.0, color = UI_TEXT_GRAY, fixed = true);
+            rect!(x = x, y = y, w = slot_size, h = slot_size, color = 0x333333CC, fixed = true);
+            if let Some((color, craftable)) = hud.quick_craft.get(i).copied() {
+                let s = slot_size * 0.7;
+                rect!(x = x + (slot_size - s) * 0.5, y = y + (slot_size - s) * 0.5, w = s, h = s, color = color, fixed = true);
+                if !craftable {
+                    rect!(x = x, y = y, w = slot_size, h = slot_size, color = 0x000000AA, fixed = true);
+                }
+            }
+            let label = format!("F{}", i + 1);
+            text!(label.as_str(), x = x + 2.0, y = y + 2.0, color = UI_TEXT_WHITE, fixed = true);
+        }
+    }
+
     /// Check if a point is inside a UI element
     pub fn is_point_in_ui(&self, point: &V2) -> Option<&UIElement> {
         for element in &self.ui_elements {
@@ -554,6 +1070,123 @@ impl UIRenderer {
     }
 }
 
+/// Title screen buttons as (element id, label), top to bottom
+pub(crate) const MAIN_MENU_BUTTONS: [(&str, &str); 3] = [
+    ("menu_start", "Start New Game"),
+    ("menu_continue", "Continue"),
+    ("menu_options", "Options"),
+];
+
+/// Bounds of a title screen button, stacked and centered below the seed and loadout
+pub(crate) fn main_menu_button_rect(screen_w: f32, screen_h: f32, index: usize) -> (f32, f32, f32, f32) {
+    let btn_w = 140.0_f32;
+    let btn_h = 22.0_f32;
+    let pad = 6.0_f32;
+    ((screen_w - btn_w) * 0.5, screen_h * 0.5 + index as f32 * (btn_h + pad), btn_w, btn_h)
+}
+
+/// Pause panel buttons as (element id, label), top to bottom
+pub(crate) const PAUSE_MENU_BUTTONS: [(&str, &str); 4] = [
+    ("pause_resume", "Resume"),
+    ("pause_save", "Save"),
+    ("pause_options", "Options"),
+    ("pause_quit", "Quit to Menu"),
+];
+
+/// Bounds of a pause panel button, stacked and centered in the 300x230 paused panel
+pub(crate) fn pause_button_rect(screen_w: f32, screen_h: f32, index: usize) -> (f32, f32, f32, f32) {
+    let btn_w = 120.0_f32;
+    let btn_h = 22.0_f32;
+    let pad = 6.0_f32;
+    let panel_y = (screen_h - 230.0) * 0.5;
+    ((screen_w - btn_w) * 0.5, panel_y + 80.0 + index as f32 * (btn_h + pad), btn_w, btn_h)
+}
+
+/// Inventory panel buttons next to the title, left to right
+pub(crate) const INVENTORY_BUTTONS: [&str; 2] = ["Sort", "Drop All"];
+
+/// Bounds of an inventory panel button
+pub(crate) fn inventory_button_rect(screen_w: u32, screen_h: u32, docked: bool, index: usize) -> (f32, f32, f32, f32) {
+    let (panel_x, panel_y, _, _) = inventory_panel_rect(screen_w, screen_h, docked);
+    (panel_x + 84.0 + index as f32 * 64.0, panel_y + 6.0, 58.0, 16.0)
+}
+
+/// Options panel bounds, centered on screen
+pub(crate) fn options_panel_rect(screen_w: f32, screen_h: f32) -> (f32, f32, f32, f32) {
+    let panel_w = 320.0_f32;
+    let panel_h = 330.0_f32;
+    ((screen_w - panel_w) * 0.5, (screen_h - panel_h) * 0.5, panel_w, panel_h)
+}
+
+/// Bounds of an options row (caption on the left, buttons on the right) below the title
+pub(crate) fn options_row_rect(screen_w: f32, screen_h: f32, index: usize) -> (f32, f32, f32, f32) {
+    let (panel_x, panel_y, panel_w, _) = options_panel_rect(screen_w, screen_h);
+    (panel_x + 14.0, panel_y + 34.0 + index as f32 * 26.0, panel_w - 28.0, 20.0)
+}
+
+/// Inventory right-click menu entries, top to bottom
+pub(crate) const CONTEXT_MENU_OPTIONS: [&str; 4] = ["Use", "Destroy", "To Hotbar", "Drop"];
+
+/// Bounds of a context menu button stacked below the menu anchor
+pub(crate) fn context_menu_button_rect(menu_x: f32, menu_y: f32, index: usize) -> (f32, f32, f32, f32) {
+    let btn_w = 80.0_f32;
+    let btn_h = 16.0_f32;
+    let pad = 2.0_f32;
+    (menu_x, menu_y + index as f32 * (btn_h + pad), btn_w, btn_h)
+}
+
+/// Recipes listed at once in the crafting panel
+pub(crate) const CRAFTING_VISIBLE_RECIPES: usize = 8;
+
+/// Crafting panel bounds, centered on screen
+pub(crate) fn crafting_panel_rect(screen_w: u32, screen_h: u32) -> (f32, f32, f32, f32) {
+    let panel_w = 600.0_f32;
+    let panel_h = 500.0_f32;
+    ((screen_w as f32 - panel_w) * 0.5, (screen_h as f32 - panel_h) * 0.5, panel_w, panel_h)
+}
+
+/// Bounds of a recipe row in the crafting list (below the title and category tabs)
+pub(crate) fn crafting_recipe_row_rect(screen_w: u32, screen_h: u32, index: usize) -> (f32, f32, f32, f32) {
+    let (panel_x, panel_y, panel_w, _) = crafting_panel_rect(screen_w, screen_h);
+    let list_start_y = panel_y + 35.0 + 30.0 + 10.0;
+    let row_h = 45.0_f32;
+    (panel_x + 20.0, list_start_y + index as f32 * (row_h + 5.0), panel_w - 40.0, row_h)
+}
+
+/// Bounds of the CRAFT button inside a recipe row
+pub(crate) fn craft_button_rect(screen_w: u32, screen_h: u32, index: usize) -> (f32, f32, f32, f32) {
+    let (row_x, row_y, row_w, _) = crafting_recipe_row_rect(screen_w, screen_h, index);
+    (row_x + row_w - 60.0, row_y + 5.0, 60.0, 20.0)
+}
+
+const HUD_HOTBAR_SLOTS: usize = 10;
+
+/// Screen rect (x, y, size) of a HUD hotbar slot, centered along the bottom edge
+pub(crate) fn hud_hotbar_slot_rect(index: usize, screen_w: u32, screen_h: u32) -> (f32, f32, f32) {
+    let slot_size = 24.0_f32;
+    let margin = 4.0_f32;
+    let total_w = HUD_HOTBAR_SLOTS as f32 * slot_size + (HUD_HOTBAR_SLOTS as f32 - 1.0) * margin;
+    let start_x = (screen_w as f32 - total_w) * 0.5;
+    (start_x + index as f32 * (slot_size + margin), screen_h as f32 - slot_size - 8.0, slot_size)
+}
+
+/// HUD hotbar slot (inventory slots 0..9) under a screen position
+pub(crate) fn hud_hotbar_slot_at(mx: f32, my: f32, screen_w: u32, screen_h: u32) -> Option<usize> {
+    (0..HUD_HOTBAR_SLOTS).find(|&i| {
+        let (x, y, size) = hud_hotbar_slot_rect(i, screen_w, screen_h);
+        mx >= x && mx <= x + size && my >= y && my <= y + size
+    })
+}
+
+/// Inventory panel bounds: full screen with small margins, or docked to the right half
+pub(crate) fn inventory_panel_rect(screen_w: u32, screen_h: u32, docked: bool) -> (f32, f32, f32, f32) {
+    let panel_margin = 8.0_f32;
+    let full_w = screen_w as f32 - panel_margin * 2.0;
+    let panel_w = if docked { (screen_w as f32 * 0.5).min(full_w) } else { full_w };
+    let panel_x = screen_w as f32 - panel_margin - panel_w;
+    (panel_x, panel_margin, panel_w, screen_h as f32 - panel_margin * 2.0)
+}
+
 #[turbo::serialize]
 pub struct HudState {
     pub tool: String,
@@ -565,6 +1198,53 @@ pub struct HudState {
     pub raft_pos: Option<String>,
     pub hotbar_items: Option<Vec<Option<(u32, u32)>>>,
     pub hotbar_active: Option<usize>,
+    pub quick_craft: Vec<(u32, bool)>, // (result color, craftable) per pinned recipe
+    pub carry_weight: f32,
+    pub world_seed: u32,
+    pub breath: Option<f32>, // Fraction of max breath left; Some only while diving
+    pub stamina: Option<(f32, bool)>, // (fraction of max stamina, exhausted); Some only while not full
+    pub wind: (f32, f32), // Top-down wind vector for the compass (length = strength)
+    pub facing: f32,      // Player heading in radians for the heading indicator
+    pub control_hints: Option<Vec<String>>, // None when hints are hidden
+    pub interaction_hint: Option<(String, (f32, f32))>, // Prompt and the player's screen position it floats above
+    pub collector_water: Option<f32>, // Rainwater stored; Some only while carrying a water collector
+    pub fish_fight: Option<(f32, f32)>, // (tension, progress) while a big fish is fighting the hook
+    pub temperature: f32, // Body warmth, 0..PLAYER_MAX_TEMPERATURE
+    pub day: u32,
+    pub survival_time: f32, // Seconds survived this run
+}
+
+/// Title screen values that change while it's open
+#[turbo::serialize]
+pub struct MainMenuState {
+    pub seed: u32,
+    pub loadout: String,
+    pub selected: usize, // Index into MAIN_MENU_BUTTONS highlighted for keyboard use
+}
+
+/// One options panel row: a caption and its (element id, label) buttons
+pub struct OptionsRow {
+    pub label: String,
+    pub buttons: Vec<(String, String)>,
+}
+
+/// Short-lived rising text for damage/heal feedback (screen space)
+#[turbo::serialize]
+pub struct FloatingText {
+    pub text: String,
+    pub x: f32,
+    pub y: f32,
+    pub color: u32,
+    pub age: f32,
+    pub lifetime: f32,
+}
+
+/// Transient corner message (achievements, toggles, milestones)
+#[turbo::serialize]
+pub struct Notification {
+    pub text: String,
+    pub color: u32,
+    pub remaining: f32, // seconds left on screen
 }
 
 #[turbo::serialize]
@@ -583,6 +1263,8 @@ pub enum UIMode {
     Inventory,
     Crafting,
     Paused,
+    Options,
+    MainMenu,
 }
 
 /// UI element types
@@ -652,3 +1334,26 @@ pub enum UIClickEvent {
         position: V2,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notifications_expire_after_their_duration() {
+        let mut ui = UIRenderer::new();
+        ui.push_notification("Short".to_string(), 1.0);
+        ui.push_toast("Long".to_string(), 2.0);
+        // A repeat toast refreshes the one showing rather than stacking a copy
+        ui.push_toast("Long".to_string(), 1.5);
+        assert_eq!(ui.notifications.len(), 2);
+
+        ui.update_notifications(0.6);
+        assert_eq!(ui.notifications.len(), 2);
+        ui.update_notifications(0.6);
+        let texts: Vec<&str> = ui.notifications.iter().map(|n| n.text.as_str()).collect();
+        assert_eq!(texts, vec!["Long"]);
+        ui.update_notifications(1.0);
+        assert!(ui.notifications.is_empty());
+    }
+}
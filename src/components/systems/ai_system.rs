@@ -1,5 +1,6 @@
 use super::*;
 use crate::math::Vec3 as V3;
+use crate::components::systems::spawn_system::ViewMode;
 
 
 /// Handles AI behavior for various game entities
@@ -7,6 +8,8 @@ use crate::math::Vec3 as V3;
 pub struct AISystem {
     behavior_trees: std::collections::HashMap<u32, BehaviorTree>,
     pathfinding_cache: std::collections::HashMap<(i32, i32, i32, i32), Vec<V3>>,
+    view_mode: ViewMode, // Plane AI moves in: x/y at the surface, x/z while diving
+    elapsed: f32,        // Drives wander headings
 }
 
 impl AISystem {
@@ -14,11 +17,24 @@ impl AISystem {
         Self {
             behavior_trees: std::collections::HashMap::new(),
             pathfinding_cache: std::collections::HashMap::new(),
+            view_mode: ViewMode::TopDown,
+            elapsed: 0.0,
         }
     }
     
-    /// Update AI for all entities
-    pub fn update(&mut self, entities: &mut [&mut dyn AIEntity], player_pos: &V3, delta_time: f32) {
+    /// Set the plane AI distances and movement are measured in
+    pub fn set_view_mode(&mut self, mode: ViewMode) {
+        self.view_mode = mode;
+    }
+    
+    /// Drop behavior trees for entities that no longer exist
+    pub fn retain_entities(&mut self, alive: &[u32]) {
+        self.behavior_trees.retain(|id, _| alive.contains(id));
+    }
+    
+    /// Update AI for all entities; `is_solid` reports blocking terrain cells for pathing
+    pub fn update(&mut self, entities: &mut [&mut dyn AIEntity], player_pos: &V3, delta_time: f32, is_solid: impl Fn(i32, i32) -> bool) {
+        self.elapsed += delta_time;
         for entity in entities {
             let entity_id = entity.get_id();
             
@@ -31,7 +47,8 @@ impl AISystem {
                 self.behavior_trees.insert(entity_id, behavior_tree);
             }
             
-            // TODO: Update behavior tree and execute actions
+            let action = self.behavior_trees[&entity_id].update(&**entity, player_pos, self.view_mode);
+            self.execute_action(&mut **entity, action, player_pos, delta_time, &is_solid);
         }
     }
     
@@ -77,7 +94,8 @@ impl AISystem {
     fn create_shark_behavior(&self) -> BehaviorTree {
         BehaviorTree::new(vec![
             BehaviorNode::Sequence(vec![
-                BehaviorNode::Condition("player_near".to_string()),
+                BehaviorNode::Condition("player_underwater".to_string()),
+                BehaviorNode::Condition("player_in_hunt_range".to_string()),
                 BehaviorNode::Action("aggressive_chase".to_string()),
             ]),
             BehaviorNode::Fallback(vec![
@@ -98,25 +116,40 @@ impl AISystem {
     }
     
     /// Execute AI action
-    fn execute_action(&self, entity: &mut dyn AIEntity, action: AIAction, player_pos: &V3, delta_time: f32) {
+    /// Sets the entity's own swim velocity; callers add currents on top
+    fn execute_action(&mut self, entity: &mut dyn AIEntity, action: AIAction, player_pos: &V3, delta_time: f32, is_solid: &dyn Fn(i32, i32) -> bool) {
         match action {
             AIAction::Flee => {
-                // Already handled in behavior tree
-            },
-            AIAction::Chase => {
-                // Already handled in behavior tree
-            },
-            AIAction::Wander => {
-                // Already handled in behavior tree
-            },
-            AIAction::Patrol => {
-                // Already handled in behavior tree
+                let away = plane_offset(self.view_mode, player_pos, &entity.get_position());
+                let distance = away.length();
+                let dir = if distance < 1e-3 { V3::new(1.0, 0.0, 0.0) } else { away.scale(1.0 / distance) };
+                entity.set_velocity(dir.scale(crate::constants::FISH_FLEE_SPEED));
             },
             AIAction::AggressiveChase => {
-                // Already handled in behavior tree
+                // Underwater, steer for the next waypoint around terrain; straight at the player otherwise
+                let position = entity.get_position();
+                let target = match self.view_mode {
+                    ViewMode::SideScroll => self.find_path(&position, player_pos, is_solid).first().copied().unwrap_or(*player_pos),
+                    ViewMode::TopDown => *player_pos,
+                };
+                let toward = plane_offset(self.view_mode, &position, &target);
+                let distance = toward.length();
+                let velocity = if distance < 1e-3 { V3::zero() } else { toward.scale(crate::constants::SHARK_CHASE_SPEED / distance) };
+                entity.set_velocity(velocity);
             },
-            AIAction::DeepPatrol => {
-                // Already handled in behavior tree
+            AIAction::Wander | AIAction::DeepPatrol => {
+                // Slowly turning heading, offset per entity so schools spread out
+                let angle = entity.get_id() as f32 * 2.4 + self.elapsed * 0.3;
+                let speed = crate::constants::AI_WANDER_SPEED;
+                let heading = match self.view_mode {
+                    ViewMode::TopDown => V3::new(angle.cos(), angle.sin(), 0.0),
+                    ViewMode::SideScroll => V3::new(angle.cos(), 0.0, angle.sin() * 0.3),
+                };
+                entity.set_velocity(heading.scale(speed));
+            },
+            AIAction::Chase | AIAction::Patrol => {
+                // Not implemented yet: hold position and let currents carry the entity
+                entity.set_velocity(V3::zero());
             },
             AIAction::Grow => {
                 // Coral growth logic
@@ -127,45 +160,116 @@ impl AISystem {
         }
     }
     
-    /// Find path between two points
-    pub fn find_path(&mut self, start: &V3, end: &V3) -> Vec<V3> {
-        let start_x = (start.x / 32.0) as i32;
-        let start_y = (start.y / 32.0) as i32;
-        let end_x = (end.x / 32.0) as i32;
-        let end_y = (end.y / 32.0) as i32;
-        let cache_key = (start_x, start_y, end_x, end_y);
+    /// Find a path between two points through the dive plane (x across, -z down), routed
+    /// around terrain cells for which `is_solid(cell_x, cell_y)` holds. Returns world-space
+    /// waypoints ending at `end`, or an empty path if `end` can't be reached.
+    pub fn find_path(&mut self, start: &V3, end: &V3, is_solid: impl Fn(i32, i32) -> bool) -> Vec<V3> {
+        let start_cell = path_cell(start);
+        let end_cell = path_cell(end);
+        let cache_key = (start_cell.0, start_cell.1, end_cell.0, end_cell.1);
         
         if let Some(cached_path) = self.pathfinding_cache.get(&cache_key) {
             return cached_path.clone();
         }
         
-        // Simple A* pathfinding (simplified for performance)
-        let path = self.simple_pathfinding(start, end);
+        let path = match astar(start_cell, end_cell, &is_solid) {
+            Some(cells) => {
+                let mut waypoints: Vec<V3> = cells.into_iter().skip(1).map(|cell| path_waypoint(cell, start.y)).collect();
+                if let Some(last) = waypoints.last_mut() {
+                    *last = *end;
+                }
+                waypoints
+            }
+            None => Vec::new(),
+        };
         
-        // Cache the result
+        // Cache the result; dropped wholesale once it grows too large
+        if self.pathfinding_cache.len() >= crate::constants::AI_PATH_CACHE_LIMIT {
+            self.pathfinding_cache.clear();
+        }
         self.pathfinding_cache.insert(cache_key, path.clone());
         
         path
     }
     
-    /// Simple pathfinding algorithm
-    fn simple_pathfinding(&self, start: &V3, end: &V3) -> Vec<V3> {
-        let mut path = Vec::new();
-        let mut current = start.clone();
-        
-        while current.distance_to(end) > 10.0 {
-            let direction = V3::new(
-                end.x - current.x,
-                end.y - current.y,
-                end.z - current.z,
-            ).normalize();
-            
-            current = current.add(direction.scale(10.0));
-            path.push(current.clone());
+    /// Forget cached paths, e.g. after terrain has been dug out
+    pub fn clear_path_cache(&mut self) {
+        self.pathfinding_cache.clear();
+    }
+}
+
+/// Terrain cell containing a world position in the dive plane
+fn path_cell(pos: &V3) -> (i32, i32) {
+    let size = crate::constants::PIXEL_SIZE;
+    ((pos.x / size).floor() as i32, (-pos.z / size).floor() as i32)
+}
+
+/// World-space center of a terrain cell, at world depth row `y`
+fn path_waypoint(cell: (i32, i32), y: f32) -> V3 {
+    let size = crate::constants::PIXEL_SIZE;
+    V3::new((cell.0 as f32 + 0.5) * size, y, -(cell.1 as f32 + 0.5) * size)
+}
+
+/// Grid A* with 8-way moves; diagonals may not cut past solid corners.
+/// Gives up after AI_PATH_MAX_NODES expansions so unreachable goals stay cheap.
+fn astar(start: (i32, i32), goal: (i32, i32), is_solid: &impl Fn(i32, i32) -> bool) -> Option<Vec<(i32, i32)>> {
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap};
+
+    const STRAIGHT: u32 = 10;
+    const DIAGONAL: u32 = 14;
+    let heuristic = |(x, y): (i32, i32)| {
+        let dx = x.abs_diff(goal.0);
+        let dy = y.abs_diff(goal.1);
+        STRAIGHT * dx.max(dy) + (DIAGONAL - STRAIGHT) * dx.min(dy)
+    };
+
+    if is_solid(goal.0, goal.1) {
+        return None;
+    }
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+    let mut best_cost: HashMap<(i32, i32), u32> = HashMap::new();
+    best_cost.insert(start, 0);
+    open.push(Reverse((heuristic(start), start)));
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, cell))) = open.pop() {
+        if cell == goal {
+            let mut path = vec![cell];
+            let mut current = cell;
+            while let Some(&previous) = came_from.get(&current) {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        expanded += 1;
+        if expanded > crate::constants::AI_PATH_MAX_NODES {
+            return None;
+        }
+        let cost = best_cost[&cell];
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let next = (cell.0 + dx, cell.1 + dy);
+                let diagonal = dx != 0 && dy != 0;
+                if is_solid(next.0, next.1) || (diagonal && (is_solid(cell.0 + dx, cell.1) || is_solid(cell.0, cell.1 + dy))) {
+                    continue;
+                }
+                let next_cost = cost + if diagonal { DIAGONAL } else { STRAIGHT };
+                if best_cost.get(&next).is_none_or(|&known| next_cost < known) {
+                    best_cost.insert(next, next_cost);
+                    came_from.insert(next, cell);
+                    open.push(Reverse((next_cost + heuristic(next), next)));
+                }
+            }
         }
-        
-        path
     }
+    None
 }
 
 /// Types of entities that can have AI
@@ -212,9 +316,66 @@ impl BehaviorTree {
         Self { nodes }
     }
     
-    pub fn update(&self, entity: &mut dyn AIEntity, player_pos: &V3, delta_time: f32) -> AIAction {
-        // TODO: Implement behavior tree logic
-        AIAction::Wander // Default action for now
+    /// Top-level nodes act as a fallback: the first one that yields an action wins
+    pub fn update(&self, entity: &dyn AIEntity, player_pos: &V3, view_mode: ViewMode) -> AIAction {
+        self.nodes.iter()
+            .find_map(|node| node.evaluate(entity, player_pos, view_mode))
+            .unwrap_or(AIAction::Wander)
+    }
+}
+
+impl BehaviorNode {
+    /// Sequence fails on the first false condition and yields its first action;
+    /// fallback yields the first child that succeeds.
+    fn evaluate(&self, entity: &dyn AIEntity, player_pos: &V3, view_mode: ViewMode) -> Option<AIAction> {
+        match self {
+            BehaviorNode::Sequence(children) => {
+                for child in children {
+                    match child {
+                        BehaviorNode::Condition(name) if !check_condition(name, entity, player_pos, view_mode) => return None,
+                        BehaviorNode::Condition(_) => {}
+                        _ => return child.evaluate(entity, player_pos, view_mode),
+                    }
+                }
+                None
+            }
+            BehaviorNode::Fallback(children) => children.iter().find_map(|child| child.evaluate(entity, player_pos, view_mode)),
+            BehaviorNode::Condition(_) => None,
+            BehaviorNode::Action(name) => AIAction::from_name(name),
+        }
+    }
+}
+
+impl AIAction {
+    fn from_name(name: &str) -> Option<AIAction> {
+        match name {
+            "flee" => Some(AIAction::Flee),
+            "chase" => Some(AIAction::Chase),
+            "wander" => Some(AIAction::Wander),
+            "patrol" => Some(AIAction::Patrol),
+            "aggressive_chase" => Some(AIAction::AggressiveChase),
+            "deep_patrol" => Some(AIAction::DeepPatrol),
+            "grow" => Some(AIAction::Grow),
+            _ => None,
+        }
+    }
+}
+
+fn check_condition(name: &str, entity: &dyn AIEntity, player_pos: &V3, view_mode: ViewMode) -> bool {
+    match name {
+        "player_near" => plane_offset(view_mode, player_pos, &entity.get_position()).length() < crate::constants::FISH_FLEE_RADIUS,
+        "player_underwater" => player_pos.z < 0.0,
+        "player_in_hunt_range" => plane_offset(view_mode, player_pos, &entity.get_position()).length() < crate::constants::SHARK_HUNT_RADIUS,
+        "can_grow" => entity.get_growth_rate().is_some(),
+        _ => true, // random_swim / random_patrol / random_deep_patrol always pass for now
+    }
+}
+
+/// Offset from `from` to `to` in the plane of the view: x/y top-down, x/z side-scroll
+fn plane_offset(view_mode: ViewMode, from: &V3, to: &V3) -> V3 {
+    match view_mode {
+        ViewMode::TopDown => V3::new(to.x - from.x, to.y - from.y, 0.0),
+        ViewMode::SideScroll => V3::new(to.x - from.x, 0.0, to.z - from.z),
     }
 }
 
@@ -226,3 +387,58 @@ pub enum BehaviorNode {
     Condition(String), // String identifier for condition
     Action(String),    // String identifier for action
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_path_routes_around_wall() {
+        // Wall at cell column 5 spanning rows -3..=3, between start and goal
+        let wall = |x: i32, y: i32| x == 5 && (-3..=3).contains(&y);
+        let start = path_waypoint((0, 0), 0.0);
+        let end = path_waypoint((10, 0), 0.0);
+
+        let path = AISystem::new().find_path(&start, &end, wall);
+
+        assert!(!path.is_empty());
+        assert_eq!(*path.last().unwrap(), end);
+        assert!(path.iter().all(|p| {
+            let (x, y) = path_cell(p);
+            !wall(x, y)
+        }));
+        // Had to detour past the end of the wall
+        assert!(path.iter().any(|p| path_cell(p).1.abs() > 3));
+    }
+
+    struct StubFish {
+        position: V3,
+        velocity: V3,
+    }
+
+    impl AIEntity for StubFish {
+        fn get_id(&self) -> u32 { 1 }
+        fn get_entity_type(&self) -> EntityType { EntityType::Fish }
+        fn get_position(&self) -> V3 { self.position }
+        fn set_position(&mut self, pos: V3) { self.position = pos; }
+        fn get_velocity(&self) -> V3 { self.velocity }
+        fn set_velocity(&mut self, vel: V3) { self.velocity = vel; }
+    }
+
+    #[test]
+    fn fish_next_to_the_player_swims_away() {
+        let player = V3::new(0.0, 0.0, -10.0);
+        // Offset in the plane each view mode moves in: x/y at the surface, x/z underwater
+        for (mode, fish_pos) in [(ViewMode::TopDown, V3::new(6.0, 4.0, -10.0)), (ViewMode::SideScroll, V3::new(6.0, 0.0, -6.0))] {
+            let mut ai = AISystem::new();
+            ai.set_view_mode(mode);
+            let mut fish = StubFish { position: fish_pos, velocity: V3::zero() };
+            ai.update(&mut [&mut fish as &mut dyn AIEntity], &player, 1.0 / 60.0, |_, _| false);
+
+            let away = plane_offset(mode, &player, &fish_pos);
+            assert!(fish.velocity.length() > 0.0, "{:?} fish didn't move", mode);
+            let v = fish.velocity;
+            assert!(v.x * away.x + v.y * away.y + v.z * away.z > 0.0, "{:?} fish swam toward the player: {:?}", mode, fish.velocity);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::math::Vec3 as V3;
 use crate::models::particle::Particle;
+use crate::components::entities::entity_factory::FishType;
+use crate::constants::*;
 use turbo::random;
 
 /// Handles spawning of various game entities
@@ -8,9 +10,12 @@ pub struct SpawnSystem {
     spawn_timers: std::collections::HashMap<SpawnType, u32>,
     spawn_rates: std::collections::HashMap<SpawnType, u32>,
     max_entities: std::collections::HashMap<SpawnType, usize>,
-    pending_spawns: Vec<(SpawnType, V3)>,
+    spawn_patterns: std::collections::HashMap<SpawnType, SpawnPattern>,
+    pending_spawns: Vec<(SpawnType, V3, Option<FishType>)>, // Fish spawns carry their chosen type
     wind: V3,
     current_view_mode: ViewMode,
+    recent_item_spawn_ys: Vec<f32>, // Last few floating item spawn rows, used to keep spacing
+    difficulty: f32, // 0..1; speeds up shark spawns and thins out floating items
 }
 
 #[derive(Copy, PartialEq)]
@@ -20,11 +25,44 @@ pub enum ViewMode {
     SideScroll,
 }
 
+/// Dive depth zones, banded like `Player::get_depth_name`
+#[derive(Copy, PartialEq)]
+#[turbo::serialize]
+pub enum DepthZone {
+    Shallow,
+    Deep,
+    Abyss,
+}
+
+impl DepthZone {
+    /// Zone for a depth in world units below the surface (positive = deeper)
+    pub fn from_depth(depth: f32) -> Self {
+        if depth <= FISH_SHALLOW_DEPTH {
+            DepthZone::Shallow
+        } else if depth <= FISH_DEEP_DEPTH {
+            DepthZone::Deep
+        } else {
+            DepthZone::Abyss
+        }
+    }
+}
+
+/// Where a spawn type appears relative to the player
+#[derive(Copy, PartialEq)]
+#[turbo::serialize]
+pub enum SpawnPattern {
+    LeftEdgeFlow,     // Off the left edge, drifting across the view
+    RingAroundPlayer, // Random angle at a type-specific distance
+    DepthBand,        // Spread horizontally within a type-specific depth range
+    ScreenEdge,       // Off either side of the view, underwater
+}
+
 #[derive(Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 #[turbo::serialize]
 pub enum SpawnType {
     FloatingItem,
     Fish,
+    Shark,
     Bubble,
     Particle,
     Coral,
@@ -36,6 +74,7 @@ impl SpawnSystem {
         let mut spawn_rates = std::collections::HashMap::new();
         spawn_rates.insert(SpawnType::FloatingItem, 600); // Every 10 seconds
         spawn_rates.insert(SpawnType::Fish, 180);          // Every 3 seconds
+        spawn_rates.insert(SpawnType::Shark, SHARK_SPAWN_RATE);
         spawn_rates.insert(SpawnType::Bubble, 60);         // Every second
         spawn_rates.insert(SpawnType::Particle, 10);       // Every 1/6 second
         spawn_rates.insert(SpawnType::Coral, 600);         // Every 10 seconds
@@ -49,13 +88,25 @@ impl SpawnSystem {
         max_entities.insert(SpawnType::Coral, 20);
         max_entities.insert(SpawnType::Treasure, 10);
         
+        let mut spawn_patterns = std::collections::HashMap::new();
+        spawn_patterns.insert(SpawnType::FloatingItem, SpawnPattern::LeftEdgeFlow);
+        spawn_patterns.insert(SpawnType::Fish, SpawnPattern::ScreenEdge);
+        spawn_patterns.insert(SpawnType::Shark, SpawnPattern::ScreenEdge);
+        spawn_patterns.insert(SpawnType::Bubble, SpawnPattern::RingAroundPlayer);
+        spawn_patterns.insert(SpawnType::Particle, SpawnPattern::RingAroundPlayer);
+        spawn_patterns.insert(SpawnType::Coral, SpawnPattern::DepthBand);
+        spawn_patterns.insert(SpawnType::Treasure, SpawnPattern::ScreenEdge);
+        
         Self {
             spawn_timers: std::collections::HashMap::new(),
             spawn_rates,
             max_entities,
+            spawn_patterns,
             pending_spawns: Vec::new(),
             wind: V3::zero(),
             current_view_mode: ViewMode::TopDown,
+            recent_item_spawn_ys: Vec::new(),
+            difficulty: 0.0,
         }
     }
     
@@ -65,9 +116,12 @@ impl SpawnSystem {
     /// Set the current view mode for spawning logic
     pub fn set_view_mode(&mut self, mode: ViewMode) { self.current_view_mode = mode; }
     
+    /// Set the run difficulty (0..1) applied on top of the configured spawn rates
+    pub fn set_difficulty(&mut self, difficulty: f32) { self.difficulty = difficulty; }
+    
     /// Update spawn timers and trigger spawns
     pub fn update(&mut self, player_pos: &V3, current_counts: &std::collections::HashMap<SpawnType, usize>) {
-        let spawn_types = [SpawnType::FloatingItem, SpawnType::Fish, SpawnType::Bubble, SpawnType::Coral, SpawnType::Treasure];
+        let spawn_types = [SpawnType::FloatingItem, SpawnType::Fish, SpawnType::Shark, SpawnType::Bubble, SpawnType::Coral, SpawnType::Treasure];
         
         for spawn_type in spawn_types {
             // Skip spawning based on view mode
@@ -78,8 +132,8 @@ impl SpawnSystem {
                         continue;
                     }
                 },
-                SpawnType::Fish => {
-                    // Only spawn fish in side-scroll mode
+                SpawnType::Fish | SpawnType::Shark | SpawnType::Treasure | SpawnType::Bubble => {
+                    // Only spawn fish, sharks, sunken treasure and bubbles in side-scroll mode
                     if self.current_view_mode != ViewMode::SideScroll {
                         continue;
                     }
@@ -87,124 +141,152 @@ impl SpawnSystem {
                 _ => {} // Other types spawn in both modes
             }
             
-            let rate = *self.spawn_rates.get(&spawn_type).unwrap_or(&300);
+            let rate = difficulty_spawn_rate(spawn_type, *self.spawn_rates.get(&spawn_type).unwrap_or(&300), self.difficulty);
             let max_count = *self.max_entities.get(&spawn_type).unwrap_or(&50);
             let current_count = *current_counts.get(&spawn_type).unwrap_or(&0);
             
-            // Ensure timer exists; initialize to rate so first update can spawn immediately
-            let init = match spawn_type { SpawnType::FloatingItem | SpawnType::Fish => rate, _ => 0 };
-            let timer = self.spawn_timers.entry(spawn_type).or_insert(init);
-            
-            let should_spawn = *timer >= rate && current_count < max_count;
-            if should_spawn { *timer = 0; } else { *timer += 1; }
-            
-            if should_spawn {
+            let timer = self.spawn_timers.entry(spawn_type).or_insert(0);
+            if tick_spawn_timer(timer, rate, current_count < max_count) {
                 self.trigger_spawn(&spawn_type, player_pos);
             }
         }
     }
     
-    /// Trigger a specific spawn type
+    /// Trigger a specific spawn type, placing it according to its configured pattern
     fn trigger_spawn(&mut self, spawn_type: &SpawnType, player_pos: &V3) {
+        let pattern = self.spawn_pattern(*spawn_type);
+        let pos = self.spawn_position(*spawn_type, pattern, player_pos);
         match spawn_type {
-            SpawnType::FloatingItem => self.spawn_floating_item(player_pos),
-            SpawnType::Fish => self.spawn_fish(player_pos),
-            SpawnType::Bubble => self.spawn_bubble(player_pos),
-            SpawnType::Coral => self.spawn_coral(player_pos),
-            SpawnType::Treasure => self.spawn_treasure(player_pos),
+            SpawnType::FloatingItem => self.pending_spawns.push((SpawnType::FloatingItem, pos, None)),
+            SpawnType::Fish => {
+                // Side-scroll positions carry depth in -z; depth bands carry it in y
+                let depth = if pos.z < 0.0 { -pos.z } else { pos.y.max(0.0) };
+                let fish_type = Self::fish_type_for_depth(depth);
+                self.pending_spawns.push((SpawnType::Fish, pos, Some(fish_type)));
+            }
+            // Dedicated shark spawns only hunt a player who is down in the abyss
+            SpawnType::Shark if DepthZone::from_depth(-player_pos.z) == DepthZone::Abyss => {
+                self.pending_spawns.push((SpawnType::Shark, pos, Some(FishType::Shark)));
+            }
+            SpawnType::Treasure => self.pending_spawns.push((SpawnType::Treasure, pos, None)),
+            // Bubbles that would start above the surface are already popped
+            SpawnType::Bubble if pos.z < 0.0 => self.pending_spawns.push((SpawnType::Bubble, pos, None)),
+            SpawnType::Coral => {
+                // TODO: enqueue coral entities when that system exists
+            }
             _ => {}
         }
     }
-    
-    /// Spawn a floating item near the player
-    fn spawn_floating_item(&mut self, player_pos: &V3) {
-        // Always spawn at left edge so it flows left -> right across the view
+
+    /// Pick a world position for a spawn using the given pattern
+    fn spawn_position(&mut self, spawn_type: SpawnType, pattern: SpawnPattern, player_pos: &V3) -> V3 {
         let (screen_w, screen_h) = turbo::resolution();
         let half_w = screen_w as f32 * 0.5;
         let half_h = screen_h as f32 * 0.5;
         let margin = 60.0; // Spawn further off-screen
-        let x = player_pos.x - half_w - margin;
-        // Much more Y variation - spread across a wider area
-        let y = player_pos.y + (-half_h * 0.6 + random::f32() * half_h * 1.2);
-        let final_pos = V3::new(x, y, 0.0);
-        self.pending_spawns.push((SpawnType::FloatingItem, final_pos));
+        match pattern {
+            SpawnPattern::LeftEdgeFlow => {
+                // Spawn at left edge so it flows left -> right across the view,
+                // spread across a wide band and kept apart from recent spawns.
+                // The band shifts upwind so crosswind drift still carries items past the player.
+                let x = player_pos.x - half_w - margin;
+                let flow_x = (ITEM_BASE_FLOW + self.wind.x * ITEM_WIND_DRIFT).max(1.0);
+                let upwind = -(self.wind.y * ITEM_WIND_DRIFT / flow_x) * (half_w + margin);
+                let y = self.pick_spaced_item_y(player_pos.y - half_h * 0.6, half_h * 1.2) + upwind;
+                V3::new(x, y, 0.0)
+            }
+            SpawnPattern::ScreenEdge => {
+                // Either side of the view at the surface row, underwater via negative z
+                let left_side = random::f32() < 0.5;
+                let x = if left_side { player_pos.x - half_w - margin } else { player_pos.x + half_w + margin };
+                let (min_depth, max_depth) = dive_depth_range(spawn_type, -player_pos.z);
+                let z = -(min_depth + random::f32() * (max_depth - min_depth));
+                V3::new(x, player_pos.y, z)
+            }
+            SpawnPattern::RingAroundPlayer => {
+                // Ring in the plane of the current view: x/y top-down, x/z while diving
+                let (min_r, max_r) = Self::ring_radius(spawn_type);
+                let angle = random::f32() * std::f32::consts::TAU;
+                let distance = min_r + random::f32() * (max_r - min_r);
+                match self.current_view_mode {
+                    ViewMode::TopDown => V3::new(
+                        player_pos.x + angle.cos() * distance,
+                        player_pos.y + angle.sin() * distance,
+                        0.0
+                    ),
+                    ViewMode::SideScroll => V3::new(
+                        player_pos.x + angle.cos() * distance,
+                        player_pos.y,
+                        player_pos.z + angle.sin() * distance
+                    ),
+                }
+            }
+            SpawnPattern::DepthBand => {
+                // Horizontal spread from the ring, vertical position from the type's depth band
+                let (min_r, max_r) = Self::ring_radius(spawn_type);
+                let angle = random::f32() * std::f32::consts::TAU;
+                let distance = min_r + random::f32() * (max_r - min_r);
+                let (min_depth, max_depth) = Self::depth_band(spawn_type);
+                let y = min_depth + random::f32() * (max_depth - min_depth);
+                V3::new(player_pos.x + angle.cos() * distance, y, 0.0)
+            }
+        }
     }
-    
-    /// Spawn a fish near the player
-    fn spawn_fish(&mut self, player_pos: &V3) {
-        // Spawn underwater using new world pos: keep y (surface), set z to negative depth
-        let (screen_w, _screen_h) = turbo::resolution();
-        let half_w = screen_w as f32 * 0.5;
-        let margin = 60.0;
-        let left_side = random::f32() < 0.5;
-        let x = if left_side { player_pos.x - half_w - margin } else { player_pos.x + half_w + margin };
-        let y = player_pos.y;
-        let z = -(20.0 + random::f32() * 120.0);
-        let final_pos = V3::new(x, y, z);
-        self.pending_spawns.push((SpawnType::Fish, final_pos));
+
+    /// Distance range from the player used by ring-based patterns
+    fn ring_radius(spawn_type: SpawnType) -> (f32, f32) {
+        match spawn_type {
+            SpawnType::Bubble | SpawnType::Particle => (0.0, 14.0),
+            SpawnType::Treasure => (200.0, 600.0),
+            _ => (150.0, 450.0),
+        }
     }
-    
-    /// Spawn a bubble particle
-    fn spawn_bubble(&mut self, player_pos: &V3) {
-        let offset = V3::new(
-            (random::f32() - 0.5) * 20.0,
-            (random::f32() - 0.5) * 20.0,
-            0.0
-        );
-        let _spawn_pos = player_pos.add(offset);
-        // TODO: enqueue or create bubble entity when bubble system exists
+
+    /// Depth range (world y) used by the depth band pattern
+    fn depth_band(spawn_type: SpawnType) -> (f32, f32) {
+        match spawn_type {
+            SpawnType::Fish => (FISH_SHALLOW_DEPTH * 0.4, FISH_DEEP_DEPTH * 1.4),
+            _ => (80.0, 250.0),                    // Deep underwater (coral)
+        }
     }
     
-    /// Spawn coral formation
-    fn spawn_coral(&mut self, player_pos: &V3) {
-        let angle = random::f32() * 6.28318;
-        let distance = 150.0 + random::f32() * 300.0;
-        let spawn_pos = V3::new(
-            player_pos.x + angle.cos() * distance,
-            player_pos.y + angle.sin() * distance,
-            0.0
-        );
-        
-        // Ensure coral spawns deep underwater
-        let _final_pos = V3::new(spawn_pos.x, (50.0 + random::f32() * 200.0).max(80.0), 0.0);
-        // TODO: enqueue coral when system exists
+    /// Pick a y in [min_y, min_y + span] at least ITEM_SPAWN_MIN_SPACING from recent spawns.
+    /// Spacing is best effort: a narrow band can't fit every remembered row's gap, so when no
+    /// candidate clears them all the best-spaced one is used.
+    fn pick_spaced_item_y(&mut self, min_y: f32, span: f32) -> f32 {
+        let candidates = (0..ITEM_SPAWN_ATTEMPTS).map(|_| min_y + random::f32() * span);
+        let y = pick_spaced_y(&self.recent_item_spawn_ys, candidates).unwrap_or(min_y);
+        if self.recent_item_spawn_ys.len() >= ITEM_SPAWN_MEMORY {
+            self.recent_item_spawn_ys.remove(0);
+        }
+        self.recent_item_spawn_ys.push(y);
+        y
     }
     
-    /// Spawn treasure
-    fn spawn_treasure(&mut self, player_pos: &V3) {
-        let angle = random::f32() * 6.28318;
-        let distance = 200.0 + random::f32() * 400.0;
-        let spawn_pos = V3::new(
-            player_pos.x + angle.cos() * distance,
-            player_pos.y + angle.sin() * distance,
-            0.0
-        );
-        
-        // Ensure treasure spawns very deep underwater
-        let _final_pos = V3::new(spawn_pos.x, (100.0 + random::f32() * 300.0).max(150.0), 0.0);
-        // TODO: enqueue treasure when system exists
+    /// Pick a fish type from the spawn table for a given depth (positive = deeper)
+    fn fish_type_for_depth(depth: f32) -> FishType {
+        fish_type_for_roll(DepthZone::from_depth(depth), random::f32())
     }
-
-    /// Drain pending spawn requests
-    pub fn drain_pending(&mut self) -> Vec<(SpawnType, V3)> {
-        let mut out = Vec::new();
-        std::mem::swap(&mut out, &mut self.pending_spawns);
-        out
+    
+    /// Drain up to MAX_SPAWNS_PER_FRAME pending spawn requests, oldest first;
+    /// the rest stay queued for later frames
+    pub fn drain_pending(&mut self) -> Vec<(SpawnType, V3, Option<FishType>)> {
+        let count = MAX_SPAWNS_PER_FRAME.min(self.pending_spawns.len());
+        self.pending_spawns.drain(..count).collect()
     }
     
     /// Spawn impact particles at a specific location
+    /// Evenly spaced ring of impact particles around `pos` in the side-view (x/z) plane.
+    /// Velocities are unit-scale with a slight upward lift; callers scale by impact speed.
     pub fn spawn_impact_particles(&self, pos: &V3, count: usize) -> Vec<Particle> {
-        let mut particles = Vec::new();
-        
-        for _ in 0..count {
-            let angle = random::f32() * 6.28318;
-            let speed = 0.5 + random::f32() * 2.0;
-            let velocity = V3::new(angle.cos() * speed, angle.sin() * speed - 1.0, 0.0);
-            
-            particles.push(Particle::new(V3::new(pos.x, pos.y, 0.0), velocity));
-        }
-        
-        particles
+        (0..count)
+            .map(|i| {
+                let angle = (i as f32 + random::f32() * 0.5) / count as f32 * std::f32::consts::TAU;
+                let speed = 0.5 + random::f32() * 0.5;
+                let velocity = V3::new(angle.cos() * speed, 0.0, angle.sin() * speed + SPLASH_LIFT);
+                Particle::new(*pos, velocity)
+            })
+            .collect()
     }
     
     /// Set spawn rate for a specific type
@@ -212,8 +294,161 @@ impl SpawnSystem {
         self.spawn_rates.insert(spawn_type, rate);
     }
     
+    /// Spawn position pattern for a type (ring around the player if unset)
+    pub fn spawn_pattern(&self, spawn_type: SpawnType) -> SpawnPattern {
+        *self.spawn_patterns.get(&spawn_type).unwrap_or(&SpawnPattern::RingAroundPlayer)
+    }
+    
     /// Set maximum entities for a specific type
     pub fn set_max_entities(&mut self, spawn_type: SpawnType, max: usize) {
         self.max_entities.insert(spawn_type, max);
     }
 }
+
+/// Advance a spawn timer by one frame. Fires (and resets) once `rate` frames have passed
+/// and there is room; a capped type keeps counting so it spawns as soon as room frees up.
+/// SPAWN_RATE_NEVER never fires.
+fn tick_spawn_timer(timer: &mut u32, rate: u32, has_room: bool) -> bool {
+    if rate == SPAWN_RATE_NEVER {
+        *timer = 0;
+        return false;
+    }
+    *timer = timer.saturating_add(1);
+    if *timer >= rate && has_room {
+        *timer = 0;
+        return true;
+    }
+    false
+}
+
+/// Spawn interval (frames) adjusted for difficulty: sharks come more often, floating items less
+fn difficulty_spawn_rate(spawn_type: SpawnType, rate: u32, difficulty: f32) -> u32 {
+    let scale = match spawn_type {
+        SpawnType::Shark => 1.0 / (1.0 + difficulty * DIFFICULTY_SHARK_RATE_BOOST),
+        SpawnType::FloatingItem => 1.0 + difficulty * DIFFICULTY_ITEM_SCARCITY,
+        _ => 1.0,
+    };
+    if rate == SPAWN_RATE_NEVER || scale == 1.0 {
+        return rate;
+    }
+    ((rate as f32 * scale).round() as u32).max(1)
+}
+
+/// First candidate at least ITEM_SPAWN_MIN_SPACING from every recent row, else the candidate
+/// farthest from its nearest recent row. None only when there are no candidates.
+fn pick_spaced_y(recent: &[f32], candidates: impl IntoIterator<Item = f32>) -> Option<f32> {
+    let mut best: Option<(f32, f32)> = None;
+    for candidate in candidates {
+        let gap = recent.iter().map(|y| (y - candidate).abs()).fold(f32::MAX, f32::min);
+        if gap >= ITEM_SPAWN_MIN_SPACING {
+            return Some(candidate);
+        }
+        if best.is_none_or(|(_, best_gap)| gap > best_gap) {
+            best = Some((candidate, gap));
+        }
+    }
+    best.map(|(y, _)| y)
+}
+
+/// Fish spawn table for a zone, given a roll in [0, 1)
+fn fish_type_for_roll(zone: DepthZone, roll: f32) -> FishType {
+    match zone {
+        DepthZone::Shallow => if roll < 0.7 { FishType::SmallFish } else { FishType::TropicalFish },
+        DepthZone::Deep => if roll < 0.8 { FishType::DeepSeaFish } else { FishType::TropicalFish },
+        DepthZone::Abyss => if roll < SHARK_ABYSS_CHANCE { FishType::Shark } else { FishType::DeepSeaFish },
+    }
+}
+
+/// Depth range (positive = deeper) for side-scroll spawns: a window around the player's
+/// depth, clamped to the zones the type lives in. Treasure lies anywhere below the shallows.
+fn dive_depth_range(spawn_type: SpawnType, player_depth: f32) -> (f32, f32) {
+    let (shallowest, deepest) = match spawn_type {
+        SpawnType::Treasure => return (FISH_SHALLOW_DEPTH, FISH_ABYSS_DEPTH),
+        SpawnType::Shark => (FISH_DEEP_DEPTH, FISH_ABYSS_DEPTH),
+        _ => (FISH_MIN_SPAWN_DEPTH, FISH_ABYSS_DEPTH),
+    };
+    (
+        (player_depth - DIVE_SPAWN_DEPTH_SPREAD).clamp(shallowest, deepest),
+        (player_depth + DIVE_SPAWN_DEPTH_SPREAD).clamp(shallowest, deepest),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_timer_fires_every_rate_frames() {
+        let mut timer = 0;
+        let fired: Vec<usize> = (1..=9).filter(|_| tick_spawn_timer(&mut timer, 3, true)).collect();
+        assert_eq!(fired, vec![3, 6, 9]);
+
+        // Capped: keeps counting, then fires on the first frame with room
+        let mut timer = 0;
+        let fired: Vec<bool> = [false, false, false, false, true, true].iter().map(|&room| tick_spawn_timer(&mut timer, 3, room)).collect();
+        assert_eq!(fired, vec![false, false, false, false, true, false]);
+
+        let mut timer = 0;
+        assert!((0..10).all(|_| !tick_spawn_timer(&mut timer, SPAWN_RATE_NEVER, true)));
+    }
+
+    #[test]
+    fn fish_populations_follow_depth_zones() {
+        assert!(DepthZone::from_depth(-SHALLOW_DEPTH as f32) == DepthZone::Shallow);
+        assert!(DepthZone::from_depth(-DEEP_DEPTH as f32) == DepthZone::Deep);
+        assert!(DepthZone::from_depth(-DEEP_DEPTH as f32 + 1.0) == DepthZone::Abyss);
+
+        assert!(fish_type_for_roll(DepthZone::Shallow, 0.0) == FishType::SmallFish);
+        assert!(fish_type_for_roll(DepthZone::Deep, 0.0) == FishType::DeepSeaFish);
+        assert!(fish_type_for_roll(DepthZone::Abyss, 0.0) == FishType::Shark);
+        assert!(fish_type_for_roll(DepthZone::Abyss, SHARK_ABYSS_CHANCE) == FishType::DeepSeaFish);
+        let shallow_sharks = (0..10).any(|i| fish_type_for_roll(DepthZone::Shallow, i as f32 / 10.0) == FishType::Shark);
+        assert!(!shallow_sharks);
+
+        // Treasure never spawns in the shallows, even when the player is at the surface
+        let (min_depth, _) = dive_depth_range(SpawnType::Treasure, 0.0);
+        assert!(DepthZone::from_depth(min_depth + 0.01) != DepthZone::Shallow);
+        let (min_depth, max_depth) = dive_depth_range(SpawnType::Fish, 100.0);
+        assert_eq!((min_depth, max_depth), (100.0 - DIVE_SPAWN_DEPTH_SPREAD, 100.0 + DIVE_SPAWN_DEPTH_SPREAD));
+    }
+
+    #[test]
+    fn spawn_backlog_drains_a_few_per_step_in_order() {
+        let mut spawner = SpawnSystem::new();
+        for i in 0..100 {
+            spawner.pending_spawns.push((SpawnType::FloatingItem, V3::new(i as f32, 0.0, 0.0), None));
+        }
+        let mut released = Vec::new();
+        while !spawner.pending_spawns.is_empty() {
+            let batch = spawner.drain_pending();
+            assert!(!batch.is_empty() && batch.len() <= MAX_SPAWNS_PER_FRAME, "released {} in one step", batch.len());
+            released.extend(batch.into_iter().map(|(_, pos, _)| pos.x as i32));
+        }
+        assert_eq!(released, (0..100).collect::<Vec<_>>());
+        assert!(spawner.drain_pending().is_empty());
+    }
+
+    #[test]
+    fn consecutive_item_spawns_keep_their_spacing() {
+        // Deterministic stand-in for random::f32 over a band wide enough to fit the spacing
+        let mut seed = 12345u32;
+        let mut roll = move || {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (seed >> 8) as f32 / (1u32 << 24) as f32
+        };
+        let mut recent: Vec<f32> = Vec::new();
+        for _ in 0..50 {
+            let candidates: Vec<f32> = (0..ITEM_SPAWN_ATTEMPTS).map(|_| roll() * 400.0).collect();
+            let y = pick_spaced_y(&recent, candidates).unwrap();
+            assert!(recent.iter().all(|r| (r - y).abs() >= ITEM_SPAWN_MIN_SPACING), "{} too close to {:?}", y, recent);
+            if recent.len() >= ITEM_SPAWN_MEMORY {
+                recent.remove(0);
+            }
+            recent.push(y);
+        }
+
+        // Crowded band: no candidate fits, so the best-spaced one is taken
+        assert_eq!(pick_spaced_y(&[10.0], [12.0, 20.0, 8.0]), Some(20.0));
+        assert_eq!(pick_spaced_y(&[10.0], []), None);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::math::Vec3 as V3;
-use crate::models::terrain::TerrainChunk;
+use crate::models::terrain::{TerrainCell, TerrainChunk};
 use crate::constants::*;
+use crate::models::ocean::FloatingItemType;
 use std::collections::HashMap;
 
 /// Handles world generation, chunk management, and terrain updates
@@ -28,10 +29,10 @@ impl WorldSystem {
         self.cleanup_distant_chunks(player_pos);
     }
     
-    /// Generate chunks around player position
+    /// Generate chunks around player position. Terrain is laid out in cells of
+    /// PIXEL_SIZE: x runs along world x, y runs down with depth (-z).
     fn generate_chunks_around_player(&mut self, player_pos: &V3) {
-        let chunk_x = (player_pos.x / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
-        let chunk_y = (player_pos.y / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
+        let (chunk_x, chunk_y) = self.player_chunk(player_pos);
         
         // Generate chunks in render distance
         for dy in -self.render_distance..=self.render_distance {
@@ -46,10 +47,26 @@ impl WorldSystem {
             }
         }
     }
+
+    /// Chunk containing the player, in the same cell space as the terrain
+    fn player_chunk(&self, player_pos: &V3) -> (i32, i32) {
+        let cell_x = (player_pos.x / PIXEL_SIZE).floor() as i32;
+        let cell_y = (-player_pos.z / PIXEL_SIZE).floor() as i32;
+        let size = self.chunk_size as i32;
+        (cell_x.div_euclid(size), cell_y.div_euclid(size))
+    }
+
+    /// Chunk key and cell index for a world cell coordinate
+    fn cell_location(&self, world_x: i32, world_y: i32) -> ((i32, i32), usize) {
+        let size = self.chunk_size as i32;
+        let local_x = world_x.rem_euclid(size) as usize;
+        let local_y = world_y.rem_euclid(size) as usize;
+        ((world_x.div_euclid(size), world_y.div_euclid(size)), local_y * self.chunk_size + local_x)
+    }
     
     /// Generate a new chunk at specified coordinates
     fn generate_chunk(&self, x: i32, y: i32) -> TerrainChunk {
-        let mut blocks = Vec::new();
+        let mut chunk = TerrainChunk::new(x, y);
         
         for row in 0..self.chunk_size {
             for col in 0..self.chunk_size {
@@ -57,12 +74,11 @@ impl WorldSystem {
                 let world_y = y * self.chunk_size as i32 + row as i32;
                 
                 let block_type = self.generate_block_type(world_x, world_y);
-                let block = self.create_block(block_type);
-                blocks.push(block);
+                chunk.cells[row * self.chunk_size + col] = TerrainCell::from_block(block_type);
             }
         }
         
-        TerrainChunk::new(x, y)
+        chunk
     }
     
     /// Generate block type based on world coordinates
@@ -120,11 +136,26 @@ impl WorldSystem {
         crate::models::terrain::Block::new(block_type, durability)
     }
     
+    /// Items granted when a block is broken. Treasure chests roll `TreasureLoot::CHEST`
+    /// on top of guaranteed treasure; other blocks drop nothing yet.
+    pub fn block_drops(&self, block_type: &crate::models::terrain::BlockType) -> Vec<(FloatingItemType, u32)> {
+        match block_type {
+            crate::models::terrain::BlockType::TreasureChest => {
+                let mut loot = vec![(FloatingItemType::Treasure, 1)];
+                loot.extend(crate::models::loot::TreasureLoot::CHEST.roll());
+                loot
+            }
+            crate::models::terrain::BlockType::IronDeposit => vec![(FloatingItemType::Metal, 2)],
+            crate::models::terrain::BlockType::Coral => vec![(FloatingItemType::Coral, 1)],
+            _ => Vec::new(),
+        }
+    }
+
     /// Clean up chunks that are too far from player
     fn cleanup_distant_chunks(&mut self, player_pos: &V3) {
-        let max_distance = (self.render_distance + 2) as f32 * self.chunk_size as f32 * PIXEL_SIZE;
-        let player_chunk_x = (player_pos.x / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
-        let player_chunk_y = (player_pos.y / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
+        // Measured in chunks so the keep radius tracks render_distance
+        let max_distance = (self.render_distance + 2) as f32;
+        let (player_chunk_x, player_chunk_y) = self.player_chunk(player_pos);
         
         self.chunks.retain(|&(cx, cy), _| {
             let chunk_distance = ((cx - player_chunk_x).pow(2) + (cy - player_chunk_y).pow(2)) as f32;
@@ -142,63 +173,71 @@ impl WorldSystem {
         self.chunks.values().collect()
     }
     
-    /// Modify block at world coordinates
+    /// Modify block at world cell coordinates
     pub fn modify_block(&mut self, world_x: i32, world_y: i32, new_type: crate::models::terrain::BlockType) -> bool {
-        let chunk_x = (world_x as f32 / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
-        let chunk_y = (world_y as f32 / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
-        
-        if let Some(chunk) = self.chunks.get_mut(&(chunk_x, chunk_y)) {
-            let local_x = (world_x - chunk_x * self.chunk_size as i32) as usize;
-            let local_y = (world_y - chunk_y * self.chunk_size as i32) as usize;
-            
-            if local_x < self.chunk_size && local_y < self.chunk_size {
-                let index = local_y * self.chunk_size + local_x;
-                if index < chunk.cells.len() {
-                    // Convert BlockType to TerrainMaterial (simplified mapping)
-                    let material = match new_type {
-                        crate::models::terrain::BlockType::Sand => crate::models::terrain::TerrainMaterial::Sand,
-                        crate::models::terrain::BlockType::Rock => crate::models::terrain::TerrainMaterial::Stone,
-                        crate::models::terrain::BlockType::Coral => crate::models::terrain::TerrainMaterial::Stone, // Approximate
-                        crate::models::terrain::BlockType::Kelp => crate::models::terrain::TerrainMaterial::Leaves, // Approximate
-                        crate::models::terrain::BlockType::TreasureChest => crate::models::terrain::TerrainMaterial::Stone,
-                        crate::models::terrain::BlockType::IronDeposit => crate::models::terrain::TerrainMaterial::Iron,
-                        crate::models::terrain::BlockType::PearlBed => crate::models::terrain::TerrainMaterial::Stone,
-                        _ => crate::models::terrain::TerrainMaterial::Water,
-                    };
-                    chunk.cells[index] = crate::models::terrain::TerrainCell::new(material);
-                    return true;
-                }
+        let (key, index) = self.cell_location(world_x, world_y);
+        match self.chunks.get_mut(&key).and_then(|chunk| chunk.cells.get_mut(index)) {
+            Some(cell) => {
+                *cell = TerrainCell::from_block(new_type);
+                true
             }
+            None => false,
         }
-        
-        false
     }
     
-    /// Get block at world coordinates
-    pub fn get_block(&self, world_x: i32, world_y: i32) -> Option<&crate::models::terrain::Block> {
-        let chunk_x = (world_x as f32 / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
-        let chunk_y = (world_y as f32 / (self.chunk_size as f32 * PIXEL_SIZE)).floor() as i32;
-        
-        if let Some(chunk) = self.chunks.get(&(chunk_x, chunk_y)) {
-            let local_x = (world_x - chunk_x * self.chunk_size as i32) as usize;
-            let local_y = (world_y - chunk_y * self.chunk_size as i32) as usize;
-            
-            if local_x < self.chunk_size && local_y < self.chunk_size {
-                let index = local_y * self.chunk_size + local_x;
-                if index < chunk.cells.len() {
-                    // For now, return None since we're using TerrainCell instead of Block
-                    // This method needs to be updated to work with the new terrain system
-                    return None;
+    /// Get block at world cell coordinates; None if its chunk isn't loaded
+    pub fn get_block(&self, world_x: i32, world_y: i32) -> Option<crate::models::terrain::Block> {
+        let (key, index) = self.cell_location(world_x, world_y);
+        let cell = self.chunks.get(&key)?.cells.get(index)?;
+        Some(self.create_block(cell.block_type))
+    }
+
+    /// Whether the cell at world cell coordinates blocks movement; unloaded cells are open water
+    pub fn is_solid(&self, world_x: i32, world_y: i32) -> bool {
+        self.get_block(world_x, world_y).is_some_and(|block| block.block_type.is_solid())
+    }
+
+    /// Break a mineable block (iron, coral, treasure), leaving water behind.
+    /// Returns the block's primary drop, or None if nothing there can be mined.
+    pub fn mine_block(&mut self, world_x: i32, world_y: i32) -> Option<FloatingItemType> {
+        let drop = match self.get_block(world_x, world_y)?.block_type {
+            crate::models::terrain::BlockType::IronDeposit => FloatingItemType::Metal,
+            crate::models::terrain::BlockType::Coral => FloatingItemType::Coral,
+            crate::models::terrain::BlockType::TreasureChest => FloatingItemType::Treasure,
+            _ => return None,
+        };
+        self.modify_block(world_x, world_y, crate::models::terrain::BlockType::Water);
+        Some(drop)
+    }
+    
+    /// Closest mineable block to a diver within `reach` world units, searched in side-view
+    /// cells (x across, -z down)
+    pub fn nearest_mineable_block(&self, pos: &V3, reach: f32) -> Option<crate::models::terrain::BlockType> {
+        let cell_x = (pos.x / PIXEL_SIZE).floor() as i32;
+        let cell_y = (-pos.z / PIXEL_SIZE).floor() as i32;
+        let cell_reach = (reach / PIXEL_SIZE).ceil() as i32;
+        let mut nearest: Option<(i32, crate::models::terrain::BlockType)> = None;
+        for dy in -cell_reach..=cell_reach {
+            for dx in -cell_reach..=cell_reach {
+                let dist_sq = dx * dx + dy * dy;
+                if dist_sq > cell_reach * cell_reach || nearest.is_some_and(|(best, _)| best <= dist_sq) {
+                    continue;
+                }
+                if let Some(block) = self.get_block(cell_x + dx, cell_y + dy).filter(|b| b.block_type.is_mineable()) {
+                    nearest = Some((dist_sq, block.block_type));
                 }
             }
         }
-        
-        None
+        nearest.map(|(_, block_type)| block_type)
     }
     
-    /// Set render distance
+    /// Set render distance in chunks, clamped to MIN/MAX_RENDER_DISTANCE
     pub fn set_render_distance(&mut self, distance: i32) {
-        self.render_distance = distance;
+        self.render_distance = distance.clamp(MIN_RENDER_DISTANCE, MAX_RENDER_DISTANCE);
+    }
+
+    pub fn render_distance(&self) -> i32 {
+        self.render_distance
     }
     
     /// Get world seed
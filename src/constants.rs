@@ -3,6 +3,13 @@ pub const GRAVITY: f32 = 0.5;
 pub const FRICTION: f32 = 0.98;
 pub const BOUNCE_DAMPING: f32 = 0.7;
 
+// Buoyancy: submerged bodies are pulled toward the surface (z = 0) in proportion to depth
+pub const ITEM_BUOYANCY: f32 = 4.0;          // Upward accel per unit of depth (1/s^2)
+pub const ITEM_BUOYANCY_DAMPING: f32 = 4.0;  // Vertical velocity damping (1/s); critical so items don't overshoot
+pub const DIVER_BUOYANCY: f32 = 0.15;        // Weaker pull on an idle diver so they drift up slowly
+pub const DIVER_BUOYANCY_DAMPING: f32 = 1.5;
+pub const BUOYANCY_SURFACE_SNAP: f32 = 0.5;  // Bodies this close below the surface settle onto it
+
 // Gameplay constants
 pub const PLAYER_RADIUS: f32 = 10.0;
 pub const PLAYER_SPEED: f32 = 3.5;
@@ -10,22 +17,110 @@ pub const BULLET_RADIUS: f32 = 3.0;
 pub const BULLET_SPEED: f32 = 8.0;
 pub const SHOOT_INTERVAL_TICKS: u32 = 20;
 pub const PARTICLE_LIFETIME_TICKS: u32 = 30;
+pub const ACTION_COOLDOWN_SECONDS: f32 = 0.25; // 15 frames at 60 FPS
+pub const HOOK_MAX_LENGTH: f32 = 100.0;
+pub const FISHING_ROD_MAX_LENGTH: f32 = 140.0; // Longer cast reaches deeper water
+pub const FISHING_ROD_CATCH_BONUS: f32 = 1.5;  // Multiplier on the depth-based hook catch chance
+pub const SPEAR_CATCH_BONUS: f32 = 1.2;
+pub const SPEAR_COOLDOWN: f32 = 0.2;
+pub const SPEAR_THROW_SPEED: f32 = 180.0;      // Thrown spears fly faster than a cast hook
+pub const SPEAR_THROW_RANGE: f32 = 160.0;      // Then drop where they stop as a collectible spear
+pub const SPEAR_DAMAGE: f32 = 30.0;
+pub const NET_RADIUS: f32 = 40.0;              // A net sweep collects every floating item this close
+pub const NET_COOLDOWN: f32 = 1.5;             // Seconds between net sweeps
+pub const RAFT_TILE_SIZE: f32 = 16.0; // World units per raft tile edge
+pub const HOOK_ITEM_BASE_RADIUS: f32 = 9.0;  // Hook reach to floating items before size is added
+pub const HOOK_FISH_BASE_RADIUS: f32 = 8.0;  // Hook reach to fish before size is added
+pub const HOOK_RADIUS_SIZE_SCALE: f32 = 0.5; // Extra reach per unit of target render size
+pub const HOOK_RETRACT_MULTIPLIER: f32 = 1.5;       // Base return speed relative to launch speed
+pub const HOOK_EMPTY_RETRACT_MULTIPLIER: f32 = 3.5; // Return speed for empty hooks with fast retract on
+pub const HOOK_LOAD_SLOWDOWN: f32 = 0.15;           // Return speed lost per attached item
+pub const HOOK_MIN_RETRACT_MULTIPLIER: f32 = 0.75;  // Floor for heavily loaded reels
+pub const HOOK_REEL_MULTIPLIER: f32 = 2.0;          // Return speed boost while the reel key is held
+pub const MAX_HOOKS: u32 = 2;                        // Hooks out at once with every Twin Hook Kit installed
+pub const HOOK_TERRAIN_STEP: f32 = 1.0;             // Distance per terrain check along an outgoing hook's path
+pub const HOOK_IMPACT_PARTICLES: usize = 6;         // Sediment puff where a hook strikes the seafloor
+pub const SPLASH_PARTICLES_PER_SPEED: f32 = 0.05;   // Splash particles per unit/s of hook speed at the surface
+pub const SPLASH_MIN_PARTICLES: usize = 4;
+pub const SPLASH_MAX_PARTICLES: usize = 16;
+pub const SPLASH_SPEED_SCALE: f32 = 0.3;            // Splash particle speed relative to the hook's
+pub const SPLASH_LIFT: f32 = 0.4;                   // Upward bias on the unit splash ring
+
+// Fish fight (large catches)
+pub const FISH_FIGHT_ZONE_MIN: f32 = 0.35;      // Tension band that reels the fish in
+pub const FISH_FIGHT_ZONE_MAX: f32 = 0.65;
+pub const FISH_FIGHT_START_TENSION: f32 = 0.5;
+pub const FISH_FIGHT_START_PROGRESS: f32 = 0.3; // Landed at 1, escapes at 0
+pub const FISH_FIGHT_TAP_BOOST: f32 = 0.35;     // Tension speed added per reel tap
+pub const FISH_FIGHT_SLACK: f32 = 0.9;          // Tension speed lost per second (line goes slack)
+pub const FISH_FIGHT_TUG: f32 = 0.8;            // Strength of the fish's oscillating pull
+pub const FISH_FIGHT_TUG_RATE: f32 = 3.0;       // Radians per second of the pull cycle
+pub const FISH_FIGHT_REEL_RATE: f32 = 0.25;     // Progress per second inside the zone
+pub const FISH_FIGHT_SLIP_RATE: f32 = 0.2;      // Progress lost per second outside it
 
 // Pixel walls
 pub const PIXEL_SIZE: f32 = 3.0;
 pub const PIXEL_WALL_COLOR: u32 = 0xff808080;
+pub const WALL_GRID_COLS: usize = 20;     // Width in pixels of a newly started wall
+pub const WALL_GRID_ROWS: usize = 12;     // Height in pixels; the bottom row rests on the deck
+pub const WALL_PIXEL_COST: u32 = 1;       // Wood per wall pixel placed with the Builder
+pub const WALL_BUILD_REACH: f32 = 60.0;   // How far from the player walls can be built or smashed
+pub const WALL_SMASH_RADIUS: f32 = 6.0;   // Hammer blow radius against walls
+
+// Terrain colors (RGBA), drawn per cell in the side view
+pub const TERRAIN_SAND_COLOR: u32 = 0xC8B277FF;
+pub const TERRAIN_STONE_COLOR: u32 = 0x5E6670FF;
+pub const TERRAIN_LEAVES_COLOR: u32 = 0x2E8B57FF;  // Kelp green
+pub const TERRAIN_IRON_COLOR: u32 = 0x9C6B4EFF;    // Rusty ore
+pub const TERRAIN_CORAL_COLOR: u32 = 0xFF7F7FFF;
+pub const TERRAIN_TREASURE_COLOR: u32 = 0xDAA520FF; // Goldenrod chest
+pub const TERRAIN_PEARL_COLOR: u32 = 0xE8E4F0FF;
 
 // World generation
+pub const DEFAULT_WORLD_SEED: u32 = 12345;
+pub const MAX_SEED_DIGITS: u32 = 9; // Keeps typed seeds inside u32
 pub const CHUNK_SIZE: usize = 32;
 pub const RENDER_DISTANCE: i32 = 3;
+pub const MIN_RENDER_DISTANCE: i32 = 1; // Chunks, player-adjustable range
+pub const MAX_RENDER_DISTANCE: i32 = 6;
 
 // Terrain durability
 pub const SAND_HP: f32 = 50.0;
 pub const STONE_HP: f32 = 120.0;
 pub const IRON_HP: f32 = 180.0;
 pub const WATER_HP: f32 = 1.0;
+pub const ITEM_PICKUP_RANGE: f32 = 20.0; // Floating items this close can be collected by hand
+pub const SURFACE_PROMPT_DEPTH: f32 = 15.0; // Divers shallower than this are prompted to surface
+pub const MINE_REACH: f32 = 40.0; // Max distance from a diver to a seabed block they can mine with the Axe/Hammer
+
+// Treasure loot tables: (item, weight, max quantity); each roll picks one entry by weight
+pub const CHEST_LOOT_ROLLS: u32 = 4; // On top of one guaranteed Treasure
+pub const CHEST_LOOT_TABLE: &[(crate::models::ocean::FloatingItemType, u32, u32)] = &[
+    (crate::models::ocean::FloatingItemType::Metal, 30, 4),
+    (crate::models::ocean::FloatingItemType::Nail, 25, 12),
+    (crate::models::ocean::FloatingItemType::Rope, 15, 3),
+    (crate::models::ocean::FloatingItemType::Cloth, 15, 3),
+    (crate::models::ocean::FloatingItemType::Bottle, 10, 2),
+    (crate::models::ocean::FloatingItemType::Fish, 8, 2),
+    (crate::models::ocean::FloatingItemType::Treasure, 5, 1),
+];
+pub const FLOATING_TREASURE_LOOT_ROLLS: u32 = 2; // Rolled when a drifting Treasure is picked up
+pub const FLOATING_TREASURE_LOOT_TABLE: &[(crate::models::ocean::FloatingItemType, u32, u32)] = &[
+    (crate::models::ocean::FloatingItemType::Metal, 30, 2),
+    (crate::models::ocean::FloatingItemType::Nail, 30, 6),
+    (crate::models::ocean::FloatingItemType::Rope, 15, 2),
+    (crate::models::ocean::FloatingItemType::Cloth, 15, 2),
+    (crate::models::ocean::FloatingItemType::Treasure, 10, 1),
+];
 
 // Player survival and diving
+pub const CARRY_WEIGHT_FREE: f32 = 20.0;       // Weight carried with no swim penalty
+pub const CARRY_WEIGHT_MAX: f32 = 100.0;       // Weight at which the full penalty applies
+pub const MAX_ENCUMBRANCE_PENALTY: f32 = 0.6;  // Fraction of swim speed lost at CARRY_WEIGHT_MAX
+pub const AUTO_EAT_THRESHOLD: f32 = 25.0; // Auto-eat kicks in when hunger or thirst drops below this
+pub const HEALTH_REGEN_RATE: f32 = 0.5;        // Health per second while well fed and watered
+pub const HEALTH_REGEN_HUNGER_MIN: f32 = 50.0; // Hunger must be above this for health to regenerate
+pub const HEALTH_REGEN_THIRST_MIN: f32 = 50.0; // Thirst must be above this for health to regenerate
 pub const SURFACE_DEPTH: i32 = 0;
 pub const SHALLOW_DEPTH: i32 = -50;
 pub const DEEP_DEPTH: i32 = -150;
@@ -34,6 +129,33 @@ pub const ABYSS_DEPTH: i32 = -300;
 pub const MAX_BREATH: f32 = 100.0;
 pub const BREATH_LOSS_RATE: f32 = 15.0;      // per second while diving
 pub const BREATH_RECOVERY_RATE: f32 = 25.0;  // per second on surface
+pub const DIVES_PER_LUNG_MILESTONE: u32 = 5;   // Completed dives needed for each max breath increase
+pub const LUNG_CAPACITY_BONUS: f32 = 10.0;     // Max breath gained per milestone
+pub const MAX_LUNG_CAPACITY_BONUS: f32 = 50.0; // Cap on total max breath gained from diving
+pub const BREATH_LOW_FRACTION: f32 = 0.2;     // Breath meter flashes red below this share of max breath
+pub const PLAYER_MAX_STAMINA: f32 = 100.0;
+pub const SPRINT_SPEED_MULTIPLIER: f32 = 1.6;  // Swim speed while sprinting
+pub const SPRINT_STAMINA_DRAIN: f32 = 25.0;    // per second while sprinting
+pub const SPRINT_STAMINA_REGEN: f32 = 15.0;    // per second while not sprinting
+pub const STAMINA_RECOVER_THRESHOLD: f32 = 25.0; // Stamina needed to sprint again after running dry
+pub const PLAYER_IFRAME_SECONDS: f32 = 1.0;    // Further hits are ignored this long after taking damage
+pub const PLAYER_KNOCKBACK_DISTANCE: f32 = 12.0; // A hit shoves the player this far from the attacker
+
+// Food spoilage: perishable stacks lose freshness (1 = fresh, 0 = spoiled) while carried
+pub const FISH_SPOIL_SECONDS: f32 = 360.0;
+pub const COCONUT_SPOIL_SECONDS: f32 = 900.0;
+pub const SPOILED_FOOD_DAMAGE: f32 = 10.0; // Health lost eating spoiled food, which restores nothing
+
+// Body temperature
+pub const PLAYER_MAX_TEMPERATURE: f32 = 100.0;
+pub const ABYSS_CHILL_RATE: f32 = 6.0;        // Warmth lost per second below DEEP_DEPTH
+pub const NIGHT_CHILL_RATE: f32 = 1.5;        // Warmth lost per second at night
+pub const TEMPERATURE_RECOVERY_RATE: f32 = 5.0; // Warmth regained per second on the raft by day
+pub const COLD_THRESHOLD: f32 = 30.0;         // Below this the player is cold
+pub const COLD_SPEED_MULTIPLIER: f32 = 0.7;   // Movement speed while cold
+pub const COLD_HEALTH_DRAIN: f32 = 0.5;       // Health per second while cold
+pub const CLOTH_INSULATION: f32 = 0.1;        // Share of chill blocked per carried Cloth
+pub const MAX_CLOTH_INSULATION: f32 = 0.5;
 
 // Depth tint overlays (RGBA)
 pub const SURFACE_TINT: u32 = 0x87CEEB22; // LightSkyBlue, subtle alpha
@@ -54,6 +176,199 @@ pub const UI_TEXT_ORANGE: u32 = 0xFFA500FF;
 pub const UI_TEXT_BLUE: u32 = 0x1E90FFFF;  // DodgerBlue
 pub const UI_TEXT_GRAY: u32 = 0xAAAAAAFF;
 pub const UI_PANEL_BG: u32 = 0x223344CC;   // Semi-transparent panel
+pub const UI_TEXT_GREEN: u32 = 0x55FF55FF;
+pub const UI_BUTTON_BG: u32 = 0x335577FF;  // Clickable panel buttons
+pub const UI_BUTTON_DISABLED: u32 = 0x333A44FF; // Buttons that can't be used right now
+
+// HUD survival bars
+pub const SURVIVAL_BAR_LERP_RATE: f32 = 6.0; // Fraction of the gap closed per second (scaled by dt)
+
+// Control hints
+pub const CONTROL_HINTS_AUTO_HIDE_SECONDS: f32 = 180.0; // Hide hints after this much play unless toggled
+
+// Floating feedback numbers
+pub const MAX_FLOATING_TEXTS: usize = 8;
+pub const FLOATING_TEXT_LIFETIME: f32 = 1.2; // seconds
+pub const FLOATING_TEXT_RISE: f32 = 24.0;    // pixels risen over lifetime
+
+// Toast notifications
+pub const MAX_NOTIFICATIONS: usize = 5;
+pub const NOTIFICATION_DURATION: f32 = 2.5;  // seconds, default for short messages
+pub const NOTIFICATION_FADE_TIME: f32 = 0.5; // seconds of fade-out at the end of a toast
+
+// Crafting
+pub const MAX_PINNED_RECIPES: usize = 4; // Quick-craft bar slots (F1-F4)
+pub const MAX_CRAFT_QUEUE: usize = 5;    // Crafts waiting or in progress at once
+
+// Fish spawn depth bands (world units below the surface, matching the *_DEPTH zones)
+pub const FISH_SHALLOW_DEPTH: f32 = -(SHALLOW_DEPTH as f32); // Small/tropical fish above this
+pub const FISH_DEEP_DEPTH: f32 = -(DEEP_DEPTH as f32);       // Abyss begins below this
+pub const FISH_ABYSS_DEPTH: f32 = -(ABYSS_DEPTH as f32);     // Deepest spawn depth
+pub const FISH_MIN_SPAWN_DEPTH: f32 = 20.0;    // Keep dive spawns clear of the surface row
+pub const DIVE_SPAWN_DEPTH_SPREAD: f32 = 60.0; // Dive spawns land within this of the player's depth
+pub const SHARK_ABYSS_CHANCE: f32 = 0.1;       // Chance an abyss fish spawn is a shark
+
+// Bubbles: rise from around the diver and pop at the surface
+pub const BUBBLE_RISE_SPEED: f32 = 30.0;   // World units per second toward z = 0
+pub const BUBBLE_WOBBLE: f32 = 4.0;        // Max sideways drift speed
+pub const BUBBLE_MAX_LIFETIME: f32 = 15.0; // Seconds; enough to rise from the abyss
+pub const BUBBLE_SIZE: f32 = 3.0;
+pub const BUBBLE_COLOR: u32 = 0xADD8E6AA;  // Translucent light blue
+
+// Fish flee: fish near the player dart away (top-down and dive)
+pub const FISH_FLEE_RADIUS: f32 = 80.0; // World units; the AI "player_near" range
+pub const FISH_FLEE_SPEED: f32 = 60.0;  // Units per second away from the player
+pub const AI_WANDER_SPEED: f32 = 8.0;   // Idle swim speed before currents
+pub const AI_PATH_MAX_NODES: usize = 4000; // A* expansions before a goal is treated as unreachable
+pub const AI_PATH_CACHE_LIMIT: usize = 256; // Cached paths kept before the cache is flushed
+
+// Creature stamina: recovers only while idle (barely moving)
+pub const STAMINA_REGEN_RATE: f32 = 10.0; // Stamina per second
+pub const STAMINA_IDLE_SPEED: f32 = 5.0;  // Speeds below this count as idle
+
+// Sharks: spawn as threats in dive mode, chase the diving player, telegraph then bite
+pub const SHARK_SPAWN_RATE: u32 = 900;       // Frames between shark spawn attempts while diving
+pub const MAX_SHARKS: usize = 2;             // Concurrent sharks
+pub const SHARK_HUNT_RADIUS: f32 = 260.0;    // Sharks chase an underwater player within this distance
+pub const SHARK_CHASE_SPEED: f32 = 110.0;    // Units per second toward the player
+pub const SHARK_ATTACK_RANGE: f32 = 45.0;    // Sharks closer than this start a charge
+pub const SHARK_BITE_RANGE: f32 = 15.0;      // The bite only lands if the shark is this close when the windup ends
+pub const SHARK_BITE_PARTICLES: usize = 8;
+pub const SHARK_ATTACK_WINDUP: f32 = 0.75;   // Seconds of warning flash before the bite lands
+pub const SHARK_ATTACK_COOLDOWN: f32 = 2.5;  // Seconds between charges from the same shark
+pub const SHARK_BITE_DAMAGE: f32 = 15.0;
+
+// Spawn budget
+pub const MAX_SPAWNS_PER_FRAME: usize = 4; // Pending spawns turned into entities per frame; the rest wait
+pub const SPAWN_RATE_NEVER: u32 = u32::MAX; // Spawn rate that disables a type entirely
+
+// Items dropped from the inventory land this far from the player, plus up to the scatter
+pub const DROP_ITEM_OFFSET: f32 = 24.0;
+pub const DROP_ITEM_SCATTER: f32 = 12.0;
+
+// Floating item spawn spacing
+pub const ITEM_SPAWN_MIN_SPACING: f32 = 24.0; // Minimum y gap between recent spawns
+pub const ITEM_SPAWN_MEMORY: usize = 6;       // Recent spawn rows remembered
+pub const ITEM_SPAWN_ATTEMPTS: usize = 8;     // Candidates tried before taking the best
+
+// Day/night and sleeping
+pub const DAY_LENGTH_SECONDS: f32 = 300.0;  // One full day/night cycle
+pub const SLEEP_WAKE_TIME: f32 = 0.25;      // Time of day (0..1) the player wakes at
+pub const NIGHT_START_TIME: f32 = 0.75;     // Time of day night falls; it lasts until SLEEP_WAKE_TIME
+pub const SLEEP_THREAT_RADIUS: f32 = 200.0; // Sharks/monsters closer than this prevent sleeping
+pub const SLEEP_MIN_STAT: f32 = 30.0;       // Hunger and thirst must be above this to sleep
+pub const SLEEP_HUNGER_COST: f32 = 15.0;
+pub const SLEEP_THIRST_COST: f32 = 20.0;
+
+// Wind: heading and strength wander with layered slow sines (periods of a minute or more)
+pub const WIND_BASE_HEADING: f32 = 0.0;     // Radians; 0 blows left to right
+pub const WIND_HEADING_SWING: f32 = 1.2;    // Max radians the heading wanders either side
+pub const WIND_BASE_STRENGTH: f32 = 1.0;
+pub const WIND_STRENGTH_SWING: f32 = 0.7;   // Gusts and lulls around the base strength
+pub const WIND_MIN_STRENGTH: f32 = 0.1;
+pub const WIND_MAX_STRENGTH: f32 = WIND_BASE_STRENGTH + WIND_STRENGTH_SWING;
+pub const WIND_NOISE_RATES: [f32; 3] = [0.011, 0.029, 0.067]; // Radians per second per layer
+pub const RAFT_WIND_DRIFT: f32 = 0.2;       // Raft speed per unit of wind
+pub const ITEM_WIND_DRIFT: f32 = 1.5;       // Floating item speed per unit of wind, on top of the current
+pub const ITEM_BASE_FLOW: f32 = 6.0;        // Left-to-right surface current carrying floating items
+
+// Rain and water collectors
+pub const RAIN_WIND_STRENGTH: f32 = 1.3;  // Wind at or above this strength is a rainstorm
+pub const RAIN_DRINK_AMOUNT: f32 = 20.0;  // Thirst restored per sip of rain
+pub const COLLECTOR_FILL_RATE: f32 = 2.0; // Water per second per carried collector while it rains
+pub const COLLECTOR_CAPACITY: f32 = 100.0;
+pub const COLLECTOR_SIP: f32 = 25.0;      // Most water drunk from the collector at once
+
+// Difficulty: eases from 0 toward 1 the longer a run lasts
+pub const DIFFICULTY_RAMP_SECONDS: f32 = 1800.0;  // About two thirds of full difficulty after this long
+pub const DIFFICULTY_SHARK_RATE_BOOST: f32 = 2.0; // Sharks spawn up to 3x as often
+pub const DIFFICULTY_ITEM_SCARCITY: f32 = 1.0;    // Floating items spawn up to half as often
+pub const DIFFICULTY_WIND_BOOST: f32 = 0.5;       // Wind (and the drift it drives) up to 1.5x stronger
+
+// Entities
+pub const MAX_TOTAL_ENTITIES: usize = 400; // Global cap across all spawn sources
+
+// Rendering
+pub const MIN_RENDER_DIMENSION: u32 = 16;     // Skip layout work below this resolution (init frames, minimized)
+pub const MAX_OCEAN_TILES_PER_AXIS: i32 = 128; // Upper bound on ocean tile loop extent
+pub const RENDER_CULL_MARGIN: f32 = 32.0;      // Screen pixels past the edge before entities stop being queued
+pub const ITEM_LABEL_RADIUS: f32 = 120.0;      // World units around the player that get floating item name labels
+// Sound effects: (name, file, duration in seconds), registered at startup
+pub const SOUND_HOOK_CAST: &str = "hook_cast";
+pub const SOUND_ITEM_COLLECTED: &str = "item_collected";
+pub const SOUND_CRAFT_SUCCESS: &str = "craft_success";
+pub const SOUND_DIVE_SPLASH: &str = "dive_splash";
+pub const SOUND_SHARK_WARNING: &str = "shark_warning";
+pub const GAME_SOUNDS: [(&str, &str, f32); 5] = [
+    (SOUND_HOOK_CAST, "audio/hook_cast.wav", 0.4),
+    (SOUND_ITEM_COLLECTED, "audio/item_collected.wav", 0.3),
+    (SOUND_CRAFT_SUCCESS, "audio/craft_success.wav", 0.6),
+    (SOUND_DIVE_SPLASH, "audio/dive_splash.wav", 0.8),
+    (SOUND_SHARK_WARNING, "audio/shark_warning.wav", 0.5),
+];
+// Sprite names the renderer draws; shapes are used instead until all are loaded
+pub const REQUIRED_SPRITES: [&str; 17] = [
+    "idle_up", "idle_down", "idle_left", "idle_right",
+    "run_up", "run_down", "run_left", "run_right",
+    "swim_idle_up", "swim_idle_down", "swim_idle_left", "swim_idle_right",
+    "swim_move_up", "swim_move_down", "swim_move_left", "swim_move_right",
+    "waves",
+];
+// Camera lead: shift the view ahead of the moving player (top-down only)
+pub const CAMERA_LEAD_FACTOR: f32 = 24.0; // Pixels of lead per unit of movement input
+pub const CAMERA_LEAD_MAX: f32 = 40.0;    // Clamp on lead distance
+pub const CAMERA_LEAD_EASE: f32 = 0.08;   // Fraction of the gap to the target lead closed per frame
+pub const CAMERA_SMOOTHING: f32 = 8.0;    // Fraction of the gap to the camera target closed per second (0 snaps)
+pub const TRANSITION_FADE_SECONDS: f32 = 0.5; // Black fade-out after switching between surface and dive views
+
+// Camera zoom: world-to-screen scale around the camera center
+pub const MIN_CAMERA_ZOOM: f32 = 0.5;
+pub const MAX_CAMERA_ZOOM: f32 = 2.0;
+pub const CAMERA_ZOOM_STEP: f32 = 0.25;
+
+// Options menu
+pub const MASTER_VOLUME: f32 = 1.0;        // Default master volume (0 mutes)
+pub const MASTER_VOLUME_STEP: f32 = 0.1;   // Change per -/+ click
+pub const RENDER_SCALES: [f32; 3] = [1.0, 1.5, 2.0]; // Camera zoom presets cycled by the render scale toggle
+// Player bobbing: (radians per frame, pixel amplitude)
+pub const PLAYER_BOB_FLOAT: (f32, f32) = (0.06, 2.0); // Idle in water
+pub const PLAYER_BOB_SWIM: (f32, f32) = (0.22, 1.5);  // Swimming
+pub const PLAYER_BOB_RAFT: (f32, f32) = (0.03, 1.0);  // Standing still on the raft
 
 // Minimap
-pub const MINIMAP_RANGE: f32 = 200.0; // Distance in world units to show entities on minimap
+pub const MINIMAP_RANGE: f32 = 200.0; // Default distance in world units to show entities on minimap
+pub const MIN_MINIMAP_RANGE: f32 = 100.0;
+pub const MAX_MINIMAP_RANGE: f32 = 800.0;
+pub const MINIMAP_RANGE_STEP: f32 = 100.0; // Shift + =/- changes the range by this much
+pub const MINIMAP_SIZE: f32 = 80.0;        // Minimap box edge in pixels
+pub const MINIMAP_EDGE_INSET: f32 = 4.0;   // Points are clamped this far inside the box
+
+// Buoys: player-placed landmarks
+pub const MAX_BUOYS: usize = 8;
+pub const BUOY_COST: [(crate::models::ocean::FloatingItemType, u32); 2] = [
+    (crate::models::ocean::FloatingItemType::Plastic, 2),
+    (crate::models::ocean::FloatingItemType::Rope, 1),
+];
+pub const BUOY_COLOR: u32 = 0xFF5522FF; // Safety orange
+
+// Kraken boss: a MonsterGrid that closes in on the raft until it is shot to pieces
+pub const KRAKEN_FIRST_APPEARANCE: f32 = 900.0; // Seconds of play before the first kraken rises
+pub const KRAKEN_RETURN_INTERVAL: f32 = 1200.0; // Seconds after a defeat before the next one
+pub const KRAKEN_COLS: usize = 30;
+pub const KRAKEN_ROWS: usize = 30;
+pub const KRAKEN_COLOR: u32 = 0x7B3F8CFF;
+pub const KRAKEN_SPAWN_DISTANCE: f32 = 300.0;  // How far north of the raft it surfaces
+pub const KRAKEN_APPROACH_ACCEL: f32 = 0.01;   // Per-frame speed gained toward the raft
+pub const KRAKEN_STANDOFF: f32 = 12.0;         // Its front stops this far north of the raft center
+pub const KRAKEN_PATROL_RANGE: f32 = 60.0;     // Sways this far either side of the raft center
+pub const KRAKEN_HIT_DAMAGE: f32 = 6.0;        // Per tentacle slap on deck while it is at the raft
+pub const KRAKEN_HOOK_HIT_RADIUS: f32 = 8.0;   // Pixels torn out per hook strike
+pub const KRAKEN_SPEAR_HIT_RADIUS: f32 = 14.0;
+pub const KRAKEN_LOOT_ROLLS: u32 = 5;
+pub const KRAKEN_LOOT_TABLE: &[(crate::models::ocean::FloatingItemType, u32, u32)] = &[
+    (crate::models::ocean::FloatingItemType::Metal, 30, 5),
+    (crate::models::ocean::FloatingItemType::Rope, 20, 4),
+    (crate::models::ocean::FloatingItemType::Fish, 20, 4),
+    (crate::models::ocean::FloatingItemType::Treasure, 15, 2),
+    (crate::models::ocean::FloatingItemType::Cloth, 15, 3),
+];
@@ -1,6 +1,8 @@
 use turbo::*;
+mod color;
 mod constants;
 mod math;
+mod timer;
 mod models;
 mod components;
 
@@ -0,0 +1,48 @@
+use crate::math::Vec3 as V3;
+
+/// A thrown projectile (the spear) flying in a straight line until it hits something
+/// or runs out of range
+#[turbo::serialize]
+pub struct Bullet {
+    pub position: V3,
+    pub velocity: V3,
+    pub radius: f32,
+    pub damage: f32,
+    pub traveled: f32,
+    pub max_range: f32,
+    pub spent: bool, // Hit something or stopped; the game drops it and removes the entity
+}
+
+impl Bullet {
+    /// Launch from `start` along `direction` (normalized here) at `speed` units per second
+    pub fn new(start: V3, direction: V3, speed: f32, damage: f32, max_range: f32) -> Self {
+        let safe_dir = if direction.length() < 1e-3 { V3::new(1.0, 0.0, 0.0) } else { direction.normalize() };
+        Self {
+            position: start,
+            velocity: safe_dir.scale(speed),
+            radius: crate::constants::BULLET_RADIUS,
+            damage,
+            traveled: 0.0,
+            max_range,
+            spent: false,
+        }
+    }
+
+    /// Fly one step; past max range the bullet is spent
+    pub fn update(&mut self, delta_time: f32) {
+        if self.spent {
+            return;
+        }
+        let step = self.velocity.scale(delta_time);
+        self.position = self.position.add(step);
+        self.traveled += step.length();
+        if self.traveled >= self.max_range {
+            self.spent = true;
+        }
+    }
+
+    /// Whether the bullet touches a target of `target_radius` at `target`
+    pub fn hits(&self, target: &V3, target_radius: f32) -> bool {
+        !self.spent && self.position.distance_to(target) <= self.radius + target_radius
+    }
+}
@@ -1,4 +1,5 @@
 use crate::models::ocean::FloatingItemType;
+use crate::models::player::ToolStats;
 
 #[turbo::serialize]
 pub struct CraftingRecipe {
@@ -8,8 +9,29 @@ pub struct CraftingRecipe {
     pub ingredients: Vec<(FloatingItemType, u32)>, // (item_type, quantity)
     pub result: (FloatingItemType, u32), // (item_type, quantity)
     pub category: CraftingCategory,
-    pub discovered: bool,
+    pub always_known: bool, // Available from the start, never needs discovering
     pub unlock_requirements: Vec<FloatingItemType>, // Items needed to discover recipe
+    pub tool_stats: Option<ToolStats>, // Set for tool recipes, used for comparison
+    pub craft_time: u32, // Frames from queueing to delivery
+}
+
+/// A craft whose ingredients are spent and whose result is on its way
+#[turbo::serialize]
+pub struct QueuedCraft {
+    pub name: String,
+    pub result: (FloatingItemType, u32),
+    pub frames_left: u32,
+    pub total_frames: u32,
+}
+
+impl QueuedCraft {
+    /// 0..1 progress toward delivery
+    pub fn progress(&self) -> f32 {
+        if self.total_frames == 0 {
+            return 1.0;
+        }
+        1.0 - self.frames_left as f32 / self.total_frames as f32
+    }
 }
 
 #[turbo::serialize]
@@ -37,7 +59,8 @@ impl CraftingCategory {
 #[turbo::serialize]
 pub struct CraftingSystem {
     pub recipes: Vec<CraftingRecipe>,
-    pub discovered_recipes: Vec<String>, // Recipe IDs that have been discovered
+    pub discovered_recipes: Vec<String>, // Recipe IDs discovered this run; the only record of discovery
+    pub queue: Vec<QueuedCraft>, // In-progress crafts, worked on front to back
 }
 
 impl CraftingSystem {
@@ -45,6 +68,7 @@ impl CraftingSystem {
         let mut system = Self { 
             recipes: vec![],
             discovered_recipes: vec![],
+            queue: vec![],
         };
         system.initialize_recipes();
         system
@@ -60,10 +84,12 @@ impl CraftingSystem {
                 (FloatingItemType::Wood, 2),
                 (FloatingItemType::Rope, 1),
             ],
-            result: (FloatingItemType::Wood, 1), // Placeholder - we'll need fishing rod item
+            result: (FloatingItemType::FishingRod, 1),
             category: CraftingCategory::Tools,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Wood, FloatingItemType::Rope],
+            tool_stats: Some(crate::models::player::Tool::FishingRod.stats()),
+            craft_time: 180,
         });
         
         self.recipes.push(CraftingRecipe {
@@ -74,10 +100,12 @@ impl CraftingSystem {
                 (FloatingItemType::Wood, 1),
                 (FloatingItemType::Metal, 1),
             ],
-            result: (FloatingItemType::Metal, 1), // Placeholder
+            result: (FloatingItemType::Spear, 1),
             category: CraftingCategory::Tools,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Wood, FloatingItemType::Metal],
+            tool_stats: Some(crate::models::player::Tool::Spear.stats()),
+            craft_time: 150,
         });
         
         // Building Materials
@@ -90,8 +118,10 @@ impl CraftingSystem {
             ],
             result: (FloatingItemType::Wood, 5), // More efficient processing
             category: CraftingCategory::Building,
-            discovered: true, // Always known
+            always_known: true,
             unlock_requirements: vec![],
+            tool_stats: None,
+            craft_time: 60,
         });
         
         self.recipes.push(CraftingRecipe {
@@ -103,22 +133,43 @@ impl CraftingSystem {
             ],
             result: (FloatingItemType::Rope, 1),
             category: CraftingCategory::Building,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Cloth],
+            tool_stats: None,
+            craft_time: 90,
         });
         
         self.recipes.push(CraftingRecipe {
             id: "net".to_string(),
             name: "Fishing Net".to_string(),
-            description: "A net for catching multiple fish".to_string(),
+            description: "Scoops up every floating item within reach".to_string(),
             ingredients: vec![
                 (FloatingItemType::Rope, 4),
                 (FloatingItemType::Cloth, 2),
             ],
-            result: (FloatingItemType::Rope, 2), // Placeholder
+            result: (FloatingItemType::Net, 1),
             category: CraftingCategory::Tools,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Rope, FloatingItemType::Cloth],
+            tool_stats: Some(crate::models::player::Tool::Net.stats()),
+            craft_time: 240,
+        });
+        
+        self.recipes.push(CraftingRecipe {
+            id: "twin_hook".to_string(),
+            name: "Twin Hook Kit".to_string(),
+            description: "Rigs a second line so two hooks can be out at once".to_string(),
+            ingredients: vec![
+                (FloatingItemType::Metal, 2),
+                (FloatingItemType::Rope, 2),
+                (FloatingItemType::Nail, 4),
+            ],
+            result: (FloatingItemType::HookUpgrade, 1),
+            category: CraftingCategory::Tools,
+            always_known: false,
+            unlock_requirements: vec![FloatingItemType::Metal, FloatingItemType::Nail],
+            tool_stats: None,
+            craft_time: 240,
         });
         
         // Storage
@@ -133,8 +184,10 @@ impl CraftingSystem {
             ],
             result: (FloatingItemType::Barrel, 1), // Using barrel as chest placeholder
             category: CraftingCategory::Storage,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Wood, FloatingItemType::Metal],
+            tool_stats: None,
+            craft_time: 300,
         });
         
         // Food Processing
@@ -146,10 +199,12 @@ impl CraftingSystem {
                 (FloatingItemType::Fish, 2),
                 (FloatingItemType::Cloth, 1),
             ],
-            result: (FloatingItemType::Fish, 3), // More efficient food
+            result: (FloatingItemType::DriedFish, 3), // More efficient food that never spoils
             category: CraftingCategory::Food,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Fish],
+            tool_stats: None,
+            craft_time: 120,
         });
         
         // Survival
@@ -162,16 +217,23 @@ impl CraftingSystem {
                 (FloatingItemType::Cloth, 2),
                 (FloatingItemType::Rope, 1),
             ],
-            result: (FloatingItemType::Bottle, 3), // Water bottles
+            result: (FloatingItemType::WaterCollector, 1),
             category: CraftingCategory::Survival,
-            discovered: false,
+            always_known: false,
             unlock_requirements: vec![FloatingItemType::Barrel],
+            tool_stats: None,
+            craft_time: 240,
         });
     }
     
+    /// Whether a recipe is usable: always known, or discovered this run
+    pub fn is_known(&self, recipe: &CraftingRecipe) -> bool {
+        recipe.always_known || self.discovered_recipes.contains(&recipe.id)
+    }
+    
     pub fn can_craft(&self, recipe_id: &str, inventory: &crate::models::player::Inventory) -> bool {
         if let Some(recipe) = self.recipes.iter().find(|r| r.id == recipe_id) {
-            if !recipe.discovered && !self.discovered_recipes.contains(&recipe.id) {
+            if !self.is_known(recipe) {
                 return false;
             }
             
@@ -186,8 +248,10 @@ impl CraftingSystem {
         false
     }
     
+    /// Spend a recipe's ingredients and queue its result; fails if the recipe can't be
+    /// crafted or the queue already holds MAX_CRAFT_QUEUE crafts
     pub fn craft_item(&mut self, recipe_id: &str, inventory: &mut crate::models::player::Inventory) -> bool {
-        if !self.can_craft(recipe_id, inventory) {
+        if !self.can_craft(recipe_id, inventory) || self.is_queue_full() {
             return false;
         }
         
@@ -199,38 +263,58 @@ impl CraftingSystem {
                 }
             }
             
-            // Add result
-            let (result_type, result_amount) = recipe.result;
-            inventory.add_material(result_type, result_amount);
-            
+            self.queue.push(QueuedCraft {
+                name: recipe.name.clone(),
+                result: recipe.result,
+                frames_left: recipe.craft_time,
+                total_frames: recipe.craft_time,
+            });
             return true;
         }
         false
     }
     
-    pub fn discover_recipes(&mut self, inventory: &crate::models::player::Inventory) {
-        for recipe in &mut self.recipes {
-            if !recipe.discovered && !self.discovered_recipes.contains(&recipe.id) {
-                // Check if player has unlock requirements
-                let mut can_discover = true;
-                for required_item in &recipe.unlock_requirements {
-                    if inventory.get_count(*required_item) == 0 {
-                        can_discover = false;
-                        break;
-                    }
-                }
-                
-                if can_discover {
-                    recipe.discovered = true;
-                    self.discovered_recipes.push(recipe.id.clone());
-                }
+    pub fn is_queue_full(&self) -> bool {
+        self.queue.len() >= crate::constants::MAX_CRAFT_QUEUE
+    }
+    
+    /// Advance the front craft by one frame and deliver it once done. A finished craft
+    /// that doesn't fit waits at the front until the inventory has room. Returns the
+    /// names of crafts delivered this frame.
+    pub fn update(&mut self, inventory: &mut crate::models::player::Inventory) -> Vec<String> {
+        let mut delivered = Vec::new();
+        let Some(front) = self.queue.first_mut() else { return delivered; };
+        front.frames_left = front.frames_left.saturating_sub(1);
+        if front.frames_left == 0 {
+            let (result_type, result_amount) = front.result;
+            if inventory.room_for(result_type) >= result_amount && inventory.add_material(result_type, result_amount) {
+                delivered.push(self.queue.remove(0).name);
             }
         }
+        delivered
+    }
+    
+    /// Whether the front craft is finished but blocked by a full inventory
+    pub fn is_waiting_for_space(&self) -> bool {
+        self.queue.first().is_some_and(|c| c.frames_left == 0)
+    }
+    
+    /// Mark recipes whose unlock items are all held as discovered; returns the names of
+    /// recipes unlocked by this call
+    pub fn discover_recipes(&mut self, inventory: &crate::models::player::Inventory) -> Vec<String> {
+        let unlocked: Vec<&CraftingRecipe> = self.recipes.iter()
+            .filter(|r| !self.is_known(r))
+            .filter(|r| r.unlock_requirements.iter().all(|&item| inventory.get_count(item) > 0))
+            .collect();
+        let names = unlocked.iter().map(|r| r.name.clone()).collect();
+        let ids: Vec<String> = unlocked.iter().map(|r| r.id.clone()).collect();
+        self.discovered_recipes.extend(ids);
+        names
     }
     
     pub fn get_available_recipes(&self) -> Vec<&CraftingRecipe> {
         self.recipes.iter()
-            .filter(|r| r.discovered || self.discovered_recipes.contains(&r.id))
+            .filter(|r| self.is_known(r))
             .collect()
     }
     
@@ -242,3 +326,62 @@ impl CraftingSystem {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::player::Inventory;
+
+    #[test]
+    fn queued_craft_delivers_after_its_craft_time() {
+        let mut crafting = CraftingSystem::new();
+        let craft_time = crafting.recipes.iter().find(|r| r.id == "planks").unwrap().craft_time;
+        let mut inventory = Inventory::new();
+        inventory.add_material(FloatingItemType::Wood, 3);
+
+        assert!(crafting.craft_item("planks", &mut inventory));
+        assert_eq!(inventory.get_count(FloatingItemType::Wood), 0);
+        for _ in 0..craft_time - 1 {
+            assert!(crafting.update(&mut inventory).is_empty());
+        }
+        assert_eq!(inventory.get_count(FloatingItemType::Wood), 0);
+        assert_eq!(crafting.update(&mut inventory), vec!["Wood Planks".to_string()]);
+        assert_eq!(inventory.get_count(FloatingItemType::Wood), 5);
+        assert!(crafting.queue.is_empty());
+    }
+
+    #[test]
+    fn finished_craft_waits_for_inventory_space() {
+        let mut crafting = CraftingSystem::new();
+        let mut inventory = Inventory::new();
+        inventory.add_material(FloatingItemType::Wood, 3);
+        assert!(crafting.craft_item("planks", &mut inventory));
+        for slot in &mut inventory.slots {
+            *slot = crate::models::player::InventorySlot::new_with_item(FloatingItemType::Nail, FloatingItemType::Nail.max_stack_size());
+        }
+        for _ in 0..600 {
+            assert!(crafting.update(&mut inventory).is_empty());
+        }
+        assert!(crafting.is_waiting_for_space());
+
+        inventory.slots[0] = crate::models::player::InventorySlot::new();
+        assert_eq!(crafting.update(&mut inventory).len(), 1);
+        assert_eq!(inventory.get_count(FloatingItemType::Wood), 5);
+    }
+
+    #[test]
+    fn discovery_is_tracked_only_in_the_discovered_list() {
+        let mut crafting = CraftingSystem::new();
+        let available = |c: &CraftingSystem| c.get_available_recipes().iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+        // Always-known recipes are listed without ever being discovered
+        assert_eq!(available(&crafting), vec!["planks".to_string()]);
+        assert!(crafting.discovered_recipes.is_empty());
+
+        let mut inventory = Inventory::new();
+        inventory.add_material(FloatingItemType::Fish, 1);
+        assert_eq!(crafting.discover_recipes(&inventory), vec!["Dried Fish".to_string()]);
+        assert!(crafting.discover_recipes(&inventory).is_empty());
+        assert_eq!(crafting.discovered_recipes, vec!["dried_fish".to_string()]);
+        assert!(available(&crafting).contains(&"dried_fish".to_string()));
+    }
+}
@@ -1,17 +1,20 @@
 use crate::math::Vec3 as V3;
-use crate::math::Vec2 as V2;
 
 #[turbo::serialize]
 pub struct Hook {
     pub position: V3,
     pub velocity: V3,
-    pub direction: V2,
+    pub direction: V3, // Unit launch direction (x/y on the surface, x/z when diving)
     pub length: f32,
     pub max_length: f32,
     pub speed: f32,
     pub state: HookState,
     pub attached_items: Vec<u32>, // Entity IDs of attached items
     pub owner_id: u32, // Player entity ID
+    pub fast_empty_retract: bool, // Reel empty hooks back quickly (from GameSettings)
+    pub reel_multiplier: f32, // Player reeling boost on the return trip (1 = not reeling)
+    pub fight: Option<FishFight>, // Large fish on the line; the hook holds still until it's settled
+    pub terrain_impact: Option<V3>, // Where the hook last struck terrain; taken by the game for effects
 }
 
 #[turbo::serialize]
@@ -23,44 +26,126 @@ pub enum HookState {
     Retracting,   // Hook is returning to player
 }
 
+/// Tension minigame for a large fish: the fish tugs the tension around, reel taps push it up,
+/// and keeping it inside the zone reels the fish in while drifting out lets it work loose
+#[turbo::serialize]
+pub struct FishFight {
+    pub fish_id: u32,
+    pub tension: f32,          // 0..1 line tension
+    pub tension_velocity: f32,
+    pub progress: f32,         // 0 = escaped, 1 = landed
+    pub time: f32,             // Seconds into the fight, drives the fish's pull cycle
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FightOutcome {
+    Fighting,
+    Landed,
+    Escaped,
+}
+
+impl FishFight {
+    pub fn new(fish_id: u32) -> Self {
+        Self {
+            fish_id,
+            tension: crate::constants::FISH_FIGHT_START_TENSION,
+            tension_velocity: 0.0,
+            progress: crate::constants::FISH_FIGHT_START_PROGRESS,
+            time: 0.0,
+        }
+    }
+    
+    pub fn in_zone(&self) -> bool {
+        (crate::constants::FISH_FIGHT_ZONE_MIN..=crate::constants::FISH_FIGHT_ZONE_MAX).contains(&self.tension)
+    }
+    
+    /// Advance one step; `tapped` is a reel tap this frame
+    pub fn update(&mut self, delta_time: f32, tapped: bool) -> FightOutcome {
+        use crate::constants::*;
+        self.time += delta_time;
+        if tapped {
+            self.tension_velocity += FISH_FIGHT_TAP_BOOST;
+        }
+        self.tension_velocity += (FISH_FIGHT_TUG * (self.time * FISH_FIGHT_TUG_RATE).sin() - FISH_FIGHT_SLACK) * delta_time;
+        self.tension += self.tension_velocity * delta_time;
+        // The line can't go past slack or taut; hitting either end stops the swing
+        if self.tension <= 0.0 {
+            self.tension = 0.0;
+            self.tension_velocity = self.tension_velocity.max(0.0);
+        } else if self.tension >= 1.0 {
+            self.tension = 1.0;
+            self.tension_velocity = self.tension_velocity.min(0.0);
+        }
+        let rate = if self.in_zone() { FISH_FIGHT_REEL_RATE } else { -FISH_FIGHT_SLIP_RATE };
+        self.progress = (self.progress + rate * delta_time).clamp(0.0, 1.0);
+        if self.progress >= 1.0 {
+            FightOutcome::Landed
+        } else if self.progress <= 0.0 {
+            FightOutcome::Escaped
+        } else {
+            FightOutcome::Fighting
+        }
+    }
+}
+
 impl Hook {
     pub fn new(owner_id: u32) -> Self {
         Self {
             position: V3::zero(),
             velocity: V3::zero(),
-            direction: V2::new(1.0, 0.0), // Default right direction
+            direction: V3::new(1.0, 0.0, 0.0), // Default right direction
             length: 0.0,
-            max_length: 100.0,
+            max_length: crate::constants::HOOK_MAX_LENGTH,
             speed: 80.0, // Much faster speed - 20 units per second
             state: HookState::Retracted,
             attached_items: Vec::new(),
             owner_id,
+            fast_empty_retract: true,
+            reel_multiplier: 1.0,
+            fight: None,
+            terrain_impact: None,
         }
     }
     
-    pub fn launch(&mut self, start_pos: V3, direction: V2) {
+    pub fn launch(&mut self, start_pos: V3, direction: V3) {
         self.position = start_pos;
         // Guard against zero-length direction to avoid NaNs
         let dir_len = direction.length();
-        let safe_dir = if dir_len < 1e-3 { V2::new(1.0, 0.0) } else { direction.normalize() };
+        let safe_dir = if dir_len < 1e-3 { V3::new(1.0, 0.0, 0.0) } else { direction.normalize() };
         self.direction = safe_dir;
-        self.velocity = V3::new(safe_dir.x * self.speed, safe_dir.y * self.speed, 0.0);
+        self.velocity = safe_dir.scale(self.speed);
         self.length = 0.0;
         self.state = HookState::Extending;
         self.attached_items.clear();
+        self.fight = None;
     }
     
-    pub fn update(&mut self, delta_time: f32, player_pos: V3) -> bool {
+    /// Advance the hook; `hits_terrain` reports whether a world position is inside solid terrain,
+    /// and an outgoing hook stops at the first such point. Returns true once it's back at the player.
+    pub fn update(&mut self, delta_time: f32, player_pos: V3, hits_terrain: impl Fn(&V3) -> bool) -> bool {
+        if self.fight.is_some() {
+            return false; // Held in place while fighting a fish
+        }
         match self.state {
             HookState::Retracted => {
                 // Hook is at player, do nothing
                 false
             },
             HookState::Extending => {
-                // Move hook away from player
+                // Move hook away from player in short steps so it can't tunnel through terrain
                 let move_distance = self.speed * delta_time;
-                self.position = self.position.add(self.velocity.scale(delta_time));
-                self.length += move_distance;
+                let steps = (move_distance / crate::constants::HOOK_TERRAIN_STEP).ceil().max(1.0) as u32;
+                let step = self.velocity.scale(delta_time / steps as f32);
+                for _ in 0..steps {
+                    let next = self.position.add(step);
+                    if hits_terrain(&next) {
+                        self.state = HookState::Extended;
+                        self.terrain_impact = Some(next);
+                        return false;
+                    }
+                    self.position = next;
+                    self.length += move_distance / steps as f32;
+                }
                 
                 // Check if we've reached max length
                 if self.length >= self.max_length {
@@ -85,9 +170,9 @@ impl Hook {
                     return true; // Hook has completed its cycle
                 }
                 
-                // Move towards player (faster return speed)
+                // Move towards player; empty hooks snap back, loaded reels feel heavier
                 let direction_to_player = to_player.normalize();
-                self.velocity = direction_to_player.scale(self.speed * 1.5); // 50% faster return
+                self.velocity = direction_to_player.scale(self.retract_speed());
                 self.position = self.position.add(self.velocity.scale(delta_time));
                 self.length = distance_to_player;
                 false
@@ -95,12 +180,56 @@ impl Hook {
         }
     }
     
+    /// Cut the cast short: an outgoing hook turns around and starts reeling back
+    pub fn start_retract(&mut self) {
+        if self.fight.is_none() && matches!(self.state, HookState::Extending | HookState::Extended) {
+            self.state = HookState::Retracting;
+        }
+    }
+    
+    /// Units per second on the return trip: load slows it, reeling speeds it up
+    pub fn retract_speed(&self) -> f32 {
+        self.speed * self.retract_multiplier() * self.reel_multiplier
+    }
+    
+    /// Return speed multiplier based on how much the hook is carrying
+    pub fn retract_multiplier(&self) -> f32 {
+        use crate::constants::*;
+        if self.attached_items.is_empty() {
+            if self.fast_empty_retract { HOOK_EMPTY_RETRACT_MULTIPLIER } else { HOOK_RETRACT_MULTIPLIER }
+        } else {
+            (HOOK_RETRACT_MULTIPLIER - HOOK_LOAD_SLOWDOWN * self.attached_items.len() as f32)
+                .max(HOOK_MIN_RETRACT_MULTIPLIER)
+        }
+    }
+    
     pub fn attach_item(&mut self, item_id: u32) {
         if !self.attached_items.contains(&item_id) {
             self.attached_items.push(item_id);
         }
     }
     
+    /// Hook a large fish and start fighting it
+    pub fn start_fight(&mut self, fish_id: u32) {
+        self.attach_item(fish_id);
+        self.fight = Some(FishFight::new(fish_id));
+    }
+    
+    /// Step an ongoing fight; once it's decided the hook reels back in, minus the fish if it escaped
+    pub fn update_fight(&mut self, delta_time: f32, tapped: bool) -> Option<FightOutcome> {
+        let fight = self.fight.as_mut()?;
+        let outcome = fight.update(delta_time, tapped);
+        if outcome != FightOutcome::Fighting {
+            let fish_id = fight.fish_id;
+            self.fight = None;
+            if outcome == FightOutcome::Escaped {
+                self.attached_items.retain(|&id| id != fish_id);
+            }
+            self.start_retract();
+        }
+        Some(outcome)
+    }
+    
     pub fn detach_all_items(&mut self) -> Vec<u32> {
         let items = self.attached_items.clone();
         self.attached_items.clear();
@@ -116,3 +245,101 @@ impl Hook {
         self.position
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Distance a hook loaded with `items` covers in one second of reeling back
+    fn retract_distance(items: u32) -> f32 {
+        let mut hook = Hook::new(0);
+        hook.launch(V3::zero(), V3::new(1.0, 0.0, 0.0));
+        for _ in 0..30 {
+            hook.update(1.0 / 60.0, V3::zero(), |_| false);
+        }
+        for id in 0..items {
+            hook.attach_item(id);
+        }
+        hook.start_retract();
+        let start = hook.position.x;
+        for _ in 0..60 {
+            hook.update(1.0 / 60.0, V3::new(-1000.0, 0.0, 0.0), |_| false);
+        }
+        start - hook.position.x
+    }
+
+    #[test]
+    fn more_attached_items_retract_slower() {
+        let light = retract_distance(1);
+        let heavy = retract_distance(4);
+        assert!(heavy < light, "4 items moved {} vs {} for 1 item", heavy, light);
+    }
+
+    /// Run a fight to its end, tapping whenever `tap` says to; returns the outcome and whether tension stayed in 0..1
+    fn run_fight(mut tap: impl FnMut(&FishFight) -> bool) -> (FightOutcome, bool) {
+        let mut fight = FishFight::new(7);
+        let mut bounded = true;
+        for _ in 0..60 * 60 {
+            let tapped = tap(&fight);
+            let outcome = fight.update(1.0 / 60.0, tapped);
+            bounded &= (0.0..=1.0).contains(&fight.tension) && (0.0..=1.0).contains(&fight.progress);
+            if outcome != FightOutcome::Fighting {
+                return (outcome, bounded);
+            }
+        }
+        (FightOutcome::Fighting, bounded)
+    }
+
+    #[test]
+    fn fish_fight_tension_stays_bounded_and_resolves() {
+        use crate::constants::FISH_FIGHT_ZONE_MIN;
+        // Never tapping lets the line go slack and the fish escapes
+        assert_eq!(run_fight(|_| false), (FightOutcome::Escaped, true));
+        // Mashing pins the line taut, outside the zone, so it escapes too
+        assert_eq!(run_fight(|_| true), (FightOutcome::Escaped, true));
+        // Tapping only as tension sinks toward the bottom of the zone lands it
+        assert_eq!(run_fight(|f| f.tension < FISH_FIGHT_ZONE_MIN + 0.05 && f.tension_velocity < 0.0), (FightOutcome::Landed, true));
+    }
+
+    #[test]
+    fn escaped_fish_comes_off_the_hook() {
+        let mut hook = Hook::new(0);
+        hook.launch(V3::zero(), V3::new(1.0, 0.0, 0.0));
+        hook.update(1.0 / 60.0, V3::zero(), |_| false);
+        hook.start_fight(7);
+        // The hook holds still and ignores retract requests while fighting
+        let pos = hook.position;
+        hook.start_retract();
+        hook.update(1.0 / 60.0, V3::zero(), |_| false);
+        assert_eq!(hook.state, HookState::Extending);
+        assert_eq!(hook.position.x, pos.x);
+
+        let mut outcome = None;
+        for _ in 0..60 * 60 {
+            outcome = hook.update_fight(1.0 / 60.0, false);
+            if outcome != Some(FightOutcome::Fighting) {
+                break;
+            }
+        }
+        assert_eq!(outcome, Some(FightOutcome::Escaped));
+        assert!(hook.fight.is_none());
+        assert!(!hook.attached_items.contains(&7));
+        assert_eq!(hook.state, HookState::Retracting);
+    }
+
+    #[test]
+    fn hook_stops_at_the_face_of_a_solid_block() {
+        let face = 20.0;
+        let mut hook = Hook::new(0);
+        hook.launch(V3::zero(), V3::new(1.0, 0.0, 0.0));
+        for _ in 0..60 {
+            if hook.state != HookState::Extending {
+                break;
+            }
+            hook.update(1.0 / 60.0, V3::zero(), |p| p.x >= face);
+        }
+        assert_eq!(hook.state, HookState::Extended);
+        assert!(hook.position.x < face && hook.position.x > face - crate::constants::HOOK_TERRAIN_STEP - 0.01, "hook at {}", hook.position.x);
+        assert!(hook.terrain_impact.is_some_and(|p| p.x >= face));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::constants::*;
+use crate::models::ocean::FloatingItemType;
+
+/// Weighted loot table: each roll picks one entry with probability weight / total weight,
+/// then a quantity from 1 to the entry's max
+pub struct TreasureLoot {
+    pub entries: &'static [(FloatingItemType, u32, u32)], // (item, weight, max quantity)
+    pub rolls: u32,
+}
+
+impl TreasureLoot {
+    /// Contents of a mined treasure chest
+    pub const CHEST: TreasureLoot = TreasureLoot { entries: CHEST_LOOT_TABLE, rolls: CHEST_LOOT_ROLLS };
+    /// Contents of a floating treasure pulled from the water
+    pub const FLOATING: TreasureLoot = TreasureLoot { entries: FLOATING_TREASURE_LOOT_TABLE, rolls: FLOATING_TREASURE_LOOT_ROLLS };
+    /// Spoils of a defeated kraken
+    pub const KRAKEN: TreasureLoot = TreasureLoot { entries: KRAKEN_LOOT_TABLE, rolls: KRAKEN_LOOT_ROLLS };
+
+    pub fn total_weight(&self) -> u32 {
+        self.entries.iter().map(|&(_, weight, _)| weight).sum()
+    }
+
+    /// Entry (item, max quantity) selected by a uniform roll in 0..1; None for an empty table
+    pub fn pick(&self, roll: f32) -> Option<(FloatingItemType, u32)> {
+        let mut remaining = roll.clamp(0.0, 1.0) * self.total_weight() as f32;
+        for &(item_type, weight, max_qty) in self.entries {
+            if remaining < weight as f32 {
+                return Some((item_type, max_qty));
+            }
+            remaining -= weight as f32;
+        }
+        self.entries.last().map(|&(item_type, _, max_qty)| (item_type, max_qty))
+    }
+
+    /// Roll the table with a uniform 0..1 source, merging repeats into one stack per item
+    pub fn roll_with(&self, mut random: impl FnMut() -> f32) -> Vec<(FloatingItemType, u32)> {
+        let mut loot: Vec<(FloatingItemType, u32)> = Vec::new();
+        for _ in 0..self.rolls {
+            let Some((item_type, max_qty)) = self.pick(random()) else { break; };
+            let quantity = (1 + (random() * max_qty as f32) as u32).min(max_qty);
+            match loot.iter_mut().find(|(t, _)| *t == item_type) {
+                Some(stack) => stack.1 += quantity,
+                None => loot.push((item_type, quantity)),
+            }
+        }
+        loot
+    }
+
+    pub fn roll(&self) -> Vec<(FloatingItemType, u32)> {
+        self.roll_with(turbo::random::f32)
+    }
+}
+
+/// "3 Metal, 2 Rope" style summary for loot notifications
+pub fn describe_loot(loot: &[(FloatingItemType, u32)]) -> String {
+    loot.iter().map(|(item_type, qty)| format!("{} {}", qty, item_type.display_name())).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_follow_configured_weights() {
+        let table = TreasureLoot::CHEST;
+        let total = table.total_weight() as f32;
+        let samples = 20_000;
+        // Small xorshift so the sample is deterministic without the engine's RNG
+        let mut state = 0x2545F491u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1u32 << 24) as f32
+        };
+        let mut counts = vec![0u32; table.entries.len()];
+        for _ in 0..samples {
+            let (item_type, _) = table.pick(next()).unwrap();
+            counts[table.entries.iter().position(|(t, _, _)| *t == item_type).unwrap()] += 1;
+        }
+        for (&(item_type, weight, _), &count) in table.entries.iter().zip(&counts) {
+            let expected = weight as f32 / total;
+            let observed = count as f32 / samples as f32;
+            assert!((observed - expected).abs() < 0.02, "{}: expected {:.3}, got {:.3}", item_type.display_name(), expected, observed);
+        }
+        let loot = table.roll_with(next);
+        assert!(loot.iter().all(|&(item_type, qty)| qty >= 1 && table.entries.iter().any(|&(t, _, _)| t == item_type)));
+    }
+}
@@ -6,6 +6,10 @@ pub mod crafting;
 pub mod ocean;
 pub mod raft;
 pub mod hook;
+pub mod physics_body;
+pub mod loot;
+pub mod wall;
+pub mod bullet;
 
 pub use player::{Player, Tool};
 pub use particle::Particle;
@@ -13,3 +17,4 @@ pub use particle::Particle;
 pub use ocean::{Ocean, FloatingItemType};
 pub use raft::{Raft, RaftTileType};
 pub use hook::{Hook, HookState};
+pub use physics_body::PhysicsBody;
@@ -155,16 +155,39 @@ impl MonsterGrid {
         removed
     }
 
-    pub fn render(&self) {
+    /// Draw the alive pixels; `to_screen` maps a world point to the screen at `zoom`
+    pub fn render(&self, zoom: f32, to_screen: impl Fn(f32, f32) -> (f32, f32)) {
+        let size = (PIXEL_SIZE * zoom).ceil();
         for r in 0..self.rows {
             for c in 0..self.cols {
                 let i = r * self.cols + c;
                 if self.cells[i] {
-                    let x = self.origin.x + c as f32 * PIXEL_SIZE;
-                    let y = self.origin.y + r as f32 * PIXEL_SIZE;
-                    rect!(x = x, y = y, w = PIXEL_SIZE, h = PIXEL_SIZE, color = self.color, fixed = true);
+                    let (x, y) = to_screen(self.origin.x + c as f32 * PIXEL_SIZE, self.origin.y + r as f32 * PIXEL_SIZE);
+                    rect!(x = x, y = y, w = size, h = size, color = self.color, fixed = true);
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_hits_break_the_monster_apart() {
+        let mut monster = MonsterGrid::new(0.0, 0.0, 30, 30, 0);
+        assert!(monster.center_of_mass().is_some());
+        // Strike across the whole grid, a few pixels apart
+        let step = PIXEL_SIZE * 3.0;
+        let mut destroyed = 0;
+        for row in 0..=10 {
+            for col in 0..=10 {
+                destroyed += monster.hit_circle(col as f32 * step, row as f32 * step, 8.0);
+            }
+        }
+        assert!(destroyed > 0);
+        assert!(monster.center_of_mass().is_none());
+        assert!(monster.cells.iter().all(|&alive| !alive));
+    }
+}
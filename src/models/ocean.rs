@@ -1,9 +1,12 @@
 use crate::math::Vec2 as V2;
+use crate::math::Vec3 as V3;
 
 #[turbo::serialize]
 pub struct Ocean {
     pub current_direction: V2,
     pub current_strength: f32,
+    pub wind_heading: f32,  // Radians in the top-down plane; 0 blows left to right
+    pub wind_strength: f32,
 }
 
 impl Ocean {
@@ -11,8 +14,41 @@ impl Ocean {
         Self {
             current_direction: V2::new(1.0, 0.0),
             current_strength: 0.25,
+            wind_heading: crate::constants::WIND_BASE_HEADING,
+            wind_strength: crate::constants::WIND_BASE_STRENGTH,
         }
     }
+
+    /// Weather model: heading and strength follow smooth seeded noise over play time,
+    /// so the wind swings and gusts gradually instead of jumping frame to frame.
+    /// Higher `difficulty` (0..1) blows harder overall.
+    pub fn update_wind(&mut self, time: f32, seed: u32, difficulty: f32) {
+        use crate::constants::*;
+        let heading_noise = Self::wind_noise(time, seed);
+        let strength_noise = Self::wind_noise(time, seed.wrapping_mul(0x9E37_79B9).wrapping_add(1));
+        self.wind_heading = WIND_BASE_HEADING + heading_noise * WIND_HEADING_SWING;
+        let strength = (WIND_BASE_STRENGTH + strength_noise * WIND_STRENGTH_SWING).max(WIND_MIN_STRENGTH);
+        self.wind_strength = strength * (1.0 + difficulty * DIFFICULTY_WIND_BOOST);
+    }
+
+    /// Storm gusts bring rain that can be drunk or caught in a water collector
+    pub fn is_raining(&self) -> bool {
+        self.wind_strength >= crate::constants::RAIN_WIND_STRENGTH
+    }
+
+    /// Current wind as a top-down vector (length = strength)
+    pub fn wind(&self) -> V3 {
+        V3::new(self.wind_heading.cos(), self.wind_heading.sin(), 0.0).scale(self.wind_strength)
+    }
+
+    /// Layered sines at incommensurate rates with seed-derived phases; stays within -1..1
+    fn wind_noise(time: f32, seed: u32) -> f32 {
+        let weights = [0.6, 0.3, 0.1];
+        crate::constants::WIND_NOISE_RATES.iter().zip(weights).enumerate().map(|(i, (rate, weight))| {
+            let phase = (seed.rotate_left(i as u32 * 11) % 6283) as f32 / 1000.0;
+            (time * rate + phase).sin() * weight
+        }).sum()
+    }
 }
 
 #[turbo::serialize]
@@ -31,10 +67,19 @@ pub enum FloatingItemType {
     Coconut,
     Fish,
     Seaweed,
+    DriedFish, // Crafted; never spoils
     
     // Special items
     Treasure,
     Bottle,
+    Coral, // Mined from the seabed, never floats
+    WaterCollector, // Crafted; fills with rainwater while carried
+    HookUpgrade,    // Crafted; installed on delivery for one more simultaneous hook
+    
+    // Crafted tools: single-item stacks, equipped from the hotbar
+    FishingRod,
+    Spear,
+    Net,
 }
 
 impl FloatingItemType {
@@ -53,13 +98,72 @@ impl FloatingItemType {
             FloatingItemType::Coconut => 0x654321FF,   // Brown coconut
             FloatingItemType::Fish => 0x87CEFAFF,      // Light blue fish
             FloatingItemType::Seaweed => 0x228B22FF,   // Green seaweed
+            FloatingItemType::DriedFish => 0xC08A4AFF, // Smoked brown fish
             
             // Special items
             FloatingItemType::Treasure => 0xFFD700FF,  // Gold treasure
             FloatingItemType::Bottle => 0x87CEEBFF,    // Sky blue bottle
+            FloatingItemType::Coral => 0xFF7F50FF,     // Coral pink
+            FloatingItemType::WaterCollector => 0x4682B4FF, // Steel blue barrel
+            FloatingItemType::HookUpgrade => 0xB0B8C0FF, // Bright steel hooks
+            
+            // Tools
+            FloatingItemType::FishingRod => 0xA0522DFF, // Sienna rod
+            FloatingItemType::Spear => 0xC0C0C0FF,      // Steel tip
+            FloatingItemType::Net => 0xDEB887FF,        // Burlywood net
+        }
+    }
+    
+    /// Human-readable name for tooltips and notifications
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FloatingItemType::Wood => "Wood",
+            FloatingItemType::Plastic => "Plastic",
+            FloatingItemType::Rope => "Rope",
+            FloatingItemType::Metal => "Scrap Metal",
+            FloatingItemType::Nail => "Nail",
+            FloatingItemType::Cloth => "Cloth",
+            FloatingItemType::Barrel => "Barrel",
+            FloatingItemType::Coconut => "Coconut",
+            FloatingItemType::Fish => "Raw Fish",
+            FloatingItemType::Seaweed => "Seaweed",
+            FloatingItemType::DriedFish => "Dried Fish",
+            FloatingItemType::Treasure => "Treasure",
+            FloatingItemType::Bottle => "Bottle",
+            FloatingItemType::Coral => "Coral",
+            FloatingItemType::WaterCollector => "Water Collector",
+            FloatingItemType::HookUpgrade => "Twin Hook Kit",
+            FloatingItemType::FishingRod => "Fishing Rod",
+            FloatingItemType::Spear => "Spear",
+            FloatingItemType::Net => "Fishing Net",
         }
     }
     
+    /// Sprite drawn for this item when its art is loaded; None draws a colored shape
+    pub fn sprite_name(&self) -> Option<&'static str> {
+        match self {
+            FloatingItemType::Wood => Some("wood_log"),
+            FloatingItemType::Plastic => Some("plastic"),
+            FloatingItemType::Rope => Some("rope"),
+            FloatingItemType::Metal => Some("scrap_metal"),
+            FloatingItemType::Barrel => Some("barrel"),
+            FloatingItemType::Coconut => Some("coconut"),
+            FloatingItemType::Bottle => Some("bottle"),
+            FloatingItemType::Coral => Some("coral"),
+            FloatingItemType::Treasure => Some("treasure"),
+            FloatingItemType::Nail | FloatingItemType::Cloth | FloatingItemType::Fish | FloatingItemType::Seaweed => None,
+            FloatingItemType::DriedFish => None,
+            FloatingItemType::WaterCollector => None,
+            FloatingItemType::HookUpgrade => None,
+            FloatingItemType::FishingRod | FloatingItemType::Spear | FloatingItemType::Net => None,
+        }
+    }
+    
+    /// Distance at which a hook tip catches this item (bigger items are easier to hook)
+    pub fn hook_radius(&self) -> f32 {
+        crate::constants::HOOK_ITEM_BASE_RADIUS + self.size() * crate::constants::HOOK_RADIUS_SIZE_SCALE
+    }
+    
     pub fn size(&self) -> f32 {
         match self {
             // Raft building materials - larger items
@@ -75,10 +179,19 @@ impl FloatingItemType {
             FloatingItemType::Coconut => 6.0,
             FloatingItemType::Fish => 7.0,
             FloatingItemType::Seaweed => 5.0,
+            FloatingItemType::DriedFish => 6.0,
             
             // Special items - various sizes
             FloatingItemType::Treasure => 8.0,
             FloatingItemType::Bottle => 6.0,
+            FloatingItemType::Coral => 7.0,
+            FloatingItemType::WaterCollector => 14.0,
+            FloatingItemType::HookUpgrade => 8.0,
+            
+            // Tools
+            FloatingItemType::FishingRod => 10.0,
+            FloatingItemType::Spear => 10.0,
+            FloatingItemType::Net => 9.0,
         }
     }
     
@@ -97,10 +210,17 @@ impl FloatingItemType {
             FloatingItemType::Coconut => 0.2,
             FloatingItemType::Fish => 0.15,
             FloatingItemType::Seaweed => 0.1,
+            FloatingItemType::DriedFish => 0.0, // Crafting only
             
             // Rare special items
             FloatingItemType::Treasure => 0.02,
             FloatingItemType::Bottle => 0.05,
+            FloatingItemType::Coral => 0.0, // Mining only
+            
+            // Crafting only
+            FloatingItemType::WaterCollector => 0.0,
+            FloatingItemType::HookUpgrade => 0.0,
+            FloatingItemType::FishingRod | FloatingItemType::Spear | FloatingItemType::Net => 0.0,
         }
     }
     
@@ -119,10 +239,42 @@ impl FloatingItemType {
             FloatingItemType::Coconut => 8,
             FloatingItemType::Fish => 4,
             FloatingItemType::Seaweed => 16,
+            FloatingItemType::DriedFish => 16, // Preserved food keeps, so it stacks higher
             
             // Special items - very small stacks
             FloatingItemType::Treasure => 1,
             FloatingItemType::Bottle => 8,
+            FloatingItemType::Coral => 16,
+            FloatingItemType::WaterCollector => 1,
+            FloatingItemType::HookUpgrade => 1,
+            
+            // Tools never stack
+            FloatingItemType::FishingRod | FloatingItemType::Spear | FloatingItemType::Net => 1,
+        }
+    }
+    
+    /// Carry weight per unit; heavy salvage slows swimming
+    pub fn weight(&self) -> f32 {
+        match self {
+            FloatingItemType::Wood => 1.0,
+            FloatingItemType::Plastic => 0.5,
+            FloatingItemType::Rope => 0.5,
+            FloatingItemType::Metal => 3.0,
+            FloatingItemType::Nail => 0.1,
+            FloatingItemType::Cloth => 0.3,
+            FloatingItemType::Barrel => 8.0,
+            FloatingItemType::Coconut => 0.8,
+            FloatingItemType::Fish => 0.6,
+            FloatingItemType::Seaweed => 0.2,
+            FloatingItemType::DriedFish => 0.4,
+            FloatingItemType::Treasure => 4.0,
+            FloatingItemType::Bottle => 0.5,
+            FloatingItemType::Coral => 1.5,
+            FloatingItemType::WaterCollector => 6.0,
+            FloatingItemType::HookUpgrade => 2.0,
+            FloatingItemType::FishingRod => 1.5,
+            FloatingItemType::Spear => 2.0,
+            FloatingItemType::Net => 2.5,
         }
     }
     
@@ -130,15 +282,26 @@ impl FloatingItemType {
         matches!(self, 
             FloatingItemType::Coconut | 
             FloatingItemType::Fish | 
-            FloatingItemType::Seaweed
+            FloatingItemType::Seaweed |
+            FloatingItemType::DriedFish
         )
     }
     
+    /// Seconds for a fresh stack to spoil completely; None for food that keeps
+    pub fn spoil_time(&self) -> Option<f32> {
+        match self {
+            FloatingItemType::Fish => Some(crate::constants::FISH_SPOIL_SECONDS),
+            FloatingItemType::Coconut => Some(crate::constants::COCONUT_SPOIL_SECONDS),
+            _ => None,
+        }
+    }
+    
     pub fn hunger_restore(&self) -> f32 {
         match self {
             FloatingItemType::Coconut => 15.0,
             FloatingItemType::Fish => 25.0,
             FloatingItemType::Seaweed => 5.0,
+            FloatingItemType::DriedFish => 20.0,
             _ => 0.0,
         }
     }
@@ -150,6 +313,76 @@ impl FloatingItemType {
             _ => 0.0,
         }
     }
+
+    pub fn category(&self) -> ItemCategory {
+        match self {
+            FloatingItemType::Wood
+            | FloatingItemType::Plastic
+            | FloatingItemType::Rope
+            | FloatingItemType::Metal
+            | FloatingItemType::Nail
+            | FloatingItemType::Cloth
+            | FloatingItemType::Barrel => ItemCategory::Building,
+            FloatingItemType::Coconut
+            | FloatingItemType::Fish
+            | FloatingItemType::Seaweed
+            | FloatingItemType::DriedFish => ItemCategory::Food,
+            FloatingItemType::Treasure
+            | FloatingItemType::Bottle
+            | FloatingItemType::Coral
+            | FloatingItemType::WaterCollector
+            | FloatingItemType::HookUpgrade => ItemCategory::Special,
+            FloatingItemType::FishingRod
+            | FloatingItemType::Spear
+            | FloatingItemType::Net => ItemCategory::Tools,
+        }
+    }
+    
+    /// Tool equipped when this item is selected on the hotbar; None for materials and food
+    pub fn tool(&self) -> Option<crate::models::player::Tool> {
+        use crate::models::player::Tool;
+        match self {
+            FloatingItemType::FishingRod => Some(Tool::FishingRod),
+            FloatingItemType::Spear => Some(Tool::Spear),
+            FloatingItemType::Net => Some(Tool::Net),
+            _ => None,
+        }
+    }
+
+    /// Items that stay at their depth instead of bobbing back to the surface
+    pub fn sinks(&self) -> bool {
+        matches!(self, FloatingItemType::Treasure)
+    }
+}
+
+/// Broad item groupings used for inventory filtering
+#[turbo::serialize]
+#[derive(Copy, PartialEq, Eq, Hash)]
+pub enum ItemCategory {
+    Building,
+    Food,
+    Special,
+    Tools,
 }
 
+impl ItemCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemCategory::Building => "Building",
+            ItemCategory::Food => "Food",
+            ItemCategory::Special => "Special",
+            ItemCategory::Tools => "Tools",
+        }
+    }
 
+    /// Advance an inventory filter: All -> Building -> Food -> Special -> Tools -> All
+    pub fn cycle(current: Option<ItemCategory>) -> Option<ItemCategory> {
+        match current {
+            None => Some(ItemCategory::Building),
+            Some(ItemCategory::Building) => Some(ItemCategory::Food),
+            Some(ItemCategory::Food) => Some(ItemCategory::Special),
+            Some(ItemCategory::Special) => Some(ItemCategory::Tools),
+            Some(ItemCategory::Tools) => None,
+        }
+    }
+}
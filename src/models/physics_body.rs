@@ -0,0 +1,69 @@
+use crate::math::Vec3;
+use crate::constants::*;
+
+/// Vertical buoyancy for bodies in the water. The surface is z = 0; anything below it
+/// is pushed up like a damped spring, so it rises quickly when deep and settles gently.
+#[turbo::serialize]
+#[derive(Copy, PartialEq)]
+pub struct PhysicsBody {
+    pub buoyancy: f32,
+    pub damping: f32,
+}
+
+impl PhysicsBody {
+    pub fn new(buoyancy: f32, damping: f32) -> Self {
+        Self { buoyancy, damping }
+    }
+
+    pub fn floating_item() -> Self {
+        Self::new(ITEM_BUOYANCY, ITEM_BUOYANCY_DAMPING)
+    }
+
+    pub fn diver() -> Self {
+        Self::new(DIVER_BUOYANCY, DIVER_BUOYANCY_DAMPING)
+    }
+
+    /// Acceleration on a body at `position` moving at `velocity`; zero at or above the surface
+    pub fn buoyancy_force(&self, position: &Vec3, velocity: &Vec3) -> Vec3 {
+        if position.z >= 0.0 {
+            return Vec3::zero();
+        }
+        let depth = -position.z;
+        Vec3::new(0.0, 0.0, self.buoyancy * depth - self.damping * velocity.z)
+    }
+
+    /// Integrate one step: apply buoyancy to `velocity`, then move `position` by it.
+    /// Bodies that reach the surface stop there instead of bouncing out of the water.
+    pub fn step(&self, position: &mut Vec3, velocity: &mut Vec3, delta_time: f32) {
+        let submerged = position.z < 0.0;
+        *velocity = velocity.add(self.buoyancy_force(position, velocity).scale(delta_time));
+        *position = position.add(velocity.scale(delta_time));
+        if submerged && position.z > -BUOYANCY_SURFACE_SNAP && velocity.z >= 0.0 {
+            position.z = 0.0;
+            velocity.z = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submerged_item_rises_to_surface() {
+        let body = PhysicsBody::floating_item();
+        let mut position = Vec3::new(0.0, 0.0, -50.0);
+        let mut velocity = Vec3::zero();
+        let mut last_depth = -position.z;
+        for _ in 0..60 {
+            body.step(&mut position, &mut velocity, 1.0 / 60.0);
+            assert!(-position.z <= last_depth, "item sank from {} to {}", last_depth, -position.z);
+            last_depth = -position.z;
+        }
+        assert!(last_depth < 50.0 * 0.5);
+        for _ in 0..600 {
+            body.step(&mut position, &mut velocity, 1.0 / 60.0);
+        }
+        assert_eq!(position.z, 0.0);
+    }
+}
@@ -1,14 +1,105 @@
 use crate::math::Vec3 as V3;
 use crate::models::ocean::FloatingItemType;
 use crate::constants::*;
+use crate::timer::Timer;
 
 #[derive(PartialEq)]
 #[turbo::serialize]
 pub enum Tool {
     Hook,
+    FishingRod,
     Builder,
     Axe,
     Hammer,
+    Spear,
+    Net,
+}
+
+/// Starting inventory preset chosen on New Game
+#[derive(Copy, PartialEq, Default)]
+#[turbo::serialize]
+pub enum Loadout {
+    Hardcore, // Empty-handed
+    #[default]
+    Normal,
+    Builder,  // Generous building kit
+}
+
+impl Loadout {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Loadout::Hardcore => "Hardcore",
+            Loadout::Normal => "Normal",
+            Loadout::Builder => "Builder",
+        }
+    }
+    
+    /// Starting materials granted by this preset
+    pub fn items(&self) -> &'static [(FloatingItemType, u32)] {
+        match self {
+            Loadout::Hardcore => &[],
+            Loadout::Normal => &[(FloatingItemType::Wood, 10), (FloatingItemType::Plastic, 5), (FloatingItemType::Coconut, 2)],
+            Loadout::Builder => &[
+                (FloatingItemType::Wood, 40),
+                (FloatingItemType::Plastic, 20),
+                (FloatingItemType::Rope, 10),
+                (FloatingItemType::Nail, 20),
+                (FloatingItemType::Metal, 5),
+                (FloatingItemType::Coconut, 5),
+            ],
+        }
+    }
+    
+    pub fn cycle(self) -> Loadout {
+        match self {
+            Loadout::Hardcore => Loadout::Normal,
+            Loadout::Normal => Loadout::Builder,
+            Loadout::Builder => Loadout::Hardcore,
+        }
+    }
+}
+
+/// Descriptive numbers shown when comparing tools
+#[derive(Copy, PartialEq)]
+#[turbo::serialize]
+pub struct ToolStats {
+    pub hook_length: f32, // Max reach in world units (0 = no hook)
+    pub catch_bonus: f32, // Multiplier applied to fishing catch chance
+    pub cooldown: f32,    // Seconds between uses
+}
+
+impl Tool {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tool::Hook => "Hook",
+            Tool::FishingRod => "Fishing Rod",
+            Tool::Builder => "Builder",
+            Tool::Axe => "Axe",
+            Tool::Hammer => "Hammer",
+            Tool::Spear => "Spear",
+            Tool::Net => "Fishing Net",
+        }
+    }
+
+    /// Tools that throw the hook on use
+    pub fn casts_hook(&self) -> bool {
+        matches!(self, Tool::Hook | Tool::FishingRod)
+    }
+
+    /// Tools that break seabed blocks while diving
+    pub fn mines_terrain(&self) -> bool {
+        matches!(self, Tool::Axe | Tool::Hammer)
+    }
+
+    pub fn stats(&self) -> ToolStats {
+        match self {
+            Tool::Hook => ToolStats { hook_length: HOOK_MAX_LENGTH, catch_bonus: 1.0, cooldown: ACTION_COOLDOWN_SECONDS },
+            Tool::FishingRod => ToolStats { hook_length: FISHING_ROD_MAX_LENGTH, catch_bonus: FISHING_ROD_CATCH_BONUS, cooldown: ACTION_COOLDOWN_SECONDS },
+            Tool::Spear => ToolStats { hook_length: 0.0, catch_bonus: SPEAR_CATCH_BONUS, cooldown: SPEAR_COOLDOWN },
+            Tool::Net => ToolStats { hook_length: NET_RADIUS, catch_bonus: 1.0, cooldown: NET_COOLDOWN },
+            Tool::Builder | Tool::Axe | Tool::Hammer => ToolStats { hook_length: 0.0, catch_bonus: 0.5, cooldown: ACTION_COOLDOWN_SECONDS },
+        }
+    }
 }
 
 #[turbo::serialize]
@@ -16,6 +107,7 @@ pub struct InventorySlot {
     pub item_type: Option<FloatingItemType>,
     pub quantity: u32,
     pub max_stack: u32,
+    pub freshness: f32, // 1 = fresh, 0 = spoiled; only decays for perishable food
 }
 
 impl InventorySlot {
@@ -24,6 +116,7 @@ impl InventorySlot {
             item_type: None,
             quantity: 0,
             max_stack: 64, // Default stack size
+            freshness: 1.0,
         }
     }
     
@@ -32,6 +125,7 @@ impl InventorySlot {
             item_type: Some(item_type),
             quantity,
             max_stack: item_type.max_stack_size(),
+            freshness: 1.0,
         }
     }
     
@@ -50,10 +144,16 @@ impl InventorySlot {
     }
     
     pub fn add_items(&mut self, item_type: FloatingItemType, amount: u32) -> u32 {
+        self.add_items_with_freshness(item_type, amount, 1.0)
+    }
+    
+    /// Add items at the given freshness; a stack's freshness is the average of what's in it
+    pub fn add_items_with_freshness(&mut self, item_type: FloatingItemType, amount: u32, freshness: f32) -> u32 {
         if self.is_empty() {
             self.item_type = Some(item_type);
             self.max_stack = item_type.max_stack_size();
             self.quantity = amount.min(self.max_stack);
+            self.freshness = freshness;
             return amount - self.quantity;
         }
         
@@ -61,6 +161,9 @@ impl InventorySlot {
             if current_type == item_type {
                 let can_add = self.max_stack - self.quantity;
                 let added = amount.min(can_add);
+                if added > 0 {
+                    self.freshness = (self.freshness * self.quantity as f32 + freshness * added as f32) / (self.quantity + added) as f32;
+                }
                 self.quantity += added;
                 return amount - added;
             }
@@ -73,9 +176,14 @@ impl InventorySlot {
         self.quantity -= removed;
         if self.quantity == 0 {
             self.item_type = None;
+            self.freshness = 1.0;
         }
         removed
     }
+    
+    pub fn is_spoiled(&self) -> bool {
+        self.freshness <= 0.0
+    }
 }
 
 #[turbo::serialize]
@@ -131,6 +239,12 @@ impl Inventory {
         remaining < amount
     }
     
+    pub fn total_weight(&self) -> f32 {
+        self.slots.iter()
+            .filter_map(|slot| slot.item_type.map(|t| t.weight() * slot.quantity as f32))
+            .sum()
+    }
+    
     pub fn get_count(&self, material: FloatingItemType) -> u32 {
         self.slots.iter()
             .filter(|slot| slot.item_type == Some(material))
@@ -142,10 +256,38 @@ impl Inventory {
         self.slots.iter().map(|slot| slot.quantity).sum()
     }
     
+    /// How many more of `material` fit across matching stacks and empty slots
+    pub fn room_for(&self, material: FloatingItemType) -> u32 {
+        self.slots.iter()
+            .map(|slot| match slot.item_type {
+                _ if slot.is_empty() => material.max_stack_size(),
+                Some(t) if t == material => slot.max_stack.saturating_sub(slot.quantity),
+                _ => 0,
+            })
+            .sum()
+    }
+    
     pub fn has_space(&self) -> bool {
         self.slots.iter().any(|slot| slot.is_empty())
     }
     
+    /// Age perishable stacks by `dt` seconds
+    pub fn update_freshness(&mut self, dt: f32) {
+        for slot in &mut self.slots {
+            if let Some(spoil_time) = slot.item_type.and_then(|t| t.spoil_time()) {
+                slot.freshness = (slot.freshness - dt / spoil_time).max(0.0);
+            }
+        }
+    }
+    
+    /// Take one of `material` from the first stack holding it, returning that stack's freshness
+    pub fn take_one(&mut self, material: FloatingItemType) -> Option<f32> {
+        let slot = self.slots.iter_mut().find(|slot| slot.item_type == Some(material) && slot.quantity > 0)?;
+        let freshness = slot.freshness;
+        slot.remove_items(1);
+        Some(freshness)
+    }
+    
     pub fn remove_material(&mut self, material: FloatingItemType, amount: u32) -> bool {
         let mut remaining = amount;
         
@@ -169,6 +311,38 @@ impl Inventory {
         self.slots.get_mut(index)
     }
     
+    /// Move up to `amount` items from one slot into another that is empty or holds the same
+    /// item. The target's max_stack is respected; anything that doesn't fit stays in the
+    /// source. Returns how many items moved.
+    pub fn move_items(&mut self, src: usize, dst: usize, amount: u32) -> u32 {
+        if src == dst || dst >= self.slots.len() {
+            return 0;
+        }
+        let Some(item_type) = self.slots.get(src).and_then(|s| s.item_type) else { return 0; };
+        let freshness = self.slots[src].freshness;
+        let taken = self.slots[src].remove_items(amount);
+        let leftover = self.slots[dst].add_items_with_freshness(item_type, taken, freshness);
+        if leftover > 0 {
+            let _ = self.slots[src].add_items_with_freshness(item_type, leftover, freshness);
+        }
+        taken - leftover
+    }
+    
+    /// Drop the stack in `src` onto `dst`: top up a matching stack (up to its max_stack,
+    /// leaving the rest in `src`), otherwise swap the two slots
+    pub fn merge_or_swap(&mut self, src: usize, dst: usize) {
+        let src_type = self.slots.get(src).and_then(|s| s.item_type);
+        let dst_slot = self.slots.get(dst);
+        let can_merge = src_type.is_some()
+            && dst_slot.is_some_and(|d| d.item_type == src_type && d.quantity < d.max_stack);
+        if can_merge {
+            let quantity = self.slots[src].quantity;
+            let _ = self.move_items(src, dst, quantity);
+        } else {
+            let _ = self.swap_slots(src, dst);
+        }
+    }
+    
     pub fn swap_slots(&mut self, slot1: usize, slot2: usize) -> bool {
         if slot1 < self.slots.len() && slot2 < self.slots.len() {
             self.slots.swap(slot1, slot2);
@@ -177,6 +351,72 @@ impl Inventory {
         false
     }
     
+    /// Move a bag stack onto the hotbar (slots 0..9): top up a matching stack first, then
+    /// take the first empty hotbar slot. Returns the hotbar slot used, or None if full.
+    pub fn move_to_hotbar(&mut self, slot_index: usize) -> Option<usize> {
+        let hotbar_len = 10.min(self.slots.len());
+        if slot_index < hotbar_len {
+            return None;
+        }
+        let item_type = self.slots.get(slot_index)?.item_type?;
+        let freshness = self.slots[slot_index].freshness;
+        let mut quantity = self.slots[slot_index].quantity;
+        let mut last_target = None;
+        for i in 0..hotbar_len {
+            let hot = &mut self.slots[i];
+            if hot.item_type == Some(item_type) && hot.quantity < hot.max_stack {
+                let leftover = hot.add_items_with_freshness(item_type, quantity, freshness);
+                self.slots[slot_index].remove_items(quantity - leftover);
+                quantity = leftover;
+                last_target = Some(i);
+                if quantity == 0 {
+                    return last_target;
+                }
+            }
+        }
+        if let Some(empty) = (0..hotbar_len).find(|&i| self.slots[i].is_empty()) {
+            self.swap_slots(slot_index, empty);
+            return Some(empty);
+        }
+        last_target
+    }
+    
+    /// Merge and compact stacks, ordered by item type with empty slots last. Only the bag
+    /// (slots 10..) is touched unless `include_hotbar` is set.
+    pub fn sort(&mut self, include_hotbar: bool) {
+        let start = if include_hotbar { 0 } else { 10.min(self.slots.len()) };
+        // Per item: total quantity and the quantity-weighted freshness sum
+        let mut totals: std::collections::BTreeMap<FloatingItemType, (u32, f32)> = std::collections::BTreeMap::new();
+        for slot in &mut self.slots[start..] {
+            if let Some(item_type) = slot.item_type {
+                let freshness = slot.freshness;
+                let quantity = slot.remove_items(slot.quantity);
+                let total = totals.entry(item_type).or_insert((0, 0.0));
+                total.0 += quantity;
+                total.1 += freshness * quantity as f32;
+            }
+        }
+        let mut index = start;
+        for (item_type, (mut quantity, freshness_sum)) in totals {
+            let freshness = freshness_sum / quantity.max(1) as f32;
+            while quantity > 0 && index < self.slots.len() {
+                quantity = self.slots[index].add_items_with_freshness(item_type, quantity, freshness);
+                index += 1;
+            }
+        }
+        if self.selected_slot.is_some_and(|i| i >= start) {
+            self.selected_slot = None;
+        }
+    }
+    
+    /// Empty every bag slot (10..), returning what was in them as (item, quantity) stacks
+    pub fn empty_bag(&mut self) -> Vec<(FloatingItemType, u32)> {
+        let start = 10.min(self.slots.len());
+        self.slots[start..].iter_mut()
+            .filter_map(|slot| slot.item_type.map(|item_type| (item_type, slot.remove_items(slot.quantity))))
+            .collect()
+    }
+    
     pub fn move_to_quick_slot(&mut self, inventory_slot: usize, quick_slot: usize) -> bool {
         if quick_slot < self.quick_slots.len() && inventory_slot < self.slots.len() {
             self.quick_slots[quick_slot] = Some(inventory_slot);
@@ -205,27 +445,40 @@ pub struct Player {
     pub pos: V3,
     pub vel: V3,
     pub on_raft: bool,
-    pub facing: f32,
+    pub facing: f32, // Screen-plane heading in radians (0 = right, -PI/2 = up); kept while idle
     pub current_tool: Tool,
     pub inventory: Inventory,
-    pub action_cooldown: u32,
+    pub action_cooldown: Timer,
+    pub net_cooldown: Timer, // Between net sweeps
+    pub throw_cooldown: Timer, // Between spear throws
     pub hunger: f32,
     pub thirst: f32,
     pub health: f32,
     pub depth: i32,         // Current depth (0 = surface, negative = underwater)
     pub breath: f32,        // Oxygen/breath level
+    pub dive_experience: u32, // Completed dives, raises max breath at milestones
     pub is_diving: bool,    // Whether player is underwater
     pub last_movement: V3,  // Last movement direction for animation
     pub is_moving: bool,    // Whether player is currently moving
+    pub stamina: f32,       // Spent by sprinting, regenerates otherwise
+    pub is_sprinting: bool,
+    pub stamina_exhausted: bool, // Ran dry; no sprinting until STAMINA_RECOVER_THRESHOLD
+    pub max_hooks: u32,     // Hooks that can be out at once, raised by Twin Hook Kits
+    pub temperature: f32,   // Body warmth; lost in the Abyss and at night
+    pub damage_cooldown: f32, // Seconds of invulnerability left after taking a hit
 }
 
 impl Player {
-    pub fn new(pos: V3) -> Self { 
+    pub fn new(pos: V3) -> Self {
+        Self::with_loadout(pos, Loadout::Normal)
+    }
+    
+    /// New player carrying the given starting preset
+    pub fn with_loadout(pos: V3, loadout: Loadout) -> Self {
         let mut inventory = Inventory::new();
-        // Give player some starting materials
-        inventory.add_material(FloatingItemType::Wood, 10);
-        inventory.add_material(FloatingItemType::Plastic, 5);
-        inventory.add_material(FloatingItemType::Coconut, 2);
+        for &(item_type, quantity) in loadout.items() {
+            inventory.add_material(item_type, quantity);
+        }
         // Seed hotbar with up to 10 distinct item types (no repeats by type)
         let mut chosen_indices: Vec<usize> = Vec::new();
         let mut seen_types: std::collections::HashSet<FloatingItemType> = std::collections::HashSet::new();
@@ -249,34 +502,125 @@ impl Player {
             facing: 0.0,
             current_tool: Tool::Hook,
             inventory,
-            action_cooldown: 0,
+            action_cooldown: Timer::finished(ACTION_COOLDOWN_SECONDS),
+            net_cooldown: Timer::finished(NET_COOLDOWN),
+            throw_cooldown: Timer::finished(SPEAR_COOLDOWN),
             hunger: 100.0,
             thirst: 100.0,
             health: 100.0,
             depth: SURFACE_DEPTH,
             breath: MAX_BREATH,
+            dive_experience: 0,
             is_diving: false,
             last_movement: V3::zero(),
             is_moving: false,
+            stamina: PLAYER_MAX_STAMINA,
+            is_sprinting: false,
+            stamina_exhausted: false,
+            max_hooks: 1,
+            temperature: PLAYER_MAX_TEMPERATURE,
+            damage_cooldown: 0.0,
         } 
     }
     
+    /// Use up carried Twin Hook Kits, one extra hook each up to MAX_HOOKS; true if any were installed
+    pub fn install_hook_upgrades(&mut self) -> bool {
+        let before = self.max_hooks;
+        while self.max_hooks < MAX_HOOKS && self.inventory.remove_material(FloatingItemType::HookUpgrade, 1) {
+            self.max_hooks += 1;
+        }
+        self.max_hooks > before
+    }
+    
+    /// Sprinting needs stamina left and isn't possible after running dry until it recovers
+    pub fn can_sprint(&self) -> bool {
+        self.stamina > 0.0 && !self.stamina_exhausted
+    }
+    
     pub fn switch_tool(&mut self) {
         self.current_tool = match self.current_tool {
-            Tool::Hook => Tool::Builder,
+            Tool::Hook => Tool::FishingRod,
+            Tool::FishingRod => Tool::Builder,
             Tool::Builder => Tool::Axe,
             Tool::Axe => Tool::Hammer,
-            Tool::Hammer => Tool::Hook,
+            Tool::Hammer | Tool::Spear | Tool::Net => Tool::Hook,
         };
     }
     
     pub fn consume_item(&mut self, item_type: FloatingItemType) -> bool {
-        if item_type.is_consumable() && self.inventory.remove_material(item_type, 1) {
-            self.hunger = (self.hunger + item_type.hunger_restore()).min(100.0);
-            self.thirst = (self.thirst + item_type.thirst_restore()).min(100.0);
-            return true;
+        if !item_type.is_consumable() {
+            return false;
         }
-        false
+        let Some(freshness) = self.inventory.take_one(item_type) else { return false; };
+        self.eat_portion(item_type, freshness);
+        true
+    }
+    
+    /// Apply one eaten item: restores shrink as food goes off, and spoiled food hurts instead
+    fn eat_portion(&mut self, item_type: FloatingItemType, freshness: f32) {
+        if freshness <= 0.0 {
+            self.health = (self.health - SPOILED_FOOD_DAMAGE).max(0.0);
+            return;
+        }
+        self.hunger = (self.hunger + item_type.hunger_restore() * freshness).min(100.0);
+        self.thirst = (self.thirst + item_type.thirst_restore() * freshness).min(100.0);
+    }
+    
+    /// Swim speed multiplier from carried weight (1.0 when unencumbered)
+    pub fn encumbrance_multiplier(&self) -> f32 {
+        let over = (self.inventory.total_weight() - CARRY_WEIGHT_FREE) / (CARRY_WEIGHT_MAX - CARRY_WEIGHT_FREE);
+        1.0 - over.clamp(0.0, 1.0) * MAX_ENCUMBRANCE_PENALTY
+    }
+    
+    /// Still in the i-frame window after the last hit
+    pub fn is_invulnerable(&self) -> bool {
+        self.damage_cooldown > 0.0
+    }
+    
+    /// Take a hit from an attacker at `from`: lose health, get knocked away in the current view
+    /// plane and become briefly invulnerable. Returns false if the hit was ignored.
+    pub fn take_hit(&mut self, damage: f32, from: V3) -> bool {
+        if self.is_invulnerable() {
+            return false;
+        }
+        self.health = (self.health - damage).max(0.0);
+        self.damage_cooldown = PLAYER_IFRAME_SECONDS;
+        let mut away = self.pos.sub(from);
+        if self.is_diving { away.y = 0.0; } else { away.z = 0.0; }
+        self.pos = self.pos.add(away.normalize().scale(PLAYER_KNOCKBACK_DISTANCE));
+        true
+    }
+    
+    pub fn is_cold(&self) -> bool {
+        self.temperature < COLD_THRESHOLD
+    }
+    
+    /// Movement speed multiplier from body temperature (1.0 unless cold)
+    pub fn temperature_multiplier(&self) -> f32 {
+        if self.is_cold() { COLD_SPEED_MULTIPLIER } else { 1.0 }
+    }
+    
+    /// Share of chill blocked by carried Cloth
+    pub fn insulation(&self) -> f32 {
+        (self.inventory.get_count(FloatingItemType::Cloth) as f32 * CLOTH_INSULATION).min(MAX_CLOTH_INSULATION)
+    }
+    
+    /// Eat the least-valuable food that restores whichever of hunger/thirst is below
+    /// AUTO_EAT_THRESHOLD (hunger first). Returns the item eaten, if any.
+    pub fn auto_eat(&mut self) -> Option<FloatingItemType> {
+        let restore: fn(&FloatingItemType) -> f32 = if self.hunger < AUTO_EAT_THRESHOLD {
+            FloatingItemType::hunger_restore
+        } else if self.thirst < AUTO_EAT_THRESHOLD {
+            FloatingItemType::thirst_restore
+        } else {
+            return None;
+        };
+        let choice = self.inventory.slots.iter()
+            .filter(|slot| slot.quantity > 0)
+            .filter_map(|slot| slot.item_type)
+            .filter(|t| t.is_consumable() && restore(t) > 0.0)
+            .min_by(|a, b| restore(a).total_cmp(&restore(b)))?;
+        if self.consume_item(choice) { Some(choice) } else { None }
     }
     
     pub fn use_quick_item(&mut self, hotbar_index: usize) -> bool {
@@ -284,11 +628,16 @@ impl Player {
         if hotbar_index < 10 {
             if let Some(slot) = self.inventory.get_slot_mut(hotbar_index) {
                 if let Some(item_type) = slot.item_type {
+                    // Tools are equipped, not used up
+                    if let Some(tool) = item_type.tool() {
+                        self.current_tool = tool;
+                        return true;
+                    }
+                    let freshness = slot.freshness;
                     let used = slot.remove_items(1);
                     if used > 0 {
                         if item_type.is_consumable() {
-                            self.hunger = (self.hunger + item_type.hunger_restore()).min(100.0);
-                            self.thirst = (self.thirst + item_type.thirst_restore()).min(100.0);
+                            self.eat_portion(item_type, freshness);
                         }
                         return true;
                     }
@@ -298,10 +647,14 @@ impl Player {
         false
     }
     
-    pub fn update_cooldowns(&mut self) {
-        if self.action_cooldown > 0 {
-            self.action_cooldown -= 1;
-        }
+    /// Per-frame upkeep: cooldowns and food spoilage count down by `delta_time` seconds;
+    /// breath, stamina, temperature and survival stats step at their per-frame rates
+    pub fn update_cooldowns(&mut self, is_night: bool, delta_time: f32) {
+        self.action_cooldown.tick(delta_time);
+        self.net_cooldown.tick(delta_time);
+        self.throw_cooldown.tick(delta_time);
+        self.damage_cooldown = (self.damage_cooldown - delta_time).max(0.0);
+        self.inventory.update_freshness(delta_time);
         
         // Update breath system
         if self.is_diving {
@@ -314,16 +667,51 @@ impl Player {
         } else {
             // Recover breath on surface
             self.breath += BREATH_RECOVERY_RATE / 60.0;
-            self.breath = self.breath.min(MAX_BREATH);
+            self.breath = self.breath.min(self.max_breath());
+        }
+        
+        // Sprinting drains stamina; resting refills it
+        if self.is_sprinting {
+            self.stamina -= SPRINT_STAMINA_DRAIN / 60.0;
+            if self.stamina <= 0.0 {
+                self.stamina = 0.0;
+                self.stamina_exhausted = true;
+                self.is_sprinting = false;
+            }
+        } else {
+            self.stamina = (self.stamina + SPRINT_STAMINA_REGEN / 60.0).min(PLAYER_MAX_STAMINA);
+            if self.stamina >= STAMINA_RECOVER_THRESHOLD {
+                self.stamina_exhausted = false;
+            }
+        }
+        
+        // Chilled in the Abyss and at night, warmed back up on the raft by day
+        let mut chill = 0.0;
+        if self.depth < DEEP_DEPTH {
+            chill += ABYSS_CHILL_RATE;
+        }
+        if is_night {
+            chill += NIGHT_CHILL_RATE;
+        }
+        if chill > 0.0 {
+            self.temperature -= chill * (1.0 - self.insulation()) / 60.0;
+        } else if self.on_raft {
+            self.temperature += TEMPERATURE_RECOVERY_RATE / 60.0;
+        }
+        self.temperature = self.temperature.clamp(0.0, PLAYER_MAX_TEMPERATURE);
+        if self.is_cold() {
+            self.health -= COLD_HEALTH_DRAIN / 60.0;
         }
         
         // Decrease survival stats over time
         self.hunger -= 0.02; // Decrease faster
         self.thirst -= 0.03; // Thirst decreases fastest
         
-        // Health decreases if hungry or thirsty
+        // Health decreases if hungry or thirsty, and slowly regenerates while well fed and watered
         if self.hunger <= 0.0 || self.thirst <= 0.0 {
             self.health -= 0.1;
+        } else if self.hunger > HEALTH_REGEN_HUNGER_MIN && self.thirst > HEALTH_REGEN_THIRST_MIN {
+            self.health += HEALTH_REGEN_RATE / 60.0;
         }
         
         // Clamp values
@@ -332,8 +720,45 @@ impl Player {
         self.health = self.health.max(0.0).min(100.0);
     }
     
+    /// Max breath including the lung capacity bonus from dive experience
+    pub fn max_breath(&self) -> f32 {
+        let milestones = (self.dive_experience / DIVES_PER_LUNG_MILESTONE) as f32;
+        MAX_BREATH + (milestones * LUNG_CAPACITY_BONUS).min(MAX_LUNG_CAPACITY_BONUS)
+    }
+    
+    /// Count a completed dive on surfacing. Returns true when it raised max breath.
+    pub fn record_dive(&mut self) -> bool {
+        let before = self.max_breath();
+        self.dive_experience += 1;
+        self.max_breath() > before
+    }
+    
     pub fn can_use_hook(&self) -> bool {
-        self.current_tool == Tool::Hook && self.action_cooldown == 0
+        self.current_tool.casts_hook() && self.action_cooldown.is_finished()
+    }
+    
+    /// Net equipped and its sweep cooldown has run out
+    pub fn can_sweep_net(&self) -> bool {
+        self.current_tool == Tool::Net && self.net_cooldown.is_finished()
+    }
+    
+    /// Spear equipped, one left to throw, and the throw cooldown has run out
+    pub fn can_throw_spear(&self) -> bool {
+        self.current_tool == Tool::Spear
+            && self.throw_cooldown.is_finished()
+            && self.inventory.get_count(FloatingItemType::Spear) > 0
+    }
+    
+    /// Use up a carried spear for a throw; once the last one is gone the hook comes back out
+    pub fn throw_spear(&mut self) -> bool {
+        if !self.can_throw_spear() || !self.inventory.remove_material(FloatingItemType::Spear, 1) {
+            return false;
+        }
+        self.throw_cooldown.reset();
+        if self.inventory.get_count(FloatingItemType::Spear) == 0 {
+            self.current_tool = Tool::Hook;
+        }
+        true
     }
     
     pub fn can_build(&self) -> bool {
@@ -342,7 +767,7 @@ impl Player {
     }
     
     pub fn start_action(&mut self) {
-        self.action_cooldown = 15; // Cooldown in frames
+        self.action_cooldown.reset();
     }
     
     pub fn eat_food(&mut self, food_type: FloatingItemType) {
@@ -362,8 +787,8 @@ impl Player {
         }
     }
     
-    pub fn drink_water(&mut self) {
-        self.thirst = (self.thirst + 50.0).min(100.0);
+    pub fn drink_water(&mut self, amount: f32) {
+        self.thirst = (self.thirst + amount).min(100.0);
     }
     
     pub fn dive_down(&mut self) {
@@ -387,12 +812,169 @@ impl Player {
         }
     }
     
+    /// Overlay tint for the current depth, blended between the band tints so it
+    /// darkens smoothly on the way down to the Abyss
     pub fn get_depth_tint(&self) -> u32 {
-        match self.depth {
-            SURFACE_DEPTH => SURFACE_TINT,
-            d if d >= SHALLOW_DEPTH => SHALLOW_TINT,
-            d if d >= DEEP_DEPTH => DEEP_TINT,
-            _ => ABYSS_TINT,
+        let bands = [(SURFACE_DEPTH, SURFACE_TINT), (SHALLOW_DEPTH, SHALLOW_TINT), (DEEP_DEPTH, DEEP_TINT), (ABYSS_DEPTH, ABYSS_TINT)];
+        for pair in bands.windows(2) {
+            let ((top, top_tint), (bottom, bottom_tint)) = (pair[0], pair[1]);
+            if self.depth >= bottom {
+                let t = (top - self.depth) as f32 / (top - bottom) as f32;
+                return crate::color::Color::lerp(top_tint, bottom_tint, t);
+            }
+        }
+        ABYSS_TINT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_regenerates_when_fed_and_drains_when_starving() {
+        let mut fed = Player::new(V3::zero());
+        fed.hunger = 100.0;
+        fed.thirst = 100.0;
+        fed.health = 50.0;
+        for _ in 0..120 {
+            fed.update_cooldowns(false, 1.0 / 60.0);
+        }
+        assert!(fed.health > 50.0, "fed player should regenerate, got {}", fed.health);
+
+        let mut starving = Player::new(V3::zero());
+        starving.hunger = 0.0;
+        starving.thirst = 100.0;
+        starving.health = 50.0;
+        for _ in 0..120 {
+            starving.update_cooldowns(false, 1.0 / 60.0);
+        }
+        assert!(starving.health < 50.0, "starving player should lose health, got {}", starving.health);
+    }
+
+    #[test]
+    fn deep_diver_chills_while_surfaced_player_warms() {
+        let mut diver = Player::new(V3::zero());
+        diver.inventory = Inventory::new();
+        diver.on_raft = false;
+        diver.is_diving = true;
+        diver.depth = ABYSS_DEPTH;
+        let mut surfaced = Player::new(V3::zero());
+        surfaced.on_raft = true;
+        surfaced.temperature = 50.0;
+        for _ in 0..120 {
+            diver.update_cooldowns(false, 1.0 / 60.0);
+            surfaced.update_cooldowns(false, 1.0 / 60.0);
+        }
+        assert!(diver.temperature < PLAYER_MAX_TEMPERATURE, "diver should chill, got {}", diver.temperature);
+        assert!(surfaced.temperature > 50.0, "surfaced player should warm up, got {}", surfaced.temperature);
+    }
+
+    #[test]
+    fn second_hit_inside_iframes_is_ignored() {
+        let mut player = Player::new(V3::zero());
+        let attacker = V3::new(-10.0, 0.0, 0.0);
+        assert!(player.take_hit(15.0, attacker));
+        assert_eq!(player.health, 85.0);
+        assert!(player.pos.x > 0.0, "knocked away from the attacker");
+
+        player.update_cooldowns(false, 1.0 / 60.0);
+        let health = player.health;
+        assert!(!player.take_hit(15.0, attacker));
+        assert_eq!(player.health, health);
+
+        for _ in 0..(PLAYER_IFRAME_SECONDS * 60.0) as usize {
+            player.update_cooldowns(false, 1.0 / 60.0);
         }
+        assert!(player.take_hit(15.0, attacker));
+    }
+
+    #[test]
+    fn fish_freshness_decays_and_dried_fish_keeps() {
+        let mut player = Player::new(V3::zero());
+        player.inventory = Inventory::new();
+        player.inventory.add_material(FloatingItemType::Fish, 2);
+        player.inventory.add_material(FloatingItemType::DriedFish, 2);
+        for _ in 0..600 {
+            player.update_cooldowns(false, 1.0 / 60.0);
+        }
+        let freshness = |t: FloatingItemType| player.inventory.slots.iter().find(|s| s.item_type == Some(t)).unwrap().freshness;
+        let fish = freshness(FloatingItemType::Fish);
+        assert!(fish < 1.0 && fish > 0.0, "fish should be going off, got {}", fish);
+        assert_eq!(freshness(FloatingItemType::DriedFish), 1.0);
+
+        // Eaten past its best, fish restores less than fresh fish would
+        player.hunger = 50.0;
+        assert!(player.consume_item(FloatingItemType::Fish));
+        assert!(player.hunger > 50.0 && player.hunger < 50.0 + FloatingItemType::Fish.hunger_restore());
+    }
+
+    #[test]
+    fn merge_or_swap_respects_max_stack() {
+        let mut inventory = Inventory::new();
+        let _ = inventory.get_slot_mut(0).unwrap().add_items(FloatingItemType::Wood, 30);
+        let _ = inventory.get_slot_mut(15).unwrap().add_items(FloatingItemType::Wood, 10);
+        inventory.merge_or_swap(15, 0);
+        assert_eq!(inventory.get_slot(0).unwrap().quantity, 32);
+        assert_eq!(inventory.get_slot(15).unwrap().quantity, 8);
+
+        let _ = inventory.get_slot_mut(20).unwrap().add_items(FloatingItemType::Rope, 5);
+        inventory.merge_or_swap(20, 0);
+        assert_eq!(inventory.get_slot(0).unwrap().item_type, Some(FloatingItemType::Rope));
+        assert_eq!(inventory.get_slot(20).unwrap().quantity, 32);
+    }
+
+    #[test]
+    fn tool_items_equip_from_hotbar_and_never_stack() {
+        let mut player = Player::new(V3::zero());
+        player.inventory = Inventory::new();
+        assert!(player.inventory.add_material(FloatingItemType::FishingRod, 2));
+        assert!(player.inventory.add_material(FloatingItemType::Wood, 10));
+        assert_eq!(player.inventory.get_slot(0).unwrap().quantity, 1);
+        assert_eq!(player.inventory.get_slot(1).unwrap().item_type, Some(FloatingItemType::FishingRod));
+        assert_eq!(player.inventory.get_slot(2).unwrap().quantity, 10);
+
+        assert!(player.use_quick_item(0));
+        assert!(player.current_tool == Tool::FishingRod);
+        assert_eq!(player.inventory.get_slot(0).unwrap().quantity, 1, "equipping must not consume the tool");
+    }
+
+    #[test]
+    fn depth_tint_darkens_toward_the_abyss() {
+        let mut player = Player::new(V3::zero());
+        assert_eq!(player.get_depth_tint(), SURFACE_TINT);
+        player.depth = DEEP_DEPTH;
+        assert_eq!(player.get_depth_tint(), DEEP_TINT);
+        player.depth = ABYSS_DEPTH;
+        assert_eq!(player.get_depth_tint(), ABYSS_TINT);
+
+        let mut last_alpha = 0;
+        for depth in (ABYSS_DEPTH..=SURFACE_DEPTH).rev().step_by(10) {
+            player.depth = depth;
+            let alpha = player.get_depth_tint() & 0xFF;
+            assert!(alpha >= last_alpha, "tint should not lighten going down (depth {depth})");
+            last_alpha = alpha;
+        }
+    }
+
+    #[test]
+    fn sort_merges_split_stacks_and_leaves_the_hotbar() {
+        let mut inventory = Inventory::new();
+        inventory.slots[3] = InventorySlot::new_with_item(FloatingItemType::Wood, 2);
+        inventory.slots[12] = InventorySlot::new_with_item(FloatingItemType::Wood, 10);
+        inventory.slots[25] = InventorySlot::new_with_item(FloatingItemType::Nail, 4);
+        inventory.slots[31] = InventorySlot::new_with_item(FloatingItemType::Wood, 5);
+
+        inventory.sort(false);
+        assert_eq!(inventory.slots[3].quantity, 2);
+        let bag: Vec<(Option<FloatingItemType>, u32)> = inventory.slots[10..].iter().map(|s| (s.item_type, s.quantity)).collect();
+        let filled: Vec<_> = bag.iter().take_while(|(t, _)| t.is_some()).collect();
+        assert_eq!(filled.len(), 2);
+        assert!(filled.contains(&&(Some(FloatingItemType::Wood), 15)));
+        assert!(bag[2..].iter().all(|(t, _)| t.is_none()));
+
+        inventory.sort(true);
+        assert_eq!(inventory.get_count(FloatingItemType::Wood), 17);
+        assert!(inventory.slots[10..].iter().all(|s| s.is_empty()));
     }
 }
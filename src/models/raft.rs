@@ -1,27 +1,154 @@
 use crate::math::Vec3 as V3;
+use crate::constants::{RAFT_TILE_SIZE, RAFT_WOOD_FLOOR_COLOR};
 
+#[derive(Copy, PartialEq)]
 #[turbo::serialize]
 pub enum RaftTileType {
     Wood,
 }
 
+impl RaftTileType {
+    pub fn color(&self) -> u32 {
+        match self {
+            RaftTileType::Wood => RAFT_WOOD_FLOOR_COLOR,
+        }
+    }
+}
+
 #[turbo::serialize]
 pub struct Raft {
     pub center: V3,
-    pub size_tiles: (i32, i32),
+    pub size_tiles: (i32, i32), // Starting layout; anchors the tile grid origin
+    pub tiles: Vec<(i32, i32)>, // Occupied (col, row) cells; may extend past size_tiles
 }
 
 impl Raft {
     pub fn new(center: V3) -> Self {
-        Self { center, size_tiles: (4, 3) }
+        let size_tiles = (4, 3);
+        let tiles = (0..size_tiles.1)
+            .flat_map(|row| (0..size_tiles.0).map(move |col| (col, row)))
+            .collect();
+        Self { center, size_tiles, tiles }
+    }
+
+    /// World position of the tile grid's (0, 0) corner; moves with the drifting center
+    fn grid_origin(&self) -> (f32, f32) {
+        (
+            self.center.x - self.size_tiles.0 as f32 * RAFT_TILE_SIZE * 0.5,
+            self.center.y - self.size_tiles.1 as f32 * RAFT_TILE_SIZE * 0.5,
+        )
+    }
+
+    /// Grid cell containing a world position (top-down x/y)
+    pub fn tile_at(&self, pos: &V3) -> (i32, i32) {
+        let (origin_x, origin_y) = self.grid_origin();
+        (
+            ((pos.x - origin_x) / RAFT_TILE_SIZE).floor() as i32,
+            ((pos.y - origin_y) / RAFT_TILE_SIZE).floor() as i32,
+        )
+    }
+
+    /// Top-down world position of each occupied tile's min corner with its type.
+    /// Tiles don't store a type yet, so every one is a wood floor.
+    pub fn tile_corners(&self) -> impl Iterator<Item = (V3, RaftTileType)> + '_ {
+        let (origin_x, origin_y) = self.grid_origin();
+        self.tiles.iter().map(move |&(col, row)| {
+            let corner = V3::new(origin_x + col as f32 * RAFT_TILE_SIZE, origin_y + row as f32 * RAFT_TILE_SIZE, self.center.z);
+            (corner, RaftTileType::Wood)
+        })
     }
 
+    pub fn has_tile(&self, tile: (i32, i32)) -> bool {
+        self.tiles.contains(&tile)
+    }
+
+    /// Place a tile; returns false if the cell is already occupied
+    pub fn add_tile(&mut self, tile: (i32, i32)) -> bool {
+        if self.has_tile(tile) {
+            return false;
+        }
+        self.tiles.push(tile);
+        true
+    }
+
+    /// Remove a tile; returns false if the cell was empty
+    pub fn remove_tile(&mut self, tile: (i32, i32)) -> bool {
+        let before = self.tiles.len();
+        self.tiles.retain(|t| *t != tile);
+        self.tiles.len() != before
+    }
+
+    /// True only when standing over an occupied tile, so gaps in the raft count as water
     pub fn is_on_raft(&self, pos: &V3) -> bool {
-        let half_w = self.size_tiles.0 as f32 * 8.0;
-        let half_h = self.size_tiles.1 as f32 * 8.0;
-        pos.x >= self.center.x - half_w && pos.x <= self.center.x + half_w &&
-        pos.y >= self.center.y - half_h && pos.y <= self.center.y + half_h
+        self.has_tile(self.tile_at(pos))
+    }
+
+    /// Pull a top-down position back onto the nearest occupied tile, so walking into an
+    /// edge slides along it instead of stepping into the water. Positions already on the
+    /// raft (and z) are untouched.
+    pub fn clamp_to_raft(&self, pos: V3) -> V3 {
+        if self.is_on_raft(&pos) {
+            return pos;
+        }
+        let (origin_x, origin_y) = self.grid_origin();
+        // Stay just inside the far edges, which belong to the next cell
+        let inset = 0.01;
+        self.tiles.iter()
+            .map(|&(col, row)| {
+                let min_x = origin_x + col as f32 * RAFT_TILE_SIZE;
+                let min_y = origin_y + row as f32 * RAFT_TILE_SIZE;
+                V3::new(
+                    pos.x.clamp(min_x, min_x + RAFT_TILE_SIZE - inset),
+                    pos.y.clamp(min_y, min_y + RAFT_TILE_SIZE - inset),
+                    pos.z,
+                )
+            })
+            .min_by(|a, b| a.distance_to(&pos).total_cmp(&b.distance_to(&pos)))
+            .unwrap_or(pos)
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    #[test]
+    fn walking_off_the_edge_is_clamped_to_the_raft() {
+        let mut raft = Raft::new(V3::new(100.0, 50.0, 0.0));
+        // Drifted raft: the clamp region follows the center
+        raft.center = raft.center.add(V3::new(30.0, -10.0, 0.0));
+        let right_edge = raft.center.x + raft.size_tiles.0 as f32 * RAFT_TILE_SIZE * 0.5;
+        let pushed = V3::new(right_edge + 25.0, raft.center.y, 0.0);
+        let clamped = raft.clamp_to_raft(pushed);
+        assert!(raft.is_on_raft(&clamped));
+        assert!((clamped.x - right_edge).abs() < 0.1, "expected x near {}, got {}", right_edge, clamped.x);
+        assert_eq!(clamped.y, raft.center.y);
+    }
+
+    #[test]
+    fn inner_corner_of_an_l_shaped_raft_is_water() {
+        let mut raft = Raft::new(V3::zero());
+        // Cut the top-right 2x2 block out of the 4x3 deck, leaving an L
+        for tile in [(2, 0), (3, 0), (2, 1), (3, 1)] {
+            assert!(raft.remove_tile(tile));
+        }
+        assert!(!raft.remove_tile((3, 1)));
+        let (origin_x, origin_y) = raft.grid_origin();
+        let tile_center = |col: i32, row: i32| V3::new(
+            origin_x + (col as f32 + 0.5) * RAFT_TILE_SIZE,
+            origin_y + (row as f32 + 0.5) * RAFT_TILE_SIZE,
+            0.0,
+        );
+
+        assert!(raft.is_on_raft(&tile_center(1, 1)));
+        assert!(raft.is_on_raft(&tile_center(2, 2)));
+        assert!(!raft.is_on_raft(&tile_center(2, 1)), "the gap inside the L is not deck");
+        // Just past the inner corner on either side is still water
+        assert!(!raft.is_on_raft(&V3::new(origin_x + 2.0 * RAFT_TILE_SIZE + 0.5, origin_y + 2.0 * RAFT_TILE_SIZE - 0.5, 0.0)));
+
+        // Filling the corner back in makes it walkable again
+        assert!(raft.add_tile((2, 1)));
+        assert!(!raft.add_tile((2, 1)));
+        assert!(raft.is_on_raft(&tile_center(2, 1)));
+    }
+}
@@ -7,13 +7,39 @@ pub enum TerrainMaterial {
     Iron,
 }
 
+impl TerrainMaterial {
+    /// Base tile color; water is fully transparent so the background shows through
+    pub fn color(&self) -> u32 {
+        match self {
+            TerrainMaterial::Water => 0,
+            TerrainMaterial::Sand => crate::constants::TERRAIN_SAND_COLOR,
+            TerrainMaterial::Stone => crate::constants::TERRAIN_STONE_COLOR,
+            TerrainMaterial::Leaves => crate::constants::TERRAIN_LEAVES_COLOR,
+            TerrainMaterial::Iron => crate::constants::TERRAIN_IRON_COLOR,
+        }
+    }
+}
+
 #[turbo::serialize]
 pub struct TerrainCell {
     pub material: TerrainMaterial,
+    pub block_type: BlockType,
 }
 
 impl TerrainCell {
-    pub fn new(material: TerrainMaterial) -> Self { Self { material } }
+    pub fn new(material: TerrainMaterial) -> Self { Self { material, block_type: BlockType::Water } }
+
+    /// Cell holding `block_type`, with the closest material for rendering/physics
+    pub fn from_block(block_type: BlockType) -> Self {
+        let material = match block_type {
+            BlockType::Sand => TerrainMaterial::Sand,
+            BlockType::Kelp => TerrainMaterial::Leaves,
+            BlockType::IronDeposit => TerrainMaterial::Iron,
+            BlockType::Rock | BlockType::Coral | BlockType::TreasureChest | BlockType::PearlBed => TerrainMaterial::Stone,
+            BlockType::Water => TerrainMaterial::Water,
+        };
+        Self { material, block_type }
+    }
 }
 
 #[turbo::serialize]
@@ -30,6 +56,7 @@ impl TerrainChunk {
 }
 
 #[turbo::serialize]
+#[derive(Copy, PartialEq)]
 pub enum BlockType {
     Water,
     Sand,
@@ -47,6 +74,41 @@ pub struct Block {
     pub durability: f32,
 }
 
+impl BlockType {
+    /// Whether swimmers and AI paths are blocked by this block; water and kelp are open
+    pub fn is_solid(&self) -> bool {
+        !matches!(self, BlockType::Water | BlockType::Kelp)
+    }
+
+    /// Blocks an Axe or Hammer can break for loot (see `WorldSystem::mine_block`)
+    pub fn is_mineable(&self) -> bool {
+        matches!(self, BlockType::IronDeposit | BlockType::Coral | BlockType::TreasureChest)
+    }
+
+    /// Tile color; stone-like blocks that are worth finding get their own color
+    pub fn color(&self) -> u32 {
+        match self {
+            BlockType::Coral => crate::constants::TERRAIN_CORAL_COLOR,
+            BlockType::TreasureChest => crate::constants::TERRAIN_TREASURE_COLOR,
+            BlockType::PearlBed => crate::constants::TERRAIN_PEARL_COLOR,
+            _ => TerrainCell::from_block(*self).material.color(),
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            BlockType::Water => "Water",
+            BlockType::Sand => "Sand",
+            BlockType::Rock => "Rock",
+            BlockType::Coral => "Coral",
+            BlockType::Kelp => "Kelp",
+            BlockType::TreasureChest => "Treasure Chest",
+            BlockType::IronDeposit => "Iron Deposit",
+            BlockType::PearlBed => "Pearl Bed",
+        }
+    }
+}
+
 impl Block {
     pub fn new(block_type: BlockType, durability: f32) -> Self { Self { block_type, durability } }
 }
@@ -0,0 +1,134 @@
+use turbo::*;
+use crate::math::Vec2 as V2;
+use crate::constants::PIXEL_SIZE;
+
+/// Player-built pixel wall standing on the raft. Positions are raft-local (offsets from the
+/// raft center) so walls drift with it; the bottom row rests on the deck and holds the rest up.
+#[turbo::serialize]
+pub struct WallGrid {
+    pub origin: V2,
+    pub cols: usize,
+    pub rows: usize,
+    pub color: u32,
+    pub cells: Vec<bool>,
+}
+
+impl WallGrid {
+    pub fn new(x: f32, y: f32, cols: usize, rows: usize, color: u32) -> Self {
+        Self { origin: V2::new(x, y), cols, rows, color, cells: vec![false; cols * rows] }
+    }
+
+    /// Cell index under a raft-local point, if it falls inside the grid
+    pub fn cell_at(&self, x: f32, y: f32) -> Option<usize> {
+        let cx = ((x - self.origin.x) / PIXEL_SIZE).floor();
+        let cy = ((y - self.origin.y) / PIXEL_SIZE).floor();
+        if cx < 0.0 || cy < 0.0 || cx as usize >= self.cols || cy as usize >= self.rows {
+            return None;
+        }
+        Some(cy as usize * self.cols + cx as usize)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.cells.iter().any(|&alive| alive)
+    }
+
+    /// Fill the pixel under a raft-local point. Returns false outside the grid or if already filled.
+    pub fn absorb_debris(&mut self, x: f32, y: f32) -> bool {
+        match self.cell_at(x, y) {
+            Some(i) if !self.cells[i] => {
+                self.cells[i] = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Clear every pixel whose center is within `radius`, returning their centers as debris
+    pub fn destroy_circle_to_debris(&mut self, x: f32, y: f32, radius: f32) -> Vec<V2> {
+        let mut debris = Vec::new();
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                let i = r * self.cols + c;
+                if !self.cells[i] { continue; }
+                let center = self.cell_center(r, c);
+                let dx = center.x - x; let dy = center.y - y;
+                if (dx*dx + dy*dy).sqrt() <= radius {
+                    self.cells[i] = false;
+                    debris.push(center);
+                }
+            }
+        }
+        debris
+    }
+
+    // Remove unsupported pixels (not connected to bottom row) and return their raft-local positions
+    pub fn pop_unsupported(&mut self) -> Vec<V2> {
+        let total = self.cols * self.rows;
+        let mut supported = vec![false; total];
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        // seed from bottom row alive pixels
+        let br = self.rows - 1;
+        for c in 0..self.cols { if self.cells[br*self.cols + c] { stack.push((br, c)); supported[br*self.cols + c] = true; } }
+        // 4-neighbor flood fill upward
+        while let Some((r,c)) = stack.pop() {
+            let neigh = [(r.wrapping_sub(1), c), (r+1, c), (r, c.wrapping_sub(1)), (r, c+1)];
+            for (nr, nc) in neigh.into_iter() {
+                if nr < self.rows && nc < self.cols {
+                    let i = nr*self.cols + nc;
+                    if self.cells[i] && !supported[i] { supported[i] = true; stack.push((nr,nc)); }
+                }
+            }
+        }
+        // collect unsupported
+        let mut removed = Vec::new();
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                let idx = r*self.cols + c;
+                if self.cells[idx] && !supported[idx] {
+                    self.cells[idx] = false;
+                    removed.push(self.cell_center(r, c));
+                }
+            }
+        }
+        removed
+    }
+
+    fn cell_center(&self, r: usize, c: usize) -> V2 {
+        V2::new(self.origin.x + c as f32 * PIXEL_SIZE + PIXEL_SIZE*0.5, self.origin.y + r as f32 * PIXEL_SIZE + PIXEL_SIZE*0.5)
+    }
+
+    /// Draw the filled pixels; `to_screen` maps a raft-local point to the screen at `zoom`
+    pub fn render(&self, zoom: f32, to_screen: impl Fn(f32, f32) -> (f32, f32)) {
+        let size = (PIXEL_SIZE * zoom).ceil();
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                if self.cells[r * self.cols + c] {
+                    let (x, y) = to_screen(self.origin.x + c as f32 * PIXEL_SIZE, self.origin.y + r as f32 * PIXEL_SIZE);
+                    rect!(x = x, y = y, w = size, h = size, color = self.color, fixed = true);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn destroying_a_support_column_pops_what_it_held_up() {
+        // A 1-wide column three pixels tall with an arm sticking out of its top
+        let mut wall = WallGrid::new(0.0, 0.0, 3, 3, 0);
+        let center = |c: usize, r: usize| ((c as f32 + 0.5) * PIXEL_SIZE, (r as f32 + 0.5) * PIXEL_SIZE);
+        for (c, r) in [(0, 0), (0, 1), (0, 2), (1, 0), (2, 0)] {
+            let (x, y) = center(c, r);
+            assert!(wall.absorb_debris(x, y));
+        }
+        assert!(wall.pop_unsupported().is_empty());
+
+        let (x, y) = center(0, 2);
+        assert_eq!(wall.destroy_circle_to_debris(x, y, PIXEL_SIZE * 0.5).len(), 1);
+        assert_eq!(wall.pop_unsupported().len(), 4);
+        assert!(wall.is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+/// Tolerance so accumulated per-frame steps (e.g. 15 x 1/60) land exactly on the duration
+const TIMER_EPSILON: f32 = 1e-4;
+
+/// Simple countdown timer measured in seconds
+#[derive(Copy, PartialEq, Default)]
+#[turbo::serialize]
+pub struct Timer {
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl Timer {
+    /// Create a timer that starts running from zero
+    pub fn new(duration: f32) -> Self {
+        Self { elapsed: 0.0, duration }
+    }
+
+    /// Create a timer that is already finished (e.g. a cooldown that starts ready)
+    pub fn finished(duration: f32) -> Self {
+        Self { elapsed: duration, duration }
+    }
+
+    /// Advance the timer; returns true only on the tick where it finishes
+    pub fn tick(&mut self, dt: f32) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        self.elapsed += dt;
+        if self.is_finished() {
+            self.elapsed = self.duration;
+            return true;
+        }
+        false
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed + TIMER_EPSILON >= self.duration
+    }
+
+    /// Restart from zero keeping the same duration
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Fraction complete in [0, 1]
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_finishes_once_and_restarts_on_reset() {
+        let mut timer = Timer::new(0.25);
+        assert!(!timer.is_finished());
+        // 15 steps of 1/60 add up to the duration despite float drift
+        let finished_on: Vec<usize> = (1..=20).filter(|_| timer.tick(1.0 / 60.0)).collect();
+        assert_eq!(finished_on, vec![15]);
+        assert!(timer.is_finished());
+        assert_eq!(timer.progress(), 1.0);
+
+        timer.reset();
+        assert!(!timer.is_finished());
+        assert_eq!(timer.progress(), 0.0);
+        timer.tick(0.125);
+        assert!((timer.progress() - 0.5).abs() < 1e-6);
+
+        assert!(Timer::finished(1.0).is_finished());
+        assert!(!Timer::finished(1.0).tick(1.0), "an already finished timer doesn't fire again");
+    }
+}